@@ -0,0 +1,6 @@
+//! AdCOM specifications
+//!
+//! This module contains implementations of the IAB Tech Lab Advertising Common Object Model,
+//! the domain layer of OpenRTB 3.0.
+
+pub mod v1_0;
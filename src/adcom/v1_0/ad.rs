@@ -0,0 +1,79 @@
+use super::*;
+use crate::core::v2_6::enums::*;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// This object is the root of a structure that defines an instance of advertising media.
+///
+/// It includes metadata about the ad overall and sub-objects that provide additional detail
+/// specific to the type of media comprising the creative.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Ad<Ext = Value> {
+    /// ID of the creative; unique at least throughout the scope of a vendor.
+    pub id: String,
+
+    /// Advertiser domain; top two levels only (e.g., "ford.com").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub adomain: Option<Vec<String>>,
+
+    /// When the product of the ad is an app, the unique ID of that app as a bundle or package name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bundle: Option<Vec<String>>,
+
+    /// URL without cache-busting to an image that is representative of the ad content for cursory level
+    /// ad quality checking.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iurl: Option<String>,
+
+    /// Array of content categories describing the ad using IDs from the taxonomy indicated in `cattax`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cat: Option<Vec<String>>,
+
+    /// The taxonomy in use for the `cat` attribute.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cattax: Option<u32>,
+
+    /// Language of the creative using ISO-639-1-alpha-2.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lang: Option<String>,
+
+    /// Set of attributes describing the creative.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attr: Option<Vec<u32>>,
+
+    /// Flag to indicate if the creative is secure, where 0 = no, 1 = yes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secure: Option<SecureFlag>,
+
+    /// Media rating per IQG guidelines.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mrating: Option<u32>,
+
+    /// Timestamp of the original instantiation of this ad in Unix format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub init: Option<u64>,
+
+    /// Timestamp of most recent modification to this ad in Unix format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lastmod: Option<u64>,
+
+    /// Media subtype object if this is a display ad.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display: Option<Display>,
+
+    /// Media subtype object if this is a video ad.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video: Option<Video>,
+
+    /// Media subtype object if this is an audio ad.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audio: Option<Audio>,
+
+    /// An object depicting the audit status of the ad, passed through undecoded.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audit: Option<Value>,
+
+    /// Placeholder for vendor-specific extensions to AdCOM.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ext: Option<Ext>,
+}
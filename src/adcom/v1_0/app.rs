@@ -0,0 +1,75 @@
+use super::*;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// This object is used to define an ad supported non-browser application, in contrast to a website.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct App<Ext = Value> {
+    /// Vendor-specific unique identifier of the distribution channel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
+    /// Displayable name of the distribution channel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Details about the publisher of the distribution channel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub r#pub: Option<Publisher>,
+
+    /// Details about the content within the distribution channel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<Content>,
+
+    /// Domain of the distribution channel (e.g., "mysite.foo.com").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub domain: Option<String>,
+
+    /// Array of content categories describing the distribution channel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cat: Option<Vec<String>>,
+
+    /// The taxonomy in use for the `cat`, `sectcat` and `pagecat` attributes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cattax: Option<u32>,
+
+    /// Array of content categories describing the current section of the distribution channel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sectcat: Option<Vec<String>>,
+
+    /// Array of content categories describing the current page or view of the distribution channel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pagecat: Option<Vec<String>>,
+
+    /// Indicates if the distribution channel has a privacy policy, where 0 = no, 1 = yes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub privpolicy: Option<u32>,
+
+    /// Comma separated list of keywords about the distribution channel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keywords: Option<String>,
+
+    /// Application version.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ver: Option<String>,
+
+    /// A platform-specific application identifier intended to be unique to the app.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bundle: Option<String>,
+
+    /// A platform-specific identifier of the app in its store.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub storeid: Option<String>,
+
+    /// App store URL for an installed app.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub storeurl: Option<String>,
+
+    /// Indicates if the app is paid, where 0 = free, 1 = paid.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paid: Option<u32>,
+
+    /// Placeholder for vendor-specific extensions to AdCOM.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ext: Option<Ext>,
+}
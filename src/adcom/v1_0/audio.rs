@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// This object provides additional detail about an ad specifically for audio ads.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Audio<Ext = Value> {
+    /// Mime type(s) of the ad.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mime: Option<Vec<String>>,
+
+    /// API required by the ad if applicable.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api: Option<Vec<u32>>,
+
+    /// Subtype of audio creative.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ctype: Option<u32>,
+
+    /// Duration of the audio creative in seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dur: Option<u32>,
+
+    /// DAAST document markup if not using a structured alternative.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub adm: Option<String>,
+
+    /// Optional means of retrieving markup by reference.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub curl: Option<String>,
+
+    /// Placeholder for vendor-specific extensions to AdCOM.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ext: Option<Ext>,
+}
@@ -0,0 +1,119 @@
+use super::*;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// This object describes the content in which an ad may appear.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Content<Ext = Value> {
+    /// ID uniquely identifying the content.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
+    /// Episode number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub episode: Option<u32>,
+
+    /// Content title.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    /// Content series.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub series: Option<String>,
+
+    /// Content season.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub season: Option<String>,
+
+    /// Artist credited with the content.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub artist: Option<String>,
+
+    /// Genre that best describes the content.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub genre: Option<String>,
+
+    /// Album to which the content belongs; typically for audio.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub album: Option<String>,
+
+    /// International Standard Recording Code conforming to ISO-3901.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub isrc: Option<String>,
+
+    /// URL of the content, for buy-side contextualization or review.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+
+    /// Array of content categories that describe the content.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cat: Option<Vec<String>>,
+
+    /// The taxonomy in use for the `cat` attribute.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cattax: Option<u32>,
+
+    /// Production quality.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prodq: Option<u32>,
+
+    /// Type of content (game, video, text, etc.).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<u32>,
+
+    /// Content rating (e.g., MPAA).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rating: Option<String>,
+
+    /// User rating of the content (e.g., number of stars, likes, etc.).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub urating: Option<String>,
+
+    /// Media rating per IQG guidelines.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mrating: Option<u32>,
+
+    /// Comma separated list of keywords describing the content.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keywords: Option<String>,
+
+    /// Indicates if the content is live, where 0 = not live, 1 = live.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub live: Option<u32>,
+
+    /// Source relationship, where 0 = indirect, 1 = direct.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub srcrel: Option<u32>,
+
+    /// Length of content in seconds; typically for video or audio.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub len: Option<u32>,
+
+    /// Content language using ISO-639-1-alpha-2.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lang: Option<String>,
+
+    /// Indicator of whether or not the content is embeddable, where 0 = no, 1 = yes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub embed: Option<u32>,
+
+    /// Details about the content producer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub producer: Option<Producer>,
+
+    /// Details about the network the content is on.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network: Option<Network>,
+
+    /// Details about the channel the content is on.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<Channel>,
+
+    /// Additional content data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Vec<Data>>,
+
+    /// Placeholder for vendor-specific extensions to AdCOM.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ext: Option<Ext>,
+}
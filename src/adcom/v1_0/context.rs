@@ -0,0 +1,42 @@
+use super::*;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// The AdCOM context of an OpenRTB 3.0 request
+///
+/// Describes the distribution channel, the user, the device and the restrictions that apply to
+/// all items of the request.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Context<Ext = Value> {
+    /// Details about the website in which the ad will be shown.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub site: Option<Site>,
+
+    /// Details about the app in which the ad will be shown.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app: Option<App>,
+
+    /// Details about the digital out-of-home screen on which the ad will be shown.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dooh: Option<Dooh>,
+
+    /// Details about the human user of the device.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<User>,
+
+    /// Details about the device to which the ad will be delivered.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device: Option<Device>,
+
+    /// Regulatory conditions in effect for all items.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub regs: Option<Regs>,
+
+    /// Restrictions that apply to all items.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub restrictions: Option<Restrictions>,
+
+    /// Placeholder for vendor-specific extensions to AdCOM.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ext: Option<Ext>,
+}
@@ -0,0 +1,23 @@
+use super::*;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// The data and segment objects together allow additional data about the related object to be specified.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Data<Ext = Value> {
+    /// Vendor-specific ID for the data provider.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
+    /// Vendor-specific displayable name for the data provider.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Array of segment objects that contain the actual data values.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub segment: Option<Vec<Segment>>,
+
+    /// Placeholder for vendor-specific extensions to AdCOM.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ext: Option<Ext>,
+}
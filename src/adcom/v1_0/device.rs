@@ -0,0 +1,121 @@
+use super::*;
+use crate::core::v2_6::UserAgent;
+use crate::core::v2_6::enums::*;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// This object provides information pertaining to the device through which the user is interacting.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Device<Ext = Value> {
+    /// The general type of device.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<DeviceType>,
+
+    /// Browser user agent string.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ua: Option<String>,
+
+    /// Structured user agent information.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sua: Option<UserAgent>,
+
+    /// ID sanctioned for advertiser use in the clear (i.e., not hashed).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ifa: Option<String>,
+
+    /// Standard "Do Not Track" flag as set in the header by the browser.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dnt: Option<DoNotTrackFlag>,
+
+    /// "Limit Ad Tracking" signal commercially endorsed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lmt: Option<LimitAdTrackingFlag>,
+
+    /// Device make (e.g., "Apple").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub make: Option<String>,
+
+    /// Device model (e.g., "iPhone10,1").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+
+    /// Device operating system from the AdCOM list of operating systems.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub os: Option<u32>,
+
+    /// Device operating system version (e.g., "3.1.2").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub osv: Option<String>,
+
+    /// Hardware version of the device (e.g., "5S" for iPhone 5S).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hwv: Option<String>,
+
+    /// Physical height of the screen in pixels.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub h: Option<u32>,
+
+    /// Physical width of the screen in pixels.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub w: Option<u32>,
+
+    /// Screen size as pixels per linear inch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ppi: Option<u32>,
+
+    /// The ratio of physical pixels to device independent pixels.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pxratio: Option<f64>,
+
+    /// Support for JavaScript, where 0 = no, 1 = yes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub js: Option<JavaScriptFlag>,
+
+    /// Browser language using ISO-639-1-alpha-2.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lang: Option<String>,
+
+    /// IPv4 address closest to device.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip: Option<String>,
+
+    /// IPv6 address closest to device.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ipv6: Option<String>,
+
+    /// The value of the X-Forwarded-For header.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub xff: Option<String>,
+
+    /// Indicator of truncation of any of the IP attributes, where 0 = no, 1 = yes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iptr: Option<u32>,
+
+    /// Carrier or ISP (e.g., "VERIZON") using exchange curated string names.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub carrier: Option<String>,
+
+    /// Mobile carrier as the concatenated MCC-MNC code.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mccmnc: Option<String>,
+
+    /// MCC and MNC of the SIM card using the same format as `mccmnc`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mccmncsim: Option<String>,
+
+    /// Network connection type.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contype: Option<ConnectionType>,
+
+    /// Indicates if the geolocation API will be available to JavaScript code running in display ad.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub geofetch: Option<GeoFetchFlag>,
+
+    /// Location of the device (i.e., typically the user's current location).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub geo: Option<Geo>,
+
+    /// Placeholder for vendor-specific extensions to AdCOM.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ext: Option<Ext>,
+}
@@ -0,0 +1,63 @@
+use crate::core::v2_6::enums::*;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// This object provides additional detail about an ad specifically for display ads.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Display<Ext = Value> {
+    /// Mime type of the ad.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mime: Option<String>,
+
+    /// API required by the ad if applicable.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api: Option<Vec<ApiFramework>>,
+
+    /// Subtype of display creative.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ctype: Option<u32>,
+
+    /// Absolute width of the creative in device independent pixels.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub w: Option<u32>,
+
+    /// Absolute height of the creative in device independent pixels.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub h: Option<u32>,
+
+    /// Relative width of the creative when expressing size as a ratio.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wratio: Option<u32>,
+
+    /// Relative height of the creative when expressing size as a ratio.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hratio: Option<u32>,
+
+    /// URL of a page informing the user about a buyer's targeting activity.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub r#priv: Option<String>,
+
+    /// General display markup if not using a structured alternative.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub adm: Option<String>,
+
+    /// Optional means of retrieving display markup by reference.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub curl: Option<String>,
+
+    /// Structured banner image object, passed through undecoded.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub banner: Option<Value>,
+
+    /// Structured native object, passed through undecoded.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub native: Option<Value>,
+
+    /// Array of events that the buyer would like to track, passed through undecoded.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event: Option<Vec<Value>>,
+
+    /// Placeholder for vendor-specific extensions to AdCOM.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ext: Option<Ext>,
+}
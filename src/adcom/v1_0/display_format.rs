@@ -0,0 +1,31 @@
+use crate::core::v2_6::enums::*;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// This object represents an allowed size or aspect ratio of a display placement.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct DisplayFormat<Ext = Value> {
+    /// Absolute width of the creative in units specified by `DisplayPlacement.unit`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub w: Option<u32>,
+
+    /// Absolute height of the creative in units specified by `DisplayPlacement.unit`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub h: Option<u32>,
+
+    /// Relative width of the creative when expressing size as a ratio.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wratio: Option<u32>,
+
+    /// Relative height of the creative when expressing size as a ratio.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hratio: Option<u32>,
+
+    /// Directions in which the creative is permitted to expand.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expdir: Option<Vec<ExpandableDirection>>,
+
+    /// Placeholder for vendor-specific extensions to AdCOM.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ext: Option<Ext>,
+}
@@ -0,0 +1,84 @@
+use super::*;
+use crate::core::v2_6::enums::*;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// This object signals that the placement may be a display placement.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct DisplayPlacement<Ext = Value> {
+    /// Placement position on screen.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pos: Option<AdPosition>,
+
+    /// Indicates if this is an interstitial placement, where 0 = no, 1 = yes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instl: Option<InterstitialFlag>,
+
+    /// Indicates if the placement will be shown in the top frame rather than in an iframe.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub topframe: Option<TopFrameFlag>,
+
+    /// Array of supported iframe busters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ifrbust: Option<Vec<String>>,
+
+    /// Indicates the click type of the placement, where 0 = embedded, 1 = native browser.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub clktype: Option<ClickBrowser>,
+
+    /// Indicates the accelerated mobile pages (AMP) rendering behavior.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ampren: Option<u32>,
+
+    /// The display placement type.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ptype: Option<u32>,
+
+    /// The context of the placement.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<u32>,
+
+    /// Array of supported mime types.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mime: Option<Vec<String>>,
+
+    /// List of supported APIs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api: Option<Vec<ApiFramework>>,
+
+    /// Creative subtypes permitted for this placement.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ctype: Option<Vec<u32>>,
+
+    /// Width of the placement in units specified by `unit`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub w: Option<u32>,
+
+    /// Height of the placement in units specified by `unit`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub h: Option<u32>,
+
+    /// Unit of size used for placement size, where 1 = device independent pixels.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit: Option<u32>,
+
+    /// Indicates if the creative must include the privacy policy icon, where 0 = no, 1 = yes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub r#priv: Option<u32>,
+
+    /// Array of objects listing the allowed sizes and aspect ratios of the placement.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub displayfmt: Option<Vec<DisplayFormat>>,
+
+    /// Native placement format, passed through undecoded.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nativefmt: Option<Value>,
+
+    /// Array of supported ad tracking events, passed through undecoded.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event: Option<Vec<Value>>,
+
+    /// Placeholder for vendor-specific extensions to AdCOM.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ext: Option<Ext>,
+}
@@ -0,0 +1,43 @@
+use super::*;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// This object is used to define an ad supported digital out-of-home experience.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Dooh<Ext = Value> {
+    /// Vendor-specific unique identifier of the distribution channel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
+    /// Displayable name of the distribution channel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Details about the publisher of the distribution channel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub r#pub: Option<Publisher>,
+
+    /// Details about the content within the distribution channel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<Content>,
+
+    /// The type of out-of-home venue.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub venuetype: Option<Vec<String>>,
+
+    /// The venue taxonomy in use.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub venuetypetax: Option<u32>,
+
+    /// Domain of the inventory owner.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub domain: Option<String>,
+
+    /// Comma separated list of keywords about the DOOH placement.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keywords: Option<String>,
+
+    /// Placeholder for vendor-specific extensions to AdCOM.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ext: Option<Ext>,
+}
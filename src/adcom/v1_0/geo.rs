@@ -0,0 +1,59 @@
+use crate::core::v2_6::enums::*;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// This object encapsulates various methods for specifying a geographic location.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Geo<Ext = Value> {
+    /// Source of location data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<LocationType>,
+
+    /// Latitude from -90.0 to +90.0, where negative is south.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lat: Option<f64>,
+
+    /// Longitude from -180.0 to +180.0, where negative is west.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lon: Option<f64>,
+
+    /// Estimated location accuracy in meters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accur: Option<u32>,
+
+    /// Number of seconds since this geolocation fix was established.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lastfix: Option<u32>,
+
+    /// Service or provider used to determine geolocation from IP address if applicable.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ipserv: Option<LocationService>,
+
+    /// Country code using ISO 3166-1 Alpha 2.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country: Option<String>,
+
+    /// Region code using ISO-3166-2; 2-letter state code if USA.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+
+    /// Regional marketing areas such as Nielsen's DMA codes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metro: Option<String>,
+
+    /// City using United Nations Code for Trade & Transport Locations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub city: Option<String>,
+
+    /// ZIP or postal code.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub zip: Option<String>,
+
+    /// Local time as the number +/- of minutes from UTC.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub utcoffset: Option<i32>,
+
+    /// Placeholder for vendor-specific extensions to AdCOM.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ext: Option<Ext>,
+}
@@ -0,0 +1,62 @@
+//! AdCOM 1.0 Specification Objects
+//!
+//! This module contains the AdCOM 1.0 objects carried by OpenRTB 3.0 requests and responses.
+//! Enumerated lists shared with OpenRTB 2.6 reuse the types in [`crate::core::v2_6::enums`].
+
+// Placement objects
+pub mod audio_placement;
+pub mod display_format;
+pub mod display_placement;
+pub mod placement;
+pub mod video_placement;
+
+// Media objects
+pub mod ad;
+pub mod audio;
+pub mod display;
+pub mod video;
+
+// Context objects
+pub mod app;
+pub mod channel;
+pub mod content;
+pub mod context;
+pub mod data;
+pub mod device;
+pub mod dooh;
+pub mod geo;
+pub mod network;
+pub mod producer;
+pub mod publisher;
+pub mod regs;
+pub mod restrictions;
+pub mod segment;
+pub mod site;
+pub mod user;
+
+// Re-export all structs for easy access
+pub use ad::*;
+pub use app::*;
+pub use audio::*;
+pub use audio_placement::*;
+pub use channel::*;
+pub use content::*;
+pub use context::*;
+pub use data::*;
+pub use device::*;
+pub use display::*;
+pub use display_format::*;
+pub use display_placement::*;
+pub use dooh::*;
+pub use geo::*;
+pub use network::*;
+pub use placement::*;
+pub use producer::*;
+pub use publisher::*;
+pub use regs::*;
+pub use restrictions::*;
+pub use segment::*;
+pub use site::*;
+pub use user::*;
+pub use video::*;
+pub use video_placement::*;
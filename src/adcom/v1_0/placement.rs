@@ -0,0 +1,63 @@
+use super::*;
+use crate::core::v2_6::enums::*;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// This object is the root of a structure that defines an offered placement.
+///
+/// Its presence in an OpenRTB 3.0 `Spec` indicates the media subtypes a buyer may bid with.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Placement<Ext = Value> {
+    /// Identifier for specific ad placement or ad tag that was used to initiate the auction.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tagid: Option<String>,
+
+    /// Indicates if server-side ad insertion (e.g., stitching an ad into an audio or video stream) is
+    /// in use and the impact of this on asset and tracker retrieval.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ssai: Option<u32>,
+
+    /// Name of ad mediation partner, SDK technology, or player responsible for rendering ad.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sdk: Option<String>,
+
+    /// Version of ad mediation partner, SDK technology, or player responsible for rendering ad.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sdkver: Option<String>,
+
+    /// Indicates if this is a rewarded placement, where 0 = no, 1 = yes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reward: Option<u32>,
+
+    /// Allowed list of languages for creatives using ISO-639-1-alpha-2.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wlang: Option<Vec<String>>,
+
+    /// Flag to indicate if the placement requires secure HTTPS URL creative assets and markup.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secure: Option<SecureFlag>,
+
+    /// Indicates if including markup is supported in the bid response, where 0 = no, 1 = yes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub admx: Option<u32>,
+
+    /// Indicates if including a URL to retrieve markup is supported, where 0 = no, 1 = yes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub curlx: Option<u32>,
+
+    /// Placement subtype indicating that a display ad is permitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display: Option<DisplayPlacement>,
+
+    /// Placement subtype indicating that a video ad is permitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video: Option<VideoPlacement>,
+
+    /// Placement subtype indicating that an audio ad is permitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audio: Option<AudioPlacement>,
+
+    /// Placeholder for vendor-specific extensions to AdCOM.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ext: Option<Ext>,
+}
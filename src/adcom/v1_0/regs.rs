@@ -0,0 +1,19 @@
+use crate::core::v2_6::enums::*;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// This object contains any legal, governmental, or industry regulations that the sender deems applicable.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Regs<Ext = Value> {
+    /// Flag indicating if this request is subject to the COPPA regulations established by the USA FTC.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coppa: Option<CoppaFlag>,
+
+    /// Flag that indicates whether or not the request is subject to GDPR regulations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gdpr: Option<GdprFlag>,
+
+    /// Placeholder for vendor-specific extensions to AdCOM.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ext: Option<Ext>,
+}
@@ -0,0 +1,31 @@
+use crate::core::v2_6::enums::*;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// This object allows lists of restrictions to be expressed for the creatives of all items.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Restrictions<Ext = Value> {
+    /// Block list of content categories using IDs from the taxonomy indicated in `cattax`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bcat: Option<Vec<String>>,
+
+    /// The taxonomy in use for the `bcat` attribute.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cattax: Option<u32>,
+
+    /// Block list of advertisers by their domains.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub badv: Option<Vec<String>>,
+
+    /// Block list of apps for which ads are disallowed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bapp: Option<Vec<String>>,
+
+    /// Block list of creative attributes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub battr: Option<Vec<CreativeAttribute>>,
+
+    /// Placeholder for vendor-specific extensions to AdCOM.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ext: Option<Ext>,
+}
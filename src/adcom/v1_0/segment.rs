@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Segment objects are essentially key-value pairs that convey specific units of data.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Segment<Ext = Value> {
+    /// ID of the data segment specific to the data provider.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
+    /// Displayable name of the data segment specific to the data provider.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// String representation of the data segment value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+
+    /// Placeholder for vendor-specific extensions to AdCOM.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ext: Option<Ext>,
+}
@@ -0,0 +1,48 @@
+use super::*;
+use crate::core::v2_6::Eid;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// This object contains information known or derived about the human user of the device.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct User<Ext = Value> {
+    /// Vendor-specific ID for the user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
+    /// Buyer-specific ID for the user as mapped by an exchange for the buyer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub buyeruid: Option<String>,
+
+    /// Year of birth as a 4-digit integer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub yob: Option<u32>,
+
+    /// Gender, where "M" = male, "F" = female, "O" = known to be other.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gender: Option<String>,
+
+    /// Comma separated list of keywords, interests, or intent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keywords: Option<String>,
+
+    /// GDPR consent string if applicable.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub consent: Option<String>,
+
+    /// Location of the user's home base.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub geo: Option<Geo>,
+
+    /// Additional user data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Vec<Data>>,
+
+    /// Details for support of a standard protocol for multiple third party identity providers.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub eids: Option<Vec<Eid>>,
+
+    /// Placeholder for vendor-specific extensions to AdCOM.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ext: Option<Ext>,
+}
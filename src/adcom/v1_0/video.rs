@@ -0,0 +1,35 @@
+use crate::core::v2_6::enums::*;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// This object provides additional detail about an ad specifically for video ads.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Video<Ext = Value> {
+    /// Mime type(s) of the ad.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mime: Option<Vec<String>>,
+
+    /// API required by the ad if applicable.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api: Option<Vec<ApiFramework>>,
+
+    /// Subtype of video creative.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ctype: Option<VideoBidResponseProtocol>,
+
+    /// Duration of the video creative in seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dur: Option<u32>,
+
+    /// VAST document markup if not using a structured alternative.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub adm: Option<String>,
+
+    /// Optional means of retrieving markup by reference.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub curl: Option<String>,
+
+    /// Placeholder for vendor-specific extensions to AdCOM.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ext: Option<Ext>,
+}
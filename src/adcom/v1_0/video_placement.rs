@@ -0,0 +1,135 @@
+use crate::core::v2_6::enums::*;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// This object signals that the placement may be a video placement.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct VideoPlacement<Ext = Value> {
+    /// Placement subtype.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ptype: Option<VideoPlcmtSubtype>,
+
+    /// Placement position on screen.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pos: Option<AdPosition>,
+
+    /// Indicates the start delay in seconds for pre-roll, mid-roll, or post-roll placements.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delay: Option<u32>,
+
+    /// Indicates if the placement imposes ad skippability, where 0 = no, 1 = yes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skip: Option<SkipFlag>,
+
+    /// Only ads with a duration greater than this value can be skippable.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skipmin: Option<u32>,
+
+    /// Number of seconds a skippable ad must be played before it becomes skippable.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skipafter: Option<u32>,
+
+    /// Minimum creative duration in seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mindur: Option<u32>,
+
+    /// Maximum creative duration in seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maxdur: Option<u32>,
+
+    /// Maximum extended creative duration if extension is allowed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maxext: Option<u32>,
+
+    /// Minimum CPM per second.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mincpms: Option<f64>,
+
+    /// The maximum number of ads that can be played in an ad pod.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maxseq: Option<u32>,
+
+    /// Precise acceptable durations for creatives in seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rqddurs: Option<Vec<u32>>,
+
+    /// Total amount of time in seconds that advertisers may fill for a dynamic ad pod.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub poddur: Option<u32>,
+
+    /// Unique identifier indicating that a placement is part of a pod.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub podid: Option<String>,
+
+    /// The sequence (position) of the ad pod within a content stream.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub podseq: Option<PodSequence>,
+
+    /// The seller's guarantee of the position of the ad slot within the pod.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slotinpod: Option<SlotPositionInPod>,
+
+    /// Indicates if letter-boxing of 4:3 creatives into a 16:9 window is allowed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub boxing: Option<BoxingAllowedFlag>,
+
+    /// Unit of size used for placement size, where 1 = device independent pixels.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit: Option<u32>,
+
+    /// Array of supported mime types.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mime: Option<Vec<String>>,
+
+    /// List of supported APIs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api: Option<Vec<ApiFramework>>,
+
+    /// Creative subtypes permitted for this placement.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ctype: Option<Vec<VideoBidResponseProtocol>>,
+
+    /// Width of the placement in units specified by `unit`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub w: Option<u32>,
+
+    /// Height of the placement in units specified by `unit`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub h: Option<u32>,
+
+    /// Minimum bit rate of the creative in Kbps.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minbitr: Option<u32>,
+
+    /// Maximum bit rate of the creative in Kbps.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maxbitr: Option<u32>,
+
+    /// Indicates if the creative must be linear or non-linear.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub linear: Option<VideoLinearity>,
+
+    /// Array of playback methods in use for this placement.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub playmethod: Option<Vec<PlaybackMethod>>,
+
+    /// The mode of playback cessation in use for this placement.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub playend: Option<PlaybackCessationMode>,
+
+    /// Array of supported creative delivery methods.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delivery: Option<Vec<ContentDeliveryMethod>>,
+
+    /// Supported companion ad types.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comptype: Option<Vec<CompanionType>>,
+
+    /// Array of companion placements, passed through undecoded.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comp: Option<Vec<Value>>,
+
+    /// Placeholder for vendor-specific extensions to AdCOM.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ext: Option<Ext>,
+}
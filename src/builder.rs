@@ -0,0 +1,111 @@
+//! Typed builders shared by the specification modules
+//!
+//! Every builder is created from the required fields of its object, so leaving out a
+//! mandatory field is a compile error, and offers one setter per optional field.
+
+/// Conversion accepted by builder arguments and setters
+///
+/// Implemented for every type into itself, and for string slices into the string types of
+/// the models, so builders accept `"video/mp4"` and `["video/mp4"]` as well as owned values.
+pub trait IntoField<T> {
+    /// Convert into the field type
+    fn into_field(self) -> T;
+}
+
+impl<T> IntoField<T> for T {
+    fn into_field(self) -> T {
+        self
+    }
+}
+
+impl IntoField<String> for &str {
+    fn into_field(self) -> String {
+        self.to_string()
+    }
+}
+
+impl<T, const N: usize> IntoField<Vec<T>> for [T; N] {
+    fn into_field(self) -> Vec<T> {
+        self.into()
+    }
+}
+
+impl<const N: usize> IntoField<Vec<String>> for [&str; N] {
+    fn into_field(self) -> Vec<String> {
+        self.iter().map(|s| s.to_string()).collect()
+    }
+}
+
+/// Generate a builder for a struct from its required, optional and defaulted fields
+///
+/// Required fields become arguments of `X::builder`, optional fields get setters wrapping the
+/// value in `Some`, and defaulted fields start from `Default::default()` with a plain setter.
+/// Optional fields start from `None`, or from the value given after `=`, which mirrors the
+/// serde default of the field so that built and parsed objects agree.
+macro_rules! builder {
+    (
+        $name:ident $(<$($generic:ident),+>)? => $builder:ident {
+            required { $($req:ident: $req_ty:ty),* $(,)? }
+            optional { $($opt:ident: $opt_ty:ty $(= $opt_init:expr)?),* $(,)? }
+            $(defaulted { $($def:ident: $def_ty:ty),* $(,)? })?
+        }
+    ) => {
+        #[doc = concat!("Builder for [`", stringify!($name), "`]")]
+        #[derive(Debug, Clone)]
+        pub struct $builder $(<$($generic = serde_json::Value),+>)? {
+            inner: $name $(<$($generic),+>)?,
+        }
+
+        #[allow(deprecated)]
+        impl $(<$($generic),+>)? $name $(<$($generic),+>)? {
+            #[doc = concat!("Start building a [`", stringify!($name), "`] from its required fields")]
+            #[allow(clippy::too_many_arguments)]
+            pub fn builder($($req: impl $crate::builder::IntoField<$req_ty>),*) -> $builder $(<$($generic),+>)? {
+                $builder {
+                    inner: $name {
+                        $($req: $crate::builder::IntoField::into_field($req),)*
+                        $($opt: $crate::builder::optional_init!($($opt_init)?),)*
+                        $($($def: Default::default(),)*)?
+                    },
+                }
+            }
+        }
+
+        #[allow(deprecated)]
+        impl $(<$($generic),+>)? $builder $(<$($generic),+>)? {
+            $(
+                #[doc = concat!("Set `", stringify!($opt), "`")]
+                pub fn $opt(mut self, $opt: impl $crate::builder::IntoField<$opt_ty>) -> Self {
+                    self.inner.$opt = Some($crate::builder::IntoField::into_field($opt));
+                    self
+                }
+            )*
+
+            $($(
+                #[doc = concat!("Set `", stringify!($def), "`")]
+                pub fn $def(mut self, $def: impl $crate::builder::IntoField<$def_ty>) -> Self {
+                    self.inner.$def = $crate::builder::IntoField::into_field($def);
+                    self
+                }
+            )*)?
+
+            #[doc = concat!("Finish building the [`", stringify!($name), "`]")]
+            pub fn build(self) -> $name $(<$($generic),+>)? {
+                self.inner
+            }
+        }
+    };
+}
+
+/// Initial value of an optional builder field
+macro_rules! optional_init {
+    () => {
+        None
+    };
+    ($init:expr) => {
+        $init
+    };
+}
+
+pub(crate) use builder;
+pub(crate) use optional_init;
@@ -1 +1,2 @@
 pub mod v2_6;
+pub mod v3_0;
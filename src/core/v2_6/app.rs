@@ -1,3 +1,4 @@
+use super::enums::*;
 use super::*;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -33,6 +34,10 @@ pub struct App<Ext = Value> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cat: Option<Vec<String>>,
 
+    /// The taxonomy in use for the cat attribute.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cattax: Option<ContentTaxonomy>,
+
     /// Array of IAB content categories that describe the current section of the app.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sectioncat: Option<Vec<String>>,
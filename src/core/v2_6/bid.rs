@@ -1,3 +1,4 @@
+use super::enums::*;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
@@ -63,6 +64,10 @@ pub struct Bid<Ext = Value> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cat: Option<Vec<String>>,
 
+    /// The taxonomy in use for the cat attribute.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cattax: Option<ContentTaxonomy>,
+
     /// Set of attributes describing the creative.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub attr: Option<Vec<u32>>,
@@ -112,6 +117,15 @@ pub struct Bid<Ext = Value> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub exp: Option<u32>,
 
+    /// Duration of the video or audio creative in seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dur: Option<u32>,
+
+    /// Indicates that the bid response is only eligible for a specific position within a
+    /// video or audio ad pod.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slotinpod: Option<SlotPositionInPod>,
+
     /// Placeholder for exchange-specific extensions to OpenRTB.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ext: Option<Ext>,
@@ -82,6 +82,10 @@ pub struct BidRequest<Ext = Value> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub bcat: Option<Vec<String>>,
 
+    /// The taxonomy in use for bcat.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cattax: Option<ContentTaxonomy>,
+
     /// Block list of advertisers by their domains (e.g., \"ford.com\").
     #[serde(skip_serializing_if = "Option::is_none")]
     pub badv: Option<Vec<String>>,
@@ -103,6 +107,6 @@ pub struct BidRequest<Ext = Value> {
     pub ext: Option<Ext>,
 }
 
-fn default_at() -> Option<AuctionType> {
+pub(super) fn default_at() -> Option<AuctionType> {
     Some(AuctionType::SecondPricePlus)
 }
@@ -38,6 +38,6 @@ pub struct BidResponse<Ext = Value> {
     pub ext: Option<Ext>,
 }
 
-fn default_cur() -> Option<String> {
+pub(super) fn default_cur() -> Option<String> {
     Some("USD".to_string())
 }
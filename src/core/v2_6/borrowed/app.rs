@@ -0,0 +1,149 @@
+use super::*;
+use crate::core::v2_6;
+use crate::core::v2_6::enums::*;
+use crate::core::v2_6::Content;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::borrow::Cow;
+
+/// [`v2_6::App`] borrowing its strings from the deserialized input
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct App<'a, Ext = Value> {
+    /// Exchange-specific app ID.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub id: Option<Cow<'a, str>>,
+
+    /// App name (may be aliased at the publisher's request).
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub name: Option<Cow<'a, str>>,
+
+    /// A platform-specific application identifier intended to be unique to the app and independent of the exchange.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub bundle: Option<Cow<'a, str>>,
+
+    /// Domain of the app (e.g., "mygame.foo.com").
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub domain: Option<Cow<'a, str>>,
+
+    /// App store URL for an installed app; for QAG 1.5 compliance.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub storeurl: Option<Cow<'a, str>>,
+
+    /// Array of IAB content categories of the app.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_vec_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub cat: Option<Vec<Cow<'a, str>>>,
+
+    /// The taxonomy in use for the cat attribute.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cattax: Option<ContentTaxonomy>,
+
+    /// Array of IAB content categories that describe the current section of the app.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_vec_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub sectioncat: Option<Vec<Cow<'a, str>>>,
+
+    /// Array of IAB content categories that describe the current page or view of the app.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_vec_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub pagecat: Option<Vec<Cow<'a, str>>>,
+
+    /// Application version.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub ver: Option<Cow<'a, str>>,
+
+    /// Indicates if the app has a privacy policy.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub privacypolicy: Option<u32>,
+
+    /// 0 = app is free, 1 = the app is a paid version.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paid: Option<u32>,
+
+    /// Details about the Publisher of the app.
+    #[serde(borrow, skip_serializing_if = "Option::is_none")]
+    pub publisher: Option<Publisher<'a>>,
+
+    /// Details about the Content within the app.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<Content>,
+
+    /// Comma separated list of keywords about the app.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub keywords: Option<Cow<'a, str>>,
+
+    /// Placeholder for exchange-specific extensions to OpenRTB.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ext: Option<Ext>,
+}
+
+impl<Ext> App<'_, Ext> {
+    /// Convert into the owned [`v2_6::App`]
+    pub fn into_owned(self) -> v2_6::App<Ext> {
+        v2_6::App {
+            id: self.id.map(Cow::into_owned),
+            name: self.name.map(Cow::into_owned),
+            bundle: self.bundle.map(Cow::into_owned),
+            domain: self.domain.map(Cow::into_owned),
+            storeurl: self.storeurl.map(Cow::into_owned),
+            cat: self.cat.map(into_owned_vec),
+            cattax: self.cattax,
+            sectioncat: self.sectioncat.map(into_owned_vec),
+            pagecat: self.pagecat.map(into_owned_vec),
+            ver: self.ver.map(Cow::into_owned),
+            privacypolicy: self.privacypolicy,
+            paid: self.paid,
+            publisher: self.publisher.map(Publisher::into_owned),
+            content: self.content,
+            keywords: self.keywords.map(Cow::into_owned),
+            ext: self.ext,
+        }
+    }
+}
@@ -0,0 +1,184 @@
+use super::*;
+use crate::core::v2_6;
+use crate::core::v2_6::enums::*;
+use crate::core::v2_6::{Dooh, Regs, Source};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::borrow::Cow;
+
+/// [`v2_6::BidRequest`] borrowing its strings from the deserialized input
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct BidRequest<'a, Ext = Value> {
+    /// ID of the bid request, assigned by the exchange, and unique for the exchange's subsequent
+    /// tracking of the responses. The exchange may use different values for different recipients.
+    #[serde(borrow)]
+    pub id: Cow<'a, str>,
+
+    /// Array of `Imp` objects representing the impressions offered. At least 1 `Imp` object is required.
+    #[serde(borrow)]
+    pub imp: Vec<Impression<'a>>,
+
+    /// Details via a `Site` object about the publisher's website. Only applicable and recommended for websites.
+    #[serde(borrow, skip_serializing_if = "Option::is_none")]
+    pub site: Option<Site<'a>>,
+
+    /// Details via an `App` object about the publisher's app (i.e., non-browser applications).
+    /// Only applicable and recommended for apps.
+    #[serde(borrow, skip_serializing_if = "Option::is_none")]
+    pub app: Option<App<'a>>,
+
+    /// This object should be included if the ad supported content is a Digital Out-Of-Home screen.
+    /// A bid request with a DOOH object must not contain a site or app object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dooh: Option<Dooh>,
+
+    /// Details via a `Device` object about the user's device to which the impression will be delivered.
+    #[serde(borrow, skip_serializing_if = "Option::is_none")]
+    pub device: Option<Device<'a>>,
+
+    /// Details via a `User` object about the human user of the device; the advertising audience.
+    #[serde(borrow, skip_serializing_if = "Option::is_none")]
+    pub user: Option<User<'a>>,
+
+    /// Indicator of test mode in which auctions are not billable, where 0 = live mode, 1 = test mode.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub test: Option<TestMode>,
+
+    /// Auction type, where 1 = First Price, 2 = Second Price Plus.
+    /// Exchange-specific auction types can be defined using values 500 and greater.
+    #[serde(default = "default_at", skip_serializing_if = "Option::is_none")]
+    pub at: Option<AuctionType>,
+
+    /// Maximum time in milliseconds the exchange allows for bids to be received including
+    /// Internet latency to avoid timeout.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tmax: Option<u32>,
+
+    /// Allowed list of buyer seats (e.g., advertisers, agencies) allowed to bid on this impression.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_vec_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub wseat: Option<Vec<Cow<'a, str>>>,
+
+    /// Block list of buyer seats (e.g., advertisers, agencies) restricted from bidding on this impression.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_vec_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub bseat: Option<Vec<Cow<'a, str>>>,
+
+    /// Flag to indicate if Exchange can verify that the impressions offered represent all or a
+    /// group of the impressions available in context.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allimps: Option<AllImpsFlag>,
+
+    /// Array of allowed currencies for bids on this bid request using ISO-4217 alpha codes.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_vec_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub cur: Option<Vec<Cow<'a, str>>>,
+
+    /// Allowed list of languages for creatives using ISO-639-1-alpha-2.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_vec_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub wlang: Option<Vec<Cow<'a, str>>>,
+
+    /// Indicates that the bidder is allowed to provide markup that will be cached by the exchange.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub cacheid: Option<Cow<'a, str>>,
+
+    /// Block list of content categories using the IAB content category taxonomy.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_vec_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub bcat: Option<Vec<Cow<'a, str>>>,
+
+    /// The taxonomy in use for bcat.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cattax: Option<ContentTaxonomy>,
+
+    /// Block list of advertisers by their domains (e.g., \"ford.com\").
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_vec_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub badv: Option<Vec<Cow<'a, str>>>,
+
+    /// Block list of apps by their platform-specific exchange-independent application identifiers.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_vec_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub bapp: Option<Vec<Cow<'a, str>>>,
+
+    /// Details via a `Source` object about the inventory source and which entity makes the final decision.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<Source>,
+
+    /// Details via `Regs` object about any industry, legal, or governmental regulations in force for this request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub regs: Option<Regs>,
+
+    /// Placeholder for exchange-specific extensions to OpenRTB.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ext: Option<Ext>,
+}
+
+impl<Ext> BidRequest<'_, Ext> {
+    /// Convert into the owned [`v2_6::BidRequest`]
+    pub fn into_owned(self) -> v2_6::BidRequest<Ext> {
+        v2_6::BidRequest {
+            id: self.id.into_owned(),
+            imp: self.imp.into_iter().map(Impression::into_owned).collect(),
+            site: self.site.map(Site::into_owned),
+            app: self.app.map(App::into_owned),
+            dooh: self.dooh,
+            device: self.device.map(Device::into_owned),
+            user: self.user.map(User::into_owned),
+            test: self.test,
+            at: self.at,
+            tmax: self.tmax,
+            wseat: self.wseat.map(into_owned_vec),
+            bseat: self.bseat.map(into_owned_vec),
+            allimps: self.allimps,
+            cur: self.cur.map(into_owned_vec),
+            wlang: self.wlang.map(into_owned_vec),
+            cacheid: self.cacheid.map(Cow::into_owned),
+            bcat: self.bcat.map(into_owned_vec),
+            cattax: self.cattax,
+            badv: self.badv.map(into_owned_vec),
+            bapp: self.bapp.map(into_owned_vec),
+            source: self.source,
+            regs: self.regs,
+            ext: self.ext,
+        }
+    }
+}
+
+fn default_at() -> Option<AuctionType> {
+    Some(AuctionType::SecondPricePlus)
+}
@@ -0,0 +1,49 @@
+use super::*;
+use crate::core::v2_6;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::borrow::Cow;
+
+/// [`v2_6::Data`] borrowing its strings from the deserialized input
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Data<'a, Ext = Value> {
+    /// Exchange-specific ID for the data provider.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub id: Option<Cow<'a, str>>,
+
+    /// Exchange-specific name for the data provider.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub name: Option<Cow<'a, str>>,
+
+    /// Array of `Segment` objects that contain the actual data values.
+    #[serde(borrow, skip_serializing_if = "Option::is_none")]
+    pub segment: Option<Vec<Segment<'a>>>,
+
+    /// Placeholder for exchange-specific extensions to OpenRTB.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ext: Option<Ext>,
+}
+
+impl<Ext> Data<'_, Ext> {
+    /// Convert into the owned [`v2_6::Data`]
+    pub fn into_owned(self) -> v2_6::Data<Ext> {
+        v2_6::Data {
+            id: self.id.map(Cow::into_owned),
+            name: self.name.map(Cow::into_owned),
+            segment: self
+                .segment
+                .map(|v| v.into_iter().map(Segment::into_owned).collect()),
+            ext: self.ext,
+        }
+    }
+}
@@ -0,0 +1,274 @@
+use super::*;
+use crate::core::v2_6;
+use crate::core::v2_6::UserAgent;
+use crate::core::v2_6::enums::*;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::borrow::Cow;
+
+/// [`v2_6::Device`] borrowing its strings from the deserialized input
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Device<'a, Ext = Value> {
+    /// Browser user agent string.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub ua: Option<Cow<'a, str>>,
+
+    /// Location of the device assumed to be the user's current location defined by a `Geo` object.
+    #[serde(borrow, skip_serializing_if = "Option::is_none")]
+    pub geo: Option<Geo<'a>>,
+
+    /// Standard "Do Not Track" flag as set in the header by the browser.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dnt: Option<DoNotTrackFlag>,
+
+    /// "Limit Ad Tracking" signal commercially endorsed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lmt: Option<LimitAdTrackingFlag>,
+
+    /// IPv4 address closest to device.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub ip: Option<Cow<'a, str>>,
+
+    /// IP address closest to device as IPv6.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub ipv6: Option<Cow<'a, str>>,
+
+    /// The general type of device.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub devicetype: Option<DeviceType>,
+
+    /// Device make (e.g., "Apple").
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub make: Option<Cow<'a, str>>,
+
+    /// Device model (e.g., "iPhone").
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub model: Option<Cow<'a, str>>,
+
+    /// Device operating system (e.g., "iOS").
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub os: Option<Cow<'a, str>>,
+
+    /// Device operating system version (e.g., "3.1.2").
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub osv: Option<Cow<'a, str>>,
+
+    /// Hardware version of the device (e.g., "5S" for iPhone 5S).
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub hwv: Option<Cow<'a, str>>,
+
+    /// Physical height of the screen in pixels.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub h: Option<u32>,
+
+    /// Physical width of the screen in pixels.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub w: Option<u32>,
+
+    /// Screen size as pixels per linear inch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ppi: Option<u32>,
+
+    /// The ratio of physical pixels to device independent pixels.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pxratio: Option<f64>,
+
+    /// Support for JavaScript.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub js: Option<JavaScriptFlag>,
+
+    /// Indicates if the geolocation API will be available to JavaScript code running in the banner.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub geofetch: Option<GeoFetchFlag>,
+
+    /// Version of Flash supported by the browser.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub flashver: Option<Cow<'a, str>>,
+
+    /// Browser language using ISO-639-1-alpha-2.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub language: Option<Cow<'a, str>>,
+
+    /// Carrier or ISP (e.g., "VERIZON") using exchange curated string names.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub carrier: Option<Cow<'a, str>>,
+
+    /// Mobile carrier as the concatenated MCC-MNC code.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub mccmnc: Option<Cow<'a, str>>,
+
+    /// Network connection type.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connectiontype: Option<ConnectionType>,
+
+    /// ID sanctioned for advertiser use in the clear.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub ifa: Option<Cow<'a, str>>,
+
+    /// Hardware device ID (e.g., IMEI); hashed via SHA1.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub didsha1: Option<Cow<'a, str>>,
+
+    /// Hardware device ID (e.g., IMEI); hashed via MD5.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub didmd5: Option<Cow<'a, str>>,
+
+    /// Platform device ID (e.g., Android ID); hashed via SHA1.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub dpidsha1: Option<Cow<'a, str>>,
+
+    /// Platform device ID (e.g., Android ID); hashed via MD5.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub dpidmd5: Option<Cow<'a, str>>,
+
+    /// MAC address of the device; hashed via SHA1.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub macsha1: Option<Cow<'a, str>>,
+
+    /// MAC address of the device; hashed via MD5.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub macmd5: Option<Cow<'a, str>>,
+
+    /// Structured user agent information.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sua: Option<UserAgent>,
+
+    /// Placeholder for exchange-specific extensions to OpenRTB.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ext: Option<Ext>,
+}
+
+impl<Ext> Device<'_, Ext> {
+    /// Convert into the owned [`v2_6::Device`]
+    pub fn into_owned(self) -> v2_6::Device<Ext> {
+        v2_6::Device {
+            ua: self.ua.map(Cow::into_owned),
+            geo: self.geo.map(Geo::into_owned),
+            dnt: self.dnt,
+            lmt: self.lmt,
+            ip: self.ip.map(Cow::into_owned),
+            ipv6: self.ipv6.map(Cow::into_owned),
+            devicetype: self.devicetype,
+            make: self.make.map(Cow::into_owned),
+            model: self.model.map(Cow::into_owned),
+            os: self.os.map(Cow::into_owned),
+            osv: self.osv.map(Cow::into_owned),
+            hwv: self.hwv.map(Cow::into_owned),
+            h: self.h,
+            w: self.w,
+            ppi: self.ppi,
+            pxratio: self.pxratio,
+            js: self.js,
+            geofetch: self.geofetch,
+            flashver: self.flashver.map(Cow::into_owned),
+            language: self.language.map(Cow::into_owned),
+            carrier: self.carrier.map(Cow::into_owned),
+            mccmnc: self.mccmnc.map(Cow::into_owned),
+            connectiontype: self.connectiontype,
+            ifa: self.ifa.map(Cow::into_owned),
+            didsha1: self.didsha1.map(Cow::into_owned),
+            didmd5: self.didmd5.map(Cow::into_owned),
+            dpidsha1: self.dpidsha1.map(Cow::into_owned),
+            dpidmd5: self.dpidmd5.map(Cow::into_owned),
+            macsha1: self.macsha1.map(Cow::into_owned),
+            macmd5: self.macmd5.map(Cow::into_owned),
+            sua: self.sua,
+            ext: self.ext,
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use super::*;
+use crate::core::v2_6;
+use crate::core::v2_6::enums::*;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::borrow::Cow;
+
+/// [`v2_6::Geo`] borrowing its strings from the deserialized input
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Geo<'a, Ext = Value> {
+    /// Latitude from -90.0 to +90.0, where negative is south.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lat: Option<f64>,
+
+    /// Longitude from -180.0 to +180.0, where negative is west.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lon: Option<f64>,
+
+    /// Source of location data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<LocationType>,
+
+    /// Estimated location accuracy in meters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accuracy: Option<u32>,
+
+    /// Number of seconds since this geolocation fix was established.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lastfix: Option<u32>,
+
+    /// Service or provider used to determine geolocation from IP address if applicable.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ipservice: Option<LocationService>,
+
+    /// Country code using ISO-3166-1-alpha-3.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub country: Option<Cow<'a, str>>,
+
+    /// Region code using ISO-3166-2; 2-letter state code if USA.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub region: Option<Cow<'a, str>>,
+
+    /// Region of a country using FIPS 10-4 notation.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub regionfips104: Option<Cow<'a, str>>,
+
+    /// Google metro code; similar to but not exactly Nielsen DMAs.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub metro: Option<Cow<'a, str>>,
+
+    /// City using United Nations Code for Trade & Transport Locations.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub city: Option<Cow<'a, str>>,
+
+    /// Zip or postal code.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub zip: Option<Cow<'a, str>>,
+
+    /// Local time as the number +/- of minutes from UTC.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub utcoffset: Option<u32>,
+
+    /// Placeholder for exchange-specific extensions to OpenRTB.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ext: Option<Ext>,
+}
+
+impl<Ext> Geo<'_, Ext> {
+    /// Convert into the owned [`v2_6::Geo`]
+    pub fn into_owned(self) -> v2_6::Geo<Ext> {
+        v2_6::Geo {
+            lat: self.lat,
+            lon: self.lon,
+            r#type: self.r#type,
+            accuracy: self.accuracy,
+            lastfix: self.lastfix,
+            ipservice: self.ipservice,
+            country: self.country.map(Cow::into_owned),
+            region: self.region.map(Cow::into_owned),
+            regionfips104: self.regionfips104.map(Cow::into_owned),
+            metro: self.metro.map(Cow::into_owned),
+            city: self.city.map(Cow::into_owned),
+            zip: self.zip.map(Cow::into_owned),
+            utcoffset: self.utcoffset,
+            ext: self.ext,
+        }
+    }
+}
@@ -0,0 +1,139 @@
+use super::*;
+use crate::core::v2_6;
+use crate::core::v2_6::enums::*;
+use crate::core::v2_6::{Audio, Banner, Metric, Native, Pmp, Video};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::borrow::Cow;
+
+/// [`v2_6::Impression`] borrowing its strings from the deserialized input
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Impression<'a, Ext = Value> {
+    /// A unique identifier for this impression within the context of the bid request.
+    #[serde(borrow)]
+    pub id: Cow<'a, str>,
+
+    /// A `Banner` object; required if this impression is offered as a banner ad opportunity.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub banner: Option<Banner>,
+
+    /// A `Video` object; required if this impression is offered as a video ad opportunity.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video: Option<Video>,
+
+    /// An `Audio` object; required if this impression is offered as an audio ad opportunity.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audio: Option<Audio>,
+
+    /// A `Native` object; required if this impression is offered as a native ad opportunity.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub native: Option<Native>,
+
+    /// A `Pmp` object containing any private marketplace deals in effect for this impression.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pmp: Option<Pmp>,
+
+    /// Name of ad mediation partner, SDK technology, or player responsible for rendering ad.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub displaymanager: Option<Cow<'a, str>>,
+
+    /// Version of ad mediation partner, SDK technology, or player responsible for rendering ad.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub displaymanagerver: Option<Cow<'a, str>>,
+
+    /// 1 = the ad is interstitial or full screen, 0 = not interstitial.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub instl: Option<InterstitialFlag>,
+
+    /// Identifier for specific ad placement or ad tag that was used to initiate the auction.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub tagid: Option<Cow<'a, str>>,
+
+    /// Minimum bid for this impression expressed in CPM.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bidfloor: Option<f64>,
+
+    /// Currency specified using ISO-4217 alpha codes.
+    #[serde(
+        borrow,
+        default = "default_bidfloorcur",
+        deserialize_with = "borrow_option_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub bidfloorcur: Option<Cow<'a, str>>,
+
+    /// Indicates the type of browser opened upon clicking the creative in an app.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub clickbrowser: Option<ClickBrowser>,
+
+    /// Flag to indicate if the impression requires secure HTTPS URL creative assets and markup.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secure: Option<SecureFlag>,
+
+    /// Array of exchange-specific names of supported iframe busters.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_vec_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub iframebuster: Option<Vec<Cow<'a, str>>>,
+
+    /// Advisory as to the number of seconds the bidder is willing to wait between the auction and
+    /// the actual impression.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exp: Option<u32>,
+
+    /// Array of `Metric` objects.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metric: Option<Vec<Metric>>,
+
+    /// Placeholder for exchange-specific extensions to OpenRTB.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ext: Option<Ext>,
+}
+
+impl<Ext> Impression<'_, Ext> {
+    /// Convert into the owned [`v2_6::Impression`]
+    pub fn into_owned(self) -> v2_6::Impression<Ext> {
+        v2_6::Impression {
+            id: self.id.into_owned(),
+            banner: self.banner,
+            video: self.video,
+            audio: self.audio,
+            native: self.native,
+            pmp: self.pmp,
+            displaymanager: self.displaymanager.map(Cow::into_owned),
+            displaymanagerver: self.displaymanagerver.map(Cow::into_owned),
+            instl: self.instl,
+            tagid: self.tagid.map(Cow::into_owned),
+            bidfloor: self.bidfloor,
+            bidfloorcur: self.bidfloorcur.map(Cow::into_owned),
+            clickbrowser: self.clickbrowser,
+            secure: self.secure,
+            iframebuster: self.iframebuster.map(into_owned_vec),
+            exp: self.exp,
+            metric: self.metric,
+            ext: self.ext,
+        }
+    }
+}
+
+fn default_bidfloorcur<'a>() -> Option<Cow<'a, str>> {
+    Some(Cow::Borrowed("USD"))
+}
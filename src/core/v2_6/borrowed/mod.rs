@@ -0,0 +1,136 @@
+//! Borrowed variants of the most allocation-heavy OpenRTB 2.6 request objects
+//!
+//! These types deserialize string fields as `Cow<'a, str>` pointing into the input
+//! buffer, allocating only for strings that contain JSON escapes. Objects that carry
+//! few strings (e.g. `Banner`, `Video`, `Regs`) keep their owned types. `into_owned()`
+//! converts to the types of [`crate::core::v2_6`].
+
+pub mod app;
+pub mod bid_request;
+pub mod data;
+pub mod device;
+pub mod geo;
+pub mod impression;
+pub mod publisher;
+pub mod segment;
+pub mod site;
+pub mod user;
+
+pub use app::*;
+pub use bid_request::*;
+pub use data::*;
+pub use device::*;
+pub use geo::*;
+pub use impression::*;
+pub use publisher::*;
+pub use segment::*;
+pub use site::*;
+pub use user::*;
+
+use serde::de::{Deserialize, Deserializer, Visitor};
+use std::borrow::Cow;
+
+/// A string that borrows from the input unless it had to be unescaped
+///
+/// serde only borrows into a bare `Cow<str>` field; inside `Option` or `Vec` the
+/// standard impl always allocates.
+struct CowStr<'a>(Cow<'a, str>);
+
+impl<'de: 'a, 'a> Deserialize<'de> for CowStr<'a> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct CowStrVisitor;
+
+        impl<'de> Visitor<'de> for CowStrVisitor {
+            type Value = Cow<'de, str>;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str("a string")
+            }
+
+            fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Self::Value, E> {
+                Ok(Cow::Borrowed(v))
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> {
+                Ok(Cow::Owned(v.to_string()))
+            }
+
+            fn visit_string<E>(self, v: String) -> Result<Self::Value, E> {
+                Ok(Cow::Owned(v))
+            }
+        }
+
+        deserializer.deserialize_str(CowStrVisitor).map(CowStr)
+    }
+}
+
+pub(crate) fn borrow_option_str<'de: 'a, 'a, D>(
+    deserializer: D,
+) -> Result<Option<Cow<'a, str>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(Option::<CowStr>::deserialize(deserializer)?.map(|s| s.0))
+}
+
+fn borrow_option_vec_str<'de: 'a, 'a, D>(
+    deserializer: D,
+) -> Result<Option<Vec<Cow<'a, str>>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(Option::<Vec<CowStr>>::deserialize(deserializer)?
+        .map(|v| v.into_iter().map(|s| s.0).collect()))
+}
+
+fn into_owned_vec(v: Vec<Cow<'_, str>>) -> Vec<String> {
+    v.into_iter().map(Cow::into_owned).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REQUEST: &str = r#"{
+        "id": "req-1",
+        "imp": [{"id": "1", "tagid": "top", "banner": {"w": 300, "h": 250}}],
+        "site": {
+            "id": "s",
+            "page": "https://example.com/a\/b",
+            "cat": ["IAB1"],
+            "publisher": {"id": "p"}
+        },
+        "device": {"ua": "Mozilla/5.0", "ip": "192.0.2.1", "geo": {"country": "USA"}},
+        "user": {"id": "u", "data": [{"id": "d", "segment": [{"id": "seg", "value": "1"}]}]},
+        "bcat": ["IAB25"],
+        "ext": {"k": 1}
+    }"#;
+
+    #[test]
+    fn test_borrowed_request() {
+        let request: BidRequest = serde_json::from_str(REQUEST).unwrap();
+        assert!(matches!(request.id, Cow::Borrowed("req-1")));
+        assert!(matches!(request.imp[0].tagid, Some(Cow::Borrowed("top"))));
+        assert_eq!(request.imp[0].bidfloorcur.as_deref(), Some("USD"));
+
+        let site = request.site.as_ref().unwrap();
+        assert!(matches!(site.page, Some(Cow::Owned(_))));
+        assert!(matches!(
+            site.cat.as_ref().unwrap()[0],
+            Cow::Borrowed("IAB1")
+        ));
+        let device = request.device.as_ref().unwrap();
+        assert!(matches!(device.ua, Some(Cow::Borrowed(_))));
+        let segment = &request.user.as_ref().unwrap().data.as_ref().unwrap()[0]
+            .segment
+            .as_ref()
+            .unwrap()[0];
+        assert!(matches!(segment.value, Some(Cow::Borrowed("1"))));
+
+        let owned: crate::core::v2_6::BidRequest = serde_json::from_str(REQUEST).unwrap();
+        assert_eq!(request.into_owned(), owned);
+    }
+}
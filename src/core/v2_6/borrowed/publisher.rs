@@ -0,0 +1,68 @@
+use super::*;
+use crate::core::v2_6;
+use crate::core::v2_6::enums::*;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::borrow::Cow;
+
+/// [`v2_6::Publisher`] borrowing its strings from the deserialized input
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Publisher<'a, Ext = Value> {
+    /// Exchange-specific publisher ID.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub id: Option<Cow<'a, str>>,
+
+    /// Publisher name (may be aliased at the publisher's request).
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub name: Option<Cow<'a, str>>,
+
+    /// Array of IAB content categories that describe the publisher.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_vec_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub cat: Option<Vec<Cow<'a, str>>>,
+
+    /// The taxonomy in use for the cat attribute.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cattax: Option<ContentTaxonomy>,
+
+    /// Highest level domain of the publisher (e.g., "publisher.com").
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub domain: Option<Cow<'a, str>>,
+
+    /// Placeholder for exchange-specific extensions to OpenRTB.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ext: Option<Ext>,
+}
+
+impl<Ext> Publisher<'_, Ext> {
+    /// Convert into the owned [`v2_6::Publisher`]
+    pub fn into_owned(self) -> v2_6::Publisher<Ext> {
+        v2_6::Publisher {
+            id: self.id.map(Cow::into_owned),
+            name: self.name.map(Cow::into_owned),
+            cat: self.cat.map(into_owned_vec),
+            cattax: self.cattax,
+            domain: self.domain.map(Cow::into_owned),
+            ext: self.ext,
+        }
+    }
+}
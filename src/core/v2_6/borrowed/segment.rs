@@ -0,0 +1,52 @@
+use super::*;
+use crate::core::v2_6;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::borrow::Cow;
+
+/// [`v2_6::Segment`] borrowing its strings from the deserialized input
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Segment<'a, Ext = Value> {
+    /// ID of the data segment specific to the data provider.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub id: Option<Cow<'a, str>>,
+
+    /// Name of the data segment specific to the data provider.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub name: Option<Cow<'a, str>>,
+
+    /// String representation of the data segment value.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub value: Option<Cow<'a, str>>,
+
+    /// Placeholder for exchange-specific extensions to OpenRTB.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ext: Option<Ext>,
+}
+
+impl<Ext> Segment<'_, Ext> {
+    /// Convert into the owned [`v2_6::Segment`]
+    pub fn into_owned(self) -> v2_6::Segment<Ext> {
+        v2_6::Segment {
+            id: self.id.map(Cow::into_owned),
+            name: self.name.map(Cow::into_owned),
+            value: self.value.map(Cow::into_owned),
+            ext: self.ext,
+        }
+    }
+}
@@ -0,0 +1,149 @@
+use super::*;
+use crate::core::v2_6;
+use crate::core::v2_6::enums::*;
+use crate::core::v2_6::Content;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::borrow::Cow;
+
+/// [`v2_6::Site`] borrowing its strings from the deserialized input
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Site<'a, Ext = Value> {
+    /// Exchange-specific site ID.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub id: Option<Cow<'a, str>>,
+
+    /// Site name (may be aliased at the publisher's request).
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub name: Option<Cow<'a, str>>,
+
+    /// Domain of the site (e.g., "mysite.foo.com").
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub domain: Option<Cow<'a, str>>,
+
+    /// Array of IAB content categories of the site.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_vec_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub cat: Option<Vec<Cow<'a, str>>>,
+
+    /// The taxonomy in use for the cat attribute.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cattax: Option<ContentTaxonomy>,
+
+    /// Array of IAB content categories that describe the current section of the site.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_vec_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub sectioncat: Option<Vec<Cow<'a, str>>>,
+
+    /// Array of IAB content categories that describe the current page or view of the site.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_vec_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub pagecat: Option<Vec<Cow<'a, str>>>,
+
+    /// URL of the page where the impression will be shown.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub page: Option<Cow<'a, str>>,
+
+    /// Referrer URL that caused navigation to the current page.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub r#ref: Option<Cow<'a, str>>,
+
+    /// Search string that caused navigation to the current page.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub search: Option<Cow<'a, str>>,
+
+    /// Indicates if the site has been programmatically crawled or human curated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mobile: Option<u32>,
+
+    /// Indicates if the site has a privacy policy.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub privacypolicy: Option<u32>,
+
+    /// Details about the Publisher of the site.
+    #[serde(borrow, skip_serializing_if = "Option::is_none")]
+    pub publisher: Option<Publisher<'a>>,
+
+    /// Details about the Content within the site.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<Content>,
+
+    /// Comma separated list of keywords about the site.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub keywords: Option<Cow<'a, str>>,
+
+    /// Placeholder for exchange-specific extensions to OpenRTB.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ext: Option<Ext>,
+}
+
+impl<Ext> Site<'_, Ext> {
+    /// Convert into the owned [`v2_6::Site`]
+    pub fn into_owned(self) -> v2_6::Site<Ext> {
+        v2_6::Site {
+            id: self.id.map(Cow::into_owned),
+            name: self.name.map(Cow::into_owned),
+            domain: self.domain.map(Cow::into_owned),
+            cat: self.cat.map(into_owned_vec),
+            cattax: self.cattax,
+            sectioncat: self.sectioncat.map(into_owned_vec),
+            pagecat: self.pagecat.map(into_owned_vec),
+            page: self.page.map(Cow::into_owned),
+            r#ref: self.r#ref.map(Cow::into_owned),
+            search: self.search.map(Cow::into_owned),
+            mobile: self.mobile,
+            privacypolicy: self.privacypolicy,
+            publisher: self.publisher.map(Publisher::into_owned),
+            content: self.content,
+            keywords: self.keywords.map(Cow::into_owned),
+            ext: self.ext,
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use super::*;
+use crate::core::v2_6;
+use crate::core::v2_6::{Eid, UserAgent};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::borrow::Cow;
+
+/// [`v2_6::User`] borrowing its strings from the deserialized input
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct User<'a, Ext = Value> {
+    /// Exchange-specific ID for the user.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub id: Option<Cow<'a, str>>,
+
+    /// Buyer-specific ID for the user as mapped by the exchange for the buyer.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub buyeruid: Option<Cow<'a, str>>,
+
+    /// Year of birth as a 4-digit integer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub yob: Option<u32>,
+
+    /// Gender, where "M" = male, "F" = female, "O" = known to be other.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub gender: Option<Cow<'a, str>>,
+
+    /// Comma separated list of keywords, interests, or intent.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub keywords: Option<Cow<'a, str>>,
+
+    /// Optional feature to pass bidder data that was set in the exchange's cookie.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub customdata: Option<Cow<'a, str>>,
+
+    /// Location of the user's home base defined by a `Geo` object.
+    #[serde(borrow, skip_serializing_if = "Option::is_none")]
+    pub geo: Option<Geo<'a>>,
+
+    /// Additional user data. Each `Data` object represents a different data source.
+    #[serde(borrow, skip_serializing_if = "Option::is_none")]
+    pub data: Option<Vec<Data<'a>>>,
+
+    /// When GDPR regulations are in effect this attribute contains the Transparency and Consent
+    /// Framework's Consent String data structure.
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option_str",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub consent: Option<Cow<'a, str>>,
+
+    /// Details for support of a standard protocol for multiple third party identity providers.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub eids: Option<Vec<Eid>>,
+
+    /// Structured user agent information.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sua: Option<UserAgent>,
+
+    /// Placeholder for exchange-specific extensions to OpenRTB.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ext: Option<Ext>,
+}
+
+impl<Ext> User<'_, Ext> {
+    /// Convert into the owned [`v2_6::User`]
+    pub fn into_owned(self) -> v2_6::User<Ext> {
+        v2_6::User {
+            id: self.id.map(Cow::into_owned),
+            buyeruid: self.buyeruid.map(Cow::into_owned),
+            yob: self.yob,
+            gender: self.gender.map(Cow::into_owned),
+            keywords: self.keywords.map(Cow::into_owned),
+            customdata: self.customdata.map(Cow::into_owned),
+            geo: self.geo.map(Geo::into_owned),
+            data: self
+                .data
+                .map(|v| v.into_iter().map(Data::into_owned).collect()),
+            consent: self.consent.map(Cow::into_owned),
+            eids: self.eids,
+            sua: self.sua,
+            ext: self.ext,
+        }
+    }
+}
@@ -0,0 +1,779 @@
+//! Typed builders for the OpenRTB 2.6 objects
+//!
+//! `X::builder` takes the required fields of `X`; optional fields are set through the
+//! returned builder. Common impression shapes have ready-made constructors.
+
+use super::enums::*;
+use super::*;
+use crate::builder::{IntoField, builder};
+#[cfg(feature = "native")]
+use crate::native::v1_2::NativeRequest;
+
+builder! {
+    App<Ext> => AppBuilder {
+        required {}
+        optional {
+            id: String,
+            name: String,
+            bundle: String,
+            domain: String,
+            storeurl: String,
+            cat: Vec<String>,
+            cattax: ContentTaxonomy,
+            sectioncat: Vec<String>,
+            pagecat: Vec<String>,
+            ver: String,
+            privacypolicy: u32,
+            paid: u32,
+            publisher: Publisher,
+            content: Content,
+            keywords: String,
+            ext: Ext,
+        }
+    }
+}
+
+builder! {
+    Audio<Ext> => AudioBuilder {
+        required {
+            mimes: Vec<String>,
+        }
+        optional {
+            minduration: u32,
+            maxduration: u32,
+            protocols: Vec<u32>,
+            startdelay: u32,
+            sequence: u32,
+            battr: Vec<u32>,
+            maxextended: u32,
+            minbitrate: u32,
+            maxbitrate: u32,
+            delivery: Vec<ContentDeliveryMethod>,
+            companionad: Vec<Banner>,
+            api: Vec<u32>,
+            companiontype: Vec<u32>,
+            maxseq: u32,
+            feed: u32,
+            stitched: u32,
+            nvol: u32,
+            ext: Ext,
+        }
+    }
+}
+
+builder! {
+    Banner<Ext> => BannerBuilder {
+        required {}
+        optional {
+            format: Vec<Format>,
+            w: u32,
+            h: u32,
+            wmax: u32,
+            hmax: u32,
+            wmin: u32,
+            hmin: u32,
+            id: String,
+            btype: Vec<BannerAdType>,
+            battr: Vec<CreativeAttribute>,
+            pos: AdPosition,
+            mimes: Vec<String>,
+            topframe: TopFrameFlag,
+            expdir: Vec<ExpandableDirection>,
+            api: Vec<ApiFramework>,
+            ext: Ext,
+        }
+    }
+}
+
+builder! {
+    Bid<Ext> => BidBuilder {
+        required {
+            id: String,
+            impid: String,
+            price: f64,
+        }
+        optional {
+            nurl: String,
+            burl: String,
+            lurl: String,
+            adm: String,
+            adid: String,
+            adomain: Vec<String>,
+            bundle: String,
+            iurl: String,
+            cid: String,
+            crid: String,
+            tactic: String,
+            cat: Vec<String>,
+            cattax: ContentTaxonomy,
+            attr: Vec<u32>,
+            apis: Vec<u32>,
+            api: u32,
+            protocol: u32,
+            qagmediarating: u32,
+            language: String,
+            dealid: String,
+            w: u32,
+            h: u32,
+            wratio: u32,
+            hratio: u32,
+            exp: u32,
+            dur: u32,
+            slotinpod: SlotPositionInPod,
+            ext: Ext,
+        }
+    }
+}
+
+builder! {
+    BidRequest<Ext> => BidRequestBuilder {
+        required {
+            id: String,
+            imp: Vec<Impression>,
+        }
+        optional {
+            site: Site,
+            app: App,
+            dooh: Dooh,
+            device: Device,
+            user: User,
+            test: TestMode,
+            at: AuctionType = bid_request::default_at(),
+            tmax: u32,
+            wseat: Vec<String>,
+            bseat: Vec<String>,
+            allimps: AllImpsFlag,
+            cur: Vec<String>,
+            wlang: Vec<String>,
+            cacheid: String,
+            bcat: Vec<String>,
+            cattax: ContentTaxonomy,
+            badv: Vec<String>,
+            bapp: Vec<String>,
+            source: Source,
+            regs: Regs,
+            ext: Ext,
+        }
+    }
+}
+
+builder! {
+    BidResponse<Ext> => BidResponseBuilder {
+        required {
+            id: String,
+        }
+        optional {
+            seatbid: Vec<SeatBid>,
+            bidid: String,
+            cur: String = bid_response::default_cur(),
+            customdata: String,
+            nbr: u32,
+            ext: Ext,
+        }
+    }
+}
+
+builder! {
+    Brand<Ext> => BrandBuilder {
+        required {}
+        optional {
+            brand: String,
+            version: Vec<String>,
+            ext: Ext,
+        }
+    }
+}
+
+builder! {
+    Channel<Ext> => ChannelBuilder {
+        required {}
+        optional {
+            id: String,
+            name: String,
+            domain: String,
+            ext: Ext,
+        }
+    }
+}
+
+builder! {
+    Content<Ext> => ContentBuilder {
+        required {}
+        optional {
+            id: String,
+            episode: u32,
+            title: String,
+            series: String,
+            season: String,
+            artist: String,
+            genre: String,
+            album: String,
+            isrc: String,
+            producer: Producer,
+            url: String,
+            cat: Vec<String>,
+            cattax: ContentTaxonomy,
+            prodq: u32,
+            videoquality: u32,
+            context: u32,
+            contentrating: String,
+            userrating: String,
+            qagmediarating: u32,
+            keywords: String,
+            livestream: u32,
+            sourcerelationship: u32,
+            len: u32,
+            language: String,
+            embeddable: u32,
+            data: Vec<Data>,
+            network: Network,
+            channel: Channel,
+            ext: Ext,
+        }
+    }
+}
+
+builder! {
+    Data<Ext> => DataBuilder {
+        required {}
+        optional {
+            id: String,
+            name: String,
+            segment: Vec<Segment>,
+            ext: Ext,
+        }
+    }
+}
+
+builder! {
+    Deal<Ext> => DealBuilder {
+        required {
+            id: String,
+        }
+        optional {
+            bidfloor: f64,
+            bidfloorcur: String = deal::default_bidfloorcur(),
+            at: u32,
+            wseat: Vec<String>,
+            wadv: Vec<String>,
+            ext: Ext,
+        }
+    }
+}
+
+builder! {
+    Device<Ext> => DeviceBuilder {
+        required {}
+        optional {
+            ua: String,
+            geo: Geo,
+            dnt: DoNotTrackFlag,
+            lmt: LimitAdTrackingFlag,
+            ip: String,
+            ipv6: String,
+            devicetype: DeviceType,
+            make: String,
+            model: String,
+            os: String,
+            osv: String,
+            hwv: String,
+            h: u32,
+            w: u32,
+            ppi: u32,
+            pxratio: f64,
+            js: JavaScriptFlag,
+            geofetch: GeoFetchFlag,
+            flashver: String,
+            language: String,
+            carrier: String,
+            mccmnc: String,
+            connectiontype: ConnectionType,
+            ifa: String,
+            didsha1: String,
+            didmd5: String,
+            dpidsha1: String,
+            dpidmd5: String,
+            macsha1: String,
+            macmd5: String,
+            sua: UserAgent,
+            ext: Ext,
+        }
+    }
+}
+
+builder! {
+    Dooh<Ext> => DoohBuilder {
+        required {}
+        optional {
+            id: String,
+            name: String,
+            venuetype: Vec<String>,
+            venuetax: u32,
+            publisher: Publisher,
+            domain: String,
+            cat: Vec<String>,
+            cattax: ContentTaxonomy,
+            keywords: String,
+            content: Content,
+            ext: Ext,
+        }
+    }
+}
+
+builder! {
+    Eid<Ext> => EidBuilder {
+        required {
+            source: String,
+            uids: Vec<Uid>,
+        }
+        optional {
+            inserter: String,
+            matcher: String,
+            mm: u32,
+            ext: Ext,
+        }
+    }
+}
+
+#[cfg(feature = "native")]
+builder! {
+    Native<Ext> => NativeBuilder {
+        required {
+            request: NativeRequest,
+        }
+        optional {
+            ver: String,
+            api: Vec<u32>,
+            battr: Vec<u32>,
+            ext: Ext,
+        }
+    }
+}
+
+#[cfg(not(feature = "native"))]
+builder! {
+    Native<Ext> => NativeBuilder {
+        required {
+            request: String,
+        }
+        optional {
+            ver: String,
+            api: Vec<u32>,
+            battr: Vec<u32>,
+            ext: Ext,
+        }
+    }
+}
+
+builder! {
+    Uid<Ext> => UidBuilder {
+        required {
+            id: String,
+        }
+        optional {
+            atype: u32,
+            ext: Ext,
+        }
+    }
+}
+
+builder! {
+    Format<Ext> => FormatBuilder {
+        required {}
+        optional {
+            w: u32,
+            h: u32,
+            wratio: u32,
+            hratio: u32,
+            wmin: u32,
+            ext: Ext,
+        }
+    }
+}
+
+builder! {
+    Geo<Ext> => GeoBuilder {
+        required {}
+        optional {
+            lat: f64,
+            lon: f64,
+            r#type: LocationType,
+            accuracy: u32,
+            lastfix: u32,
+            ipservice: LocationService,
+            country: String,
+            region: String,
+            regionfips104: String,
+            metro: String,
+            city: String,
+            zip: String,
+            utcoffset: u32,
+            ext: Ext,
+        }
+    }
+}
+
+builder! {
+    Impression<Ext> => ImpressionBuilder {
+        required {
+            id: String,
+        }
+        optional {
+            banner: Banner,
+            video: Video,
+            audio: Audio,
+            native: Native,
+            pmp: Pmp,
+            displaymanager: String,
+            displaymanagerver: String,
+            instl: InterstitialFlag,
+            tagid: String,
+            bidfloor: f64,
+            bidfloorcur: String = impression::default_bidfloorcur(),
+            clickbrowser: ClickBrowser,
+            secure: SecureFlag,
+            iframebuster: Vec<String>,
+            exp: u32,
+            metric: Vec<Metric>,
+            ext: Ext,
+        }
+    }
+}
+
+builder! {
+    Metric<Ext> => MetricBuilder {
+        required {
+            r#type: String,
+            value: f64,
+        }
+        optional {
+            vendor: String,
+            ext: Ext,
+        }
+    }
+}
+
+builder! {
+    Network<Ext> => NetworkBuilder {
+        required {}
+        optional {
+            id: String,
+            name: String,
+            domain: String,
+            ext: Ext,
+        }
+    }
+}
+
+builder! {
+    Pmp<Ext> => PmpBuilder {
+        required {}
+        optional {
+            private_auction: u32,
+            deals: Vec<Deal>,
+            ext: Ext,
+        }
+    }
+}
+
+builder! {
+    Producer<Ext> => ProducerBuilder {
+        required {}
+        optional {
+            id: String,
+            name: String,
+            cat: Vec<String>,
+            cattax: ContentTaxonomy,
+            domain: String,
+            ext: Ext,
+        }
+    }
+}
+
+builder! {
+    Publisher<Ext> => PublisherBuilder {
+        required {}
+        optional {
+            id: String,
+            name: String,
+            cat: Vec<String>,
+            cattax: ContentTaxonomy,
+            domain: String,
+            ext: Ext,
+        }
+    }
+}
+
+builder! {
+    Regs<Ext> => RegsBuilder {
+        required {}
+        optional {
+            coppa: CoppaFlag,
+            gdpr: GdprFlag,
+            us_privacy: String,
+            ext: Ext,
+        }
+    }
+}
+
+builder! {
+    SeatBid<Ext> => SeatBidBuilder {
+        required {
+            bid: Vec<Bid>,
+        }
+        optional {
+            seat: String,
+            group: u32,
+            ext: Ext,
+        }
+    }
+}
+
+builder! {
+    Segment<Ext> => SegmentBuilder {
+        required {}
+        optional {
+            id: String,
+            name: String,
+            value: String,
+            ext: Ext,
+        }
+    }
+}
+
+builder! {
+    Site<Ext> => SiteBuilder {
+        required {}
+        optional {
+            id: String,
+            name: String,
+            domain: String,
+            cat: Vec<String>,
+            cattax: ContentTaxonomy,
+            sectioncat: Vec<String>,
+            pagecat: Vec<String>,
+            page: String,
+            r#ref: String,
+            search: String,
+            mobile: u32,
+            privacypolicy: u32,
+            publisher: Publisher,
+            content: Content,
+            keywords: String,
+            ext: Ext,
+        }
+    }
+}
+
+builder! {
+    Source<Ext> => SourceBuilder {
+        required {}
+        optional {
+            fd: FinalDecision,
+            tid: String,
+            pchain: String,
+            schain: SupplyChain,
+            ext: Ext,
+        }
+    }
+}
+
+builder! {
+    SupplyChain<Ext> => SupplyChainBuilder {
+        required {
+            complete: u32,
+            nodes: Vec<SupplyChainNode>,
+            ver: String,
+        }
+        optional {
+            ext: Ext,
+        }
+    }
+}
+
+builder! {
+    SupplyChainNode<Ext> => SupplyChainNodeBuilder {
+        required {
+            asi: String,
+            sid: String,
+        }
+        optional {
+            rid: String,
+            name: String,
+            domain: String,
+            hp: u32,
+            ext: Ext,
+        }
+    }
+}
+
+builder! {
+    User<Ext> => UserBuilder {
+        required {}
+        optional {
+            id: String,
+            buyeruid: String,
+            yob: u32,
+            gender: String,
+            keywords: String,
+            customdata: String,
+            geo: Geo,
+            data: Vec<Data>,
+            consent: String,
+            eids: Vec<Eid>,
+            sua: UserAgent,
+            ext: Ext,
+        }
+    }
+}
+
+builder! {
+    UserAgent<Ext> => UserAgentBuilder {
+        required {}
+        optional {
+            browsers: Vec<Brand>,
+            platform: Brand,
+            mobile: u32,
+            architecture: String,
+            bitness: String,
+            model: String,
+            source: UserAgentSource,
+            ext: Ext,
+        }
+    }
+}
+
+builder! {
+    Video<Ext> => VideoBuilder {
+        required {
+            mimes: Vec<String>,
+        }
+        optional {
+            minduration: u32,
+            maxduration: u32,
+            protocols: Vec<VideoBidResponseProtocol>,
+            protocol: VideoBidResponseProtocol,
+            w: u32,
+            h: u32,
+            startdelay: u32,
+            plcmt: VideoPlcmtSubtype,
+            placement: VideoPlacementType,
+            linearity: VideoLinearity,
+            skip: SkipFlag,
+            skipmin: u32 = video::default_skip_min(),
+            skipafter: u32 = video::default_skip_after(),
+            sequence: u32,
+            battr: Vec<CreativeAttribute>,
+            maxextended: u32,
+            minbitrate: u32,
+            maxbitrate: u32,
+            boxingallowed: BoxingAllowedFlag = video::default_boxingallowed(),
+            playbackmethod: Vec<PlaybackMethod>,
+            playbackend: PlaybackCessationMode,
+            delivery: Vec<ContentDeliveryMethod>,
+            pos: AdPosition,
+            companionad: Vec<Banner>,
+            api: Vec<ApiFramework>,
+            companiontype: Vec<CompanionType>,
+            maxseq: u32,
+            podid: String,
+            podseq: PodSequence,
+            poddur: u32,
+            rqddurs: Vec<u32>,
+            slotinpod: SlotPositionInPod,
+            mincpmpersec: f64,
+            feed: FeedType,
+            stitched: StitchedFlag,
+            nvol: VolumeNormalizationMode,
+            ext: Ext,
+        }
+    }
+}
+
+impl<Ext> Impression<Ext> {
+    /// Banner impression offering a single `w`x`h` format
+    pub fn banner_sized(id: impl IntoField<String>, w: u32, h: u32) -> Self {
+        let format = Format::builder().w(w).h(h).build();
+        Impression::builder(id)
+            .banner(Banner::builder().format([format]).w(w).h(h).build())
+            .build()
+    }
+
+    /// Banner impression for a 300x250 medium rectangle
+    pub fn banner_300x250(id: impl IntoField<String>) -> Self {
+        Impression::banner_sized(id, 300, 250)
+    }
+
+    /// Instream video impression accepting 15 to 30 second MP4 creatives
+    pub fn instream_video(id: impl IntoField<String>) -> Self {
+        Impression::builder(id)
+            .video(Video::instream(15, 30))
+            .build()
+    }
+}
+
+impl<Ext> Video<Ext> {
+    /// Linear pre-roll instream video accepting MP4 creatives served over VAST 2 to 4
+    pub fn instream(minduration: u32, maxduration: u32) -> Self {
+        Video::builder(["video/mp4"])
+            .minduration(minduration)
+            .maxduration(maxduration)
+            .protocols([
+                VideoBidResponseProtocol::Vast2,
+                VideoBidResponseProtocol::Vast3,
+                VideoBidResponseProtocol::Vast4,
+                VideoBidResponseProtocol::Vast2Wrapper,
+                VideoBidResponseProtocol::Vast3Wrapper,
+                VideoBidResponseProtocol::Vast4Wrapper,
+            ])
+            .startdelay(0)
+            .plcmt(VideoPlcmtSubtype::Instream)
+            .linearity(VideoLinearity::Linear)
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builders() {
+        let request: BidRequest = BidRequest::builder(
+            "req-1",
+            [
+                Impression::banner_300x250("1"),
+                Impression::instream_video("2"),
+            ],
+        )
+        .site(Site::builder().domain("example.com").build())
+        .tmax(120)
+        .cur(["USD"])
+        .build();
+
+        let banner = request.imp[0].banner.as_ref().unwrap();
+        assert_eq!((banner.w, banner.h), (Some(300), Some(250)));
+        let video = request.imp[1].video.as_ref().unwrap();
+        assert_eq!(video.mimes, ["video/mp4"]);
+        assert_eq!((video.minduration, video.maxduration), (Some(15), Some(30)));
+        assert_eq!(request.at, Some(AuctionType::SecondPricePlus));
+        assert_eq!(request.imp[0].bidfloorcur.as_deref(), Some("USD"));
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(serde_json::from_value::<BidRequest>(json).unwrap(), request);
+
+        let bid: Bid = Bid::builder("b1", "1", 1.5)
+            .adm("<div/>")
+            .w(300)
+            .h(250)
+            .build();
+        let response: BidResponse = BidResponse::builder("req-1")
+            .seatbid([SeatBid::builder([bid]).seat("seat").build()])
+            .build();
+        let json = serde_json::to_value(&response).unwrap();
+        assert_eq!(json["seatbid"][0]["bid"][0]["impid"], "1");
+        assert_eq!(
+            serde_json::from_value::<BidResponse>(json).unwrap(),
+            response
+        );
+    }
+}
@@ -0,0 +1,1471 @@
+//! IAB category validation, cross-taxonomy mapping and block list checks
+//!
+//! Content Taxonomy 1.0 codes such as `IAB1-6` are validated against the structure of the
+//! 1.0 taxonomy, and Content Taxonomy 2.x and 3.0 IDs against the embedded category tree
+//! they share. Block list entries cover their subcategories in every content taxonomy, so
+//! a 2.2 `bcat` listing Sports blocks a bid tagged Soccer. 1.0 codes are mapped onto the
+//! most specific 2.x and 3.0 categories covering them; a tier-2 code without a counterpart
+//! falls back to its tier-1 parent only when that parent maps onto a single category.
+//! Categories of other taxonomies only match within their own taxonomy.
+//!
+//! No tables are embedded for the Ad Product 1.0 and 2.0 and Audience 1.1 taxonomies, so
+//! their IDs only get a syntax check: they must be positive integers.
+
+use super::enums::*;
+use super::*;
+
+/// Tier-1 categories of Content Taxonomy 1.0 with their number of tier-2 categories,
+/// indexed by the number following `IAB`
+const CONTENT_1_0: [(&str, u32); 26] = [
+    ("Arts & Entertainment", 7),
+    ("Automotive", 23),
+    ("Business", 12),
+    ("Careers", 11),
+    ("Education", 15),
+    ("Family & Parenting", 9),
+    ("Health & Fitness", 45),
+    ("Food & Drink", 18),
+    ("Hobbies & Interests", 31),
+    ("Home & Garden", 9),
+    ("Law, Gov't & Politics", 5),
+    ("News", 3),
+    ("Personal Finance", 12),
+    ("Society", 8),
+    ("Science", 10),
+    ("Pets", 7),
+    ("Sports", 44),
+    ("Style & Fashion", 6),
+    ("Technology & Computing", 36),
+    ("Travel", 27),
+    ("Real Estate", 3),
+    ("Shopping", 4),
+    ("Religion & Spirituality", 10),
+    ("Uncategorized", 0),
+    ("Non-Standard Content", 7),
+    ("Illegal Content", 4),
+];
+
+/// Categories of Content Taxonomy 2.x and 3.0 as `(id, parent, name)`, tier-1 categories
+/// having no parent
+const CONTENT_2_X: &[(&str, Option<&str>, &str)] = &[
+    ("1", None, "Automotive"),
+    ("2", Some("1"), "Auto Body Styles"),
+    ("3", Some("2"), "Commercial Trucks"),
+    ("4", Some("2"), "Sedan"),
+    ("5", Some("2"), "Station Wagon"),
+    ("6", Some("2"), "SUV"),
+    ("7", Some("2"), "Van"),
+    ("8", Some("2"), "Convertible"),
+    ("9", Some("2"), "Coupe"),
+    ("10", Some("2"), "Crossover"),
+    ("11", Some("2"), "Hatchback"),
+    ("12", Some("2"), "Microcar"),
+    ("13", Some("2"), "Minivan"),
+    ("14", Some("2"), "Off-Road Vehicles"),
+    ("15", Some("2"), "Pickup Trucks"),
+    ("16", Some("1"), "Motorcycles"),
+    ("17", Some("1"), "Auto Type"),
+    ("18", Some("17"), "Budget Cars"),
+    ("19", Some("17"), "Certified Pre-Owned Cars"),
+    ("20", Some("17"), "Classic Cars"),
+    ("21", Some("17"), "Concept Cars"),
+    ("22", Some("17"), "Driverless Cars"),
+    ("23", Some("17"), "Green Vehicles"),
+    ("24", Some("17"), "Luxury Cars"),
+    ("25", Some("17"), "Performance Cars"),
+    ("26", Some("1"), "Car Culture"),
+    ("27", Some("1"), "Dash Cam Videos"),
+    ("28", Some("1"), "Auto Parts"),
+    ("29", Some("1"), "Auto Repair"),
+    ("30", Some("1"), "Buying and Selling Cars"),
+    ("31", Some("1"), "Auto Insurance"),
+    ("32", Some("1"), "Auto Recalls"),
+    ("33", Some("1"), "Auto Technology"),
+    ("34", Some("33"), "Auto Infotainment Technologies"),
+    ("35", Some("33"), "Auto Navigation Systems"),
+    ("36", Some("33"), "Auto Safety Technologies"),
+    ("37", Some("1"), "Auto Shows"),
+    ("38", Some("1"), "Road-Side Assistance"),
+    ("39", Some("1"), "Scooters"),
+    ("40", Some("1"), "Auto Rentals"),
+    ("41", Some("1"), "Vehicle Licensing"),
+    ("42", None, "Books and Literature"),
+    ("43", Some("42"), "Art and Photography Books"),
+    ("44", Some("42"), "Biographies"),
+    ("45", Some("42"), "Children's Literature"),
+    ("46", Some("42"), "Comics and Graphic Novels"),
+    ("47", Some("42"), "Cookbooks"),
+    ("48", Some("42"), "Fiction"),
+    ("49", Some("42"), "Poetry"),
+    ("50", Some("42"), "Travel Books"),
+    ("51", Some("42"), "Young Adult Literature"),
+    ("52", None, "Business and Finance"),
+    ("53", Some("52"), "Business"),
+    ("54", Some("53"), "Business Accounting & Finance"),
+    ("55", Some("53"), "Human Resources"),
+    ("56", Some("53"), "Large Business"),
+    ("57", Some("53"), "Logistics"),
+    ("58", Some("53"), "Marketing and Advertising"),
+    ("59", Some("53"), "Sales"),
+    ("60", Some("53"), "Small and Medium-sized Business"),
+    ("61", Some("53"), "Startups"),
+    ("62", Some("53"), "Business Administration"),
+    ("63", Some("53"), "Business Banking & Finance"),
+    ("64", Some("63"), "Angel Investment"),
+    ("65", Some("63"), "Bankruptcy"),
+    ("66", Some("63"), "Business Loans"),
+    ("67", Some("63"), "Debt Factoring & Invoice Discounting"),
+    ("68", Some("63"), "Mergers and Acquisitions"),
+    ("69", Some("63"), "Private Equity"),
+    ("70", Some("63"), "Sale & Lease Back"),
+    ("71", Some("63"), "Venture Capital"),
+    ("72", Some("53"), "Business I.T."),
+    ("73", Some("53"), "Business Operations"),
+    ("74", Some("53"), "Consumer Issues"),
+    ("75", Some("74"), "Recalls"),
+    ("76", Some("53"), "Executive Leadership & Management"),
+    ("77", Some("53"), "Government Business"),
+    ("78", Some("53"), "Green Solutions"),
+    ("79", Some("53"), "Business Utilities"),
+    ("80", Some("52"), "Economy"),
+    ("81", Some("80"), "Commodities"),
+    ("82", Some("80"), "Currencies"),
+    ("83", Some("80"), "Financial Crisis"),
+    ("84", Some("80"), "Financial Reform"),
+    ("85", Some("80"), "Financial Regulation"),
+    ("86", Some("80"), "Gasoline Prices"),
+    ("87", Some("80"), "Housing Market"),
+    ("88", Some("80"), "Interest Rates"),
+    ("89", Some("80"), "Job Market"),
+    ("90", Some("52"), "Industries"),
+    ("91", Some("90"), "Advertising Industry"),
+    ("92", Some("90"), "Education industry"),
+    ("93", Some("90"), "Entertainment Industry"),
+    ("94", Some("90"), "Environmental Services Industry"),
+    ("95", Some("90"), "Financial Industry"),
+    ("96", Some("90"), "Food Industry"),
+    ("97", Some("90"), "Healthcare Industry"),
+    ("98", Some("90"), "Hospitality Industry"),
+    ("99", Some("90"), "Information Services Industry"),
+    ("100", Some("90"), "Legal Services Industry"),
+    ("101", Some("90"), "Logistics and Transportation Industry"),
+    ("102", Some("90"), "Agriculture"),
+    ("103", Some("90"), "Management Consulting Industry"),
+    ("104", Some("90"), "Manufacturing Industry"),
+    (
+        "105",
+        Some("90"),
+        "Mechanical and Industrial Engineering Industry",
+    ),
+    ("106", Some("90"), "Media Industry"),
+    ("107", Some("90"), "Metals Industry"),
+    ("108", Some("90"), "Non-Profit Organizations"),
+    ("109", Some("90"), "Pharmaceutical Industry"),
+    ("110", Some("90"), "Power and Energy Industry"),
+    ("111", Some("90"), "Publishing Industry"),
+    ("112", Some("90"), "Real Estate Industry"),
+    ("113", Some("90"), "Apparel Industry"),
+    ("114", Some("90"), "Retail Industry"),
+    ("115", Some("90"), "Technology Industry"),
+    ("116", Some("90"), "Telecommunications Industry"),
+    ("117", Some("90"), "Automotive Industry"),
+    ("118", Some("90"), "Aviation Industry"),
+    ("119", Some("90"), "Biotech and Biomedical Industry"),
+    ("120", Some("90"), "Civil Engineering Industry"),
+    ("121", Some("90"), "Construction Industry"),
+    ("122", Some("90"), "Defense Industry"),
+    ("123", None, "Careers"),
+    ("124", Some("123"), "Apprenticeships"),
+    ("125", Some("123"), "Career Advice"),
+    ("126", Some("123"), "Career Planning"),
+    ("127", Some("123"), "Job Search"),
+    ("128", Some("127"), "Job Fairs"),
+    ("129", Some("127"), "Resume Writing and Advice"),
+    ("130", Some("123"), "Remote Working"),
+    ("131", Some("123"), "Vocational Training"),
+    ("132", None, "Education"),
+    ("133", Some("132"), "Adult Education"),
+    ("134", Some("132"), "Private School"),
+    ("135", Some("132"), "Secondary Education"),
+    ("136", Some("132"), "Special Education"),
+    ("137", Some("132"), "College Education"),
+    ("138", Some("137"), "College Planning"),
+    ("139", Some("137"), "Postgraduate Education"),
+    ("140", Some("139"), "Professional School"),
+    ("141", Some("137"), "Undergraduate Education"),
+    ("142", Some("132"), "Early Childhood Education"),
+    ("143", Some("132"), "Educational Assessment"),
+    ("144", Some("143"), "Standardized Testing"),
+    ("145", Some("132"), "Homeschooling"),
+    ("146", Some("132"), "Homework and Study"),
+    ("147", Some("132"), "Language Learning"),
+    ("148", Some("132"), "Online Education"),
+    ("149", Some("132"), "Primary Education"),
+    ("150", None, "Events and Attractions"),
+    ("151", Some("150"), "Amusement and Theme Parks"),
+    ("152", Some("150"), "Fashion Events"),
+    ("153", Some("150"), "Historic Site and Landmark Tours"),
+    ("154", Some("150"), "Malls & Shopping Centers"),
+    ("155", Some("150"), "Museums & Galleries"),
+    ("156", Some("150"), "Musicals"),
+    ("157", Some("150"), "National & Civic Holidays"),
+    ("158", Some("150"), "Nightclubs"),
+    ("159", Some("150"), "Outdoor Activities"),
+    ("160", Some("150"), "Parks & Nature"),
+    ("161", Some("150"), "Party Supplies and Decorations"),
+    ("162", Some("150"), "Awards Shows"),
+    ("163", Some("150"), "Personal Celebrations & Life Events"),
+    ("164", Some("163"), "Anniversary"),
+    ("165", Some("163"), "Wedding"),
+    ("166", Some("163"), "Baby Shower"),
+    ("167", Some("163"), "Bachelor Party"),
+    ("168", Some("163"), "Bachelorette Party"),
+    ("169", Some("163"), "Birth"),
+    ("170", Some("163"), "Birthday"),
+    ("171", Some("163"), "Funeral"),
+    ("172", Some("163"), "Graduation"),
+    ("173", Some("163"), "Prom"),
+    ("174", Some("150"), "Political Event"),
+    ("175", Some("150"), "Religious Events"),
+    ("176", Some("150"), "Sporting Events"),
+    ("177", Some("150"), "Theater Venues and Events"),
+    ("178", Some("150"), "Zoos & Aquariums"),
+    ("179", Some("150"), "Bars & Restaurants"),
+    ("180", Some("150"), "Business Expos & Conferences"),
+    ("181", Some("150"), "Casinos & Gambling"),
+    ("182", Some("150"), "Cinemas and Events"),
+    ("183", Some("150"), "Comedy Events"),
+    ("184", Some("150"), "Concerts & Music Events"),
+    ("185", Some("150"), "Fan Conventions"),
+    ("186", None, "Family and Relationships"),
+    ("187", Some("186"), "Bereavement"),
+    ("188", Some("186"), "Dating"),
+    ("189", Some("186"), "Divorce"),
+    ("190", Some("186"), "Eldercare"),
+    ("191", Some("186"), "Marriage and Civil Unions"),
+    ("192", Some("186"), "Parenting"),
+    ("193", Some("192"), "Adoption and Fostering"),
+    ("194", Some("192"), "Daycare and Pre-School"),
+    ("195", Some("192"), "Internet Safety"),
+    ("196", Some("192"), "Parenting Babies and Toddlers"),
+    ("197", Some("192"), "Parenting Children Aged 4-11"),
+    ("198", Some("192"), "Parenting Teens"),
+    ("199", Some("192"), "Special Needs Kids"),
+    ("200", Some("186"), "Single Life"),
+    ("201", None, "Fine Art"),
+    ("202", Some("201"), "Costume"),
+    ("203", Some("201"), "Dance"),
+    ("204", Some("201"), "Design"),
+    ("205", Some("201"), "Digital Arts"),
+    ("206", Some("201"), "Fine Art Photography"),
+    ("207", Some("201"), "Modern Art"),
+    ("208", Some("201"), "Opera"),
+    ("209", Some("201"), "Theater"),
+    ("210", None, "Food & Drink"),
+    ("211", Some("210"), "Alcoholic Beverages"),
+    ("212", Some("210"), "Vegan Diets"),
+    ("213", Some("210"), "Vegetarian Diets"),
+    ("214", Some("210"), "World Cuisines"),
+    ("215", Some("210"), "Barbecues and Grilling"),
+    ("216", Some("210"), "Cooking"),
+    ("217", Some("210"), "Desserts and Baking"),
+    ("218", Some("210"), "Dining Out"),
+    ("219", Some("210"), "Food Allergies"),
+    ("220", Some("210"), "Food Movements"),
+    ("221", Some("210"), "Healthy Cooking and Eating"),
+    ("222", Some("210"), "Non-Alcoholic Beverages"),
+    ("223", None, "Healthy Living"),
+    ("224", Some("223"), "Children's Health"),
+    ("225", Some("223"), "Fitness and Exercise"),
+    ("226", Some("225"), "Participant Sports"),
+    ("227", Some("225"), "Running and Jogging"),
+    ("228", Some("223"), "Men's Health"),
+    ("229", Some("223"), "Nutrition"),
+    ("230", Some("223"), "Senior Health"),
+    ("231", Some("223"), "Weight Loss"),
+    ("232", Some("223"), "Wellness"),
+    ("233", Some("232"), "Alternative Medicine"),
+    ("234", Some("233"), "Herbs and Supplements"),
+    ("235", Some("233"), "Holistic Health"),
+    ("236", Some("232"), "Physical Therapy"),
+    ("237", Some("232"), "Smoking Cessation"),
+    ("238", Some("223"), "Women's Health"),
+    ("239", None, "Hobbies & Interests"),
+    ("240", Some("239"), "Antiquing and Antiques"),
+    ("241", Some("239"), "Magic and Illusion"),
+    ("242", Some("239"), "Model Toys"),
+    ("243", Some("239"), "Musical Instruments"),
+    ("244", Some("239"), "Paranormal Phenomena"),
+    ("245", Some("239"), "Radio Control"),
+    ("246", Some("239"), "Sci-fi and Fantasy"),
+    ("247", Some("239"), "Workshops and Classes"),
+    ("248", Some("239"), "Arts and Crafts"),
+    ("249", Some("248"), "Beadwork"),
+    ("250", Some("248"), "Candle and Soap Making"),
+    ("251", Some("248"), "Drawing and Sketching"),
+    ("252", Some("248"), "Jewelry Making"),
+    ("253", Some("248"), "Needlework"),
+    ("254", Some("248"), "Painting"),
+    ("255", Some("248"), "Photography"),
+    ("256", Some("248"), "Scrapbooking"),
+    ("257", Some("248"), "Woodworking"),
+    ("258", Some("239"), "Beekeeping"),
+    ("259", Some("239"), "Birdwatching"),
+    ("260", Some("239"), "Cigars"),
+    ("261", Some("239"), "Collecting"),
+    ("262", Some("261"), "Comic Books"),
+    ("263", Some("261"), "Stamps and Coins"),
+    ("264", Some("239"), "Content Production"),
+    ("265", Some("264"), "Audio Production"),
+    ("266", Some("264"), "Freelance Writing"),
+    ("267", Some("264"), "Screenwriting"),
+    ("268", Some("264"), "Video Production"),
+    ("269", Some("239"), "Games and Puzzles"),
+    ("270", Some("269"), "Board Games and Puzzles"),
+    ("271", Some("269"), "Card Games"),
+    ("272", Some("269"), "Roleplaying Games"),
+    ("273", Some("239"), "Genealogy and Ancestry"),
+    ("274", None, "Home & Garden"),
+    ("275", Some("274"), "Gardening"),
+    ("276", Some("274"), "Remodeling & Construction"),
+    ("277", Some("274"), "Smart Home"),
+    ("278", Some("274"), "Home Appliances"),
+    ("279", Some("274"), "Home Entertaining"),
+    ("280", Some("274"), "Home Improvement"),
+    ("281", Some("274"), "Home Security"),
+    ("282", Some("274"), "Indoor Environmental Quality"),
+    ("283", Some("274"), "Interior Decorating"),
+    ("284", Some("274"), "Landscaping"),
+    ("285", Some("274"), "Outdoor Decorating"),
+    ("286", None, "Medical Health"),
+    ("287", Some("286"), "Diseases and Conditions"),
+    ("288", Some("287"), "Allergies"),
+    ("289", Some("287"), "Ear, Nose and Throat Conditions"),
+    ("290", Some("287"), "Endocrine and Metabolic Diseases"),
+    ("291", Some("290"), "Hormonal Disorders"),
+    ("292", Some("290"), "Menopause"),
+    ("293", Some("290"), "Thyroid Disorders"),
+    ("294", Some("287"), "Eye and Vision Conditions"),
+    ("295", Some("287"), "Foot Health"),
+    ("296", Some("287"), "Heart and Cardiovascular Diseases"),
+    ("297", Some("287"), "Infectious Diseases"),
+    ("298", Some("287"), "Injuries"),
+    ("299", Some("298"), "First Aid"),
+    ("300", Some("287"), "Lung and Respiratory Health"),
+    ("301", Some("287"), "Mental Health"),
+    ("302", Some("287"), "Reproductive Health"),
+    ("303", Some("302"), "Birth Control"),
+    ("304", Some("302"), "Infertility"),
+    ("305", Some("302"), "Pregnancy"),
+    ("306", Some("287"), "Blood Disorders"),
+    ("307", Some("287"), "Sexual Health"),
+    ("308", Some("307"), "Sexual Conditions"),
+    ("309", Some("287"), "Skin and Dermatology"),
+    ("310", Some("287"), "Sleep Disorders"),
+    ("311", Some("287"), "Substance Abuse"),
+    ("312", Some("287"), "Bone and Joint Conditions"),
+    ("313", Some("287"), "Brain and Nervous System Disorders"),
+    ("314", Some("287"), "Cancer"),
+    ("315", Some("287"), "Cold and Flu"),
+    ("316", Some("287"), "Dental Health"),
+    ("317", Some("287"), "Diabetes"),
+    ("318", Some("287"), "Digestive Disorders"),
+    ("319", Some("286"), "Medical Tests"),
+    ("320", Some("286"), "Pharmaceutical Drugs"),
+    ("321", Some("286"), "Surgery"),
+    ("322", Some("286"), "Vaccines"),
+    ("323", Some("286"), "Cosmetic Medical Services"),
+    ("324", None, "Movies"),
+    ("325", Some("324"), "Action and Adventure Movies"),
+    ("326", Some("324"), "Romance Movies"),
+    ("327", Some("324"), "Science Fiction Movies"),
+    ("328", Some("324"), "Indie and Arthouse Movies"),
+    ("329", Some("324"), "Animation Movies"),
+    ("330", Some("324"), "Comedy Movies"),
+    ("331", Some("324"), "Crime and Mystery Movies"),
+    ("332", Some("324"), "Documentary Movies"),
+    ("333", Some("324"), "Drama Movies"),
+    ("334", Some("324"), "Family and Children Movies"),
+    ("335", Some("324"), "Fantasy Movies"),
+    ("336", Some("324"), "Horror Movies"),
+    ("337", Some("324"), "World Movies"),
+    ("338", None, "Music and Audio"),
+    ("339", Some("338"), "Adult Contemporary Music"),
+    ("340", Some("339"), "Soft AC Music"),
+    ("341", Some("339"), "Urban AC Music"),
+    ("342", Some("338"), "Adult Album Alternative"),
+    ("343", Some("338"), "Alternative Music"),
+    ("344", Some("338"), "Children's Music"),
+    ("345", Some("338"), "Classic Hits"),
+    ("346", Some("338"), "Classical Music"),
+    ("347", Some("338"), "College Radio"),
+    ("348", Some("338"), "Comedy (Music and Audio)"),
+    ("349", Some("338"), "Contemporary Hits/Pop/Top 40"),
+    ("350", Some("338"), "Country Music"),
+    ("351", Some("338"), "Dance and Electronic Music"),
+    ("352", Some("338"), "World/International Music"),
+    ("353", Some("338"), "Songwriters/Folk"),
+    ("354", Some("338"), "Gospel Music"),
+    ("355", Some("338"), "Hip Hop Music"),
+    ("356", Some("338"), "Inspirational/New Age Music"),
+    ("357", Some("338"), "Jazz"),
+    ("358", Some("338"), "Oldies/Adult Standards"),
+    ("359", Some("338"), "Reggae"),
+    ("360", Some("338"), "Blues"),
+    ("361", Some("338"), "Religious (Music and Audio)"),
+    ("362", Some("338"), "R&B/Soul/Funk"),
+    ("363", Some("338"), "Rock Music"),
+    ("364", Some("363"), "Album-oriented Rock"),
+    ("365", Some("363"), "Alternative Rock"),
+    ("366", Some("363"), "Classic Rock"),
+    ("367", Some("363"), "Hard Rock"),
+    ("368", Some("363"), "Soft Rock"),
+    ("369", Some("338"), "Soundtracks, TV and Showtunes"),
+    ("370", Some("338"), "Sports Radio"),
+    ("371", Some("338"), "Talk Radio"),
+    ("372", Some("371"), "Business News Radio"),
+    ("373", Some("371"), "Educational Radio"),
+    ("374", Some("371"), "News Radio"),
+    ("375", Some("371"), "News/Talk Radio"),
+    ("376", Some("371"), "Public Radio"),
+    ("377", Some("338"), "Urban Contemporary Music"),
+    ("378", Some("338"), "Variety (Music and Audio)"),
+    ("379", None, "News and Politics"),
+    ("380", Some("379"), "Crime"),
+    ("381", Some("379"), "Disasters"),
+    ("382", Some("379"), "International News"),
+    ("383", Some("379"), "Law"),
+    ("384", Some("379"), "Local News"),
+    ("385", Some("379"), "National News"),
+    ("386", Some("379"), "Politics"),
+    ("387", Some("386"), "Elections"),
+    ("388", Some("386"), "Political Issues"),
+    ("389", Some("386"), "War and Conflicts"),
+    ("390", Some("379"), "Weather"),
+    ("391", None, "Personal Finance"),
+    ("392", Some("391"), "Consumer Banking"),
+    ("393", Some("391"), "Financial Assistance"),
+    ("394", Some("393"), "Government Support and Welfare"),
+    ("395", Some("393"), "Student Financial Aid"),
+    ("396", Some("391"), "Financial Planning"),
+    ("397", Some("391"), "Frugal Living"),
+    ("398", Some("391"), "Insurance"),
+    ("399", Some("398"), "Health Insurance"),
+    ("400", Some("398"), "Home Insurance"),
+    ("401", Some("398"), "Life Insurance"),
+    ("402", Some("398"), "Motor Insurance"),
+    ("403", Some("398"), "Pet Insurance"),
+    ("404", Some("398"), "Travel Insurance"),
+    ("405", Some("391"), "Personal Debt"),
+    ("406", Some("405"), "Credit Cards"),
+    ("407", Some("405"), "Home Financing"),
+    ("408", Some("405"), "Personal Loans"),
+    ("409", Some("405"), "Student Loans"),
+    ("410", Some("391"), "Personal Investing"),
+    ("411", Some("410"), "Hedge Funds"),
+    ("412", Some("410"), "Mutual Funds"),
+    ("413", Some("410"), "Options"),
+    ("414", Some("410"), "Stocks and Bonds"),
+    ("415", Some("391"), "Personal Taxes"),
+    ("416", Some("391"), "Retirement Planning"),
+    ("417", Some("391"), "Home Utilities"),
+    ("418", Some("417"), "Gas and Electric"),
+    ("419", Some("417"), "Internet Service Providers"),
+    ("420", Some("417"), "Phone Services"),
+    ("421", Some("417"), "Water Services"),
+    ("422", None, "Pets"),
+    ("423", Some("422"), "Birds"),
+    ("424", Some("422"), "Cats"),
+    ("425", Some("422"), "Dogs"),
+    ("426", Some("422"), "Fish and Aquariums"),
+    ("427", Some("422"), "Large Animals"),
+    ("428", Some("422"), "Pet Adoptions"),
+    ("429", Some("422"), "Reptiles"),
+    ("430", Some("422"), "Veterinary Medicine"),
+    ("431", Some("422"), "Pet Supplies"),
+    ("432", None, "Pop Culture"),
+    ("433", Some("432"), "Celebrity Deaths"),
+    ("434", Some("432"), "Celebrity Families"),
+    ("435", Some("432"), "Celebrity Homes"),
+    ("436", Some("432"), "Celebrity Pregnancy"),
+    ("437", Some("432"), "Celebrity Relationships"),
+    ("438", Some("432"), "Celebrity Scandal"),
+    ("439", Some("432"), "Celebrity Style"),
+    ("440", Some("432"), "Humor and Satire"),
+    ("441", None, "Real Estate"),
+    ("442", Some("441"), "Apartments"),
+    ("443", Some("441"), "Retail Property"),
+    ("444", Some("441"), "Vacation Properties"),
+    ("445", Some("441"), "Developmental Sites"),
+    ("446", Some("441"), "Hotel Properties"),
+    ("447", Some("441"), "Houses"),
+    ("448", Some("441"), "Industrial Property"),
+    ("449", Some("441"), "Land and Farms"),
+    ("450", Some("441"), "Office Property"),
+    ("451", Some("441"), "Real Estate Buying and Selling"),
+    ("452", Some("441"), "Real Estate Renting and Leasing"),
+    ("453", None, "Religion & Spirituality"),
+    ("454", Some("453"), "Agnosticism"),
+    ("455", Some("453"), "Spirituality"),
+    ("456", Some("453"), "Astrology"),
+    ("457", Some("453"), "Atheism"),
+    ("458", Some("453"), "Buddhism"),
+    ("459", Some("453"), "Christianity"),
+    ("460", Some("453"), "Hinduism"),
+    ("461", Some("453"), "Islam"),
+    ("462", Some("453"), "Judaism"),
+    ("463", Some("453"), "Sikhism"),
+    ("464", None, "Science"),
+    ("465", Some("464"), "Genetics"),
+    ("466", Some("464"), "Biological Sciences"),
+    ("467", Some("464"), "Chemistry"),
+    ("468", Some("464"), "Environment"),
+    ("469", Some("464"), "Geography"),
+    ("470", Some("464"), "Physics"),
+    ("471", Some("464"), "Space and Astronomy"),
+    ("472", Some("464"), "Geology"),
+    ("473", None, "Shopping"),
+    ("474", Some("473"), "Coupons and Discounts"),
+    ("475", Some("473"), "Flower Shopping"),
+    ("476", Some("473"), "Gifts and Greetings Cards"),
+    ("477", Some("473"), "Grocery Shopping"),
+    ("478", Some("473"), "Holiday Shopping"),
+    ("479", Some("473"), "Household Supplies"),
+    ("480", Some("473"), "Lotteries and Scratchcards"),
+    ("481", Some("473"), "Sales and Promotions"),
+    ("482", Some("473"), "Children's Games and Toys"),
+    ("483", None, "Sports"),
+    ("484", Some("483"), "American Football"),
+    ("485", Some("483"), "Boxing"),
+    ("486", Some("483"), "Cheerleading"),
+    ("487", Some("483"), "College Sports"),
+    ("488", Some("487"), "College Football"),
+    ("489", Some("487"), "College Basketball"),
+    ("490", Some("487"), "College Baseball"),
+    ("491", Some("483"), "Cricket"),
+    ("492", Some("483"), "Cycling"),
+    ("493", Some("483"), "Darts"),
+    ("494", Some("483"), "Disabled Sports"),
+    ("495", Some("483"), "Diving"),
+    ("496", Some("483"), "Equine Sports"),
+    ("497", Some("496"), "Horse Racing"),
+    ("498", Some("483"), "Extreme Sports"),
+    ("499", Some("498"), "Canoeing and Kayaking"),
+    ("500", Some("498"), "Climbing"),
+    ("501", Some("498"), "Paintball"),
+    ("502", Some("498"), "Scuba Diving"),
+    ("503", Some("498"), "Skateboarding"),
+    ("504", Some("498"), "Snowboarding"),
+    ("505", Some("498"), "Surfing and Bodyboarding"),
+    ("506", Some("498"), "Waterskiing and Wakeboarding"),
+    ("507", Some("483"), "Australian Rules Football"),
+    ("508", Some("483"), "Fantasy Sports"),
+    ("509", Some("483"), "Field Hockey"),
+    ("510", Some("483"), "Figure Skating"),
+    ("511", Some("483"), "Fishing Sports"),
+    ("512", Some("483"), "Golf"),
+    ("513", Some("483"), "Gymnastics"),
+    ("514", Some("483"), "Hunting and Shooting"),
+    ("515", Some("483"), "Ice Hockey"),
+    ("516", Some("483"), "Inline Skating"),
+    ("517", Some("483"), "Lacrosse"),
+    ("518", Some("483"), "Auto Racing"),
+    ("519", Some("518"), "Motorcycle Sports"),
+    ("520", Some("483"), "Martial Arts"),
+    ("521", Some("483"), "Olympic Sports"),
+    ("522", Some("521"), "Summer Olympic Sports"),
+    ("523", Some("521"), "Winter Olympic Sports"),
+    ("524", Some("483"), "Poker and Professional Gambling"),
+    ("525", Some("483"), "Rodeo"),
+    ("526", Some("483"), "Rowing"),
+    ("527", Some("483"), "Rugby"),
+    ("528", Some("527"), "Rugby League"),
+    ("529", Some("527"), "Rugby Union"),
+    ("530", Some("483"), "Sailing"),
+    ("531", Some("483"), "Skiing"),
+    ("532", Some("483"), "Snooker/Pool/Billiards"),
+    ("533", Some("483"), "Soccer"),
+    ("534", Some("483"), "Badminton"),
+    ("535", Some("483"), "Softball"),
+    ("536", Some("483"), "Squash"),
+    ("537", Some("483"), "Swimming"),
+    ("538", Some("483"), "Table Tennis"),
+    ("539", Some("483"), "Tennis"),
+    ("540", Some("483"), "Track and Field"),
+    ("541", Some("483"), "Volleyball"),
+    ("542", Some("483"), "Walking"),
+    ("543", Some("483"), "Water Polo"),
+    ("544", Some("483"), "Weightlifting"),
+    ("545", Some("483"), "Baseball"),
+    ("546", Some("483"), "Wrestling"),
+    ("547", Some("483"), "Basketball"),
+    ("548", Some("483"), "Beach Volleyball"),
+    ("549", Some("483"), "Bodybuilding"),
+    ("550", Some("483"), "Bowling"),
+    ("551", Some("483"), "Sports Equipment"),
+    ("552", None, "Style & Fashion"),
+    ("553", Some("552"), "Beauty"),
+    ("554", Some("553"), "Hair Care"),
+    ("555", Some("553"), "Makeup and Accessories"),
+    ("556", Some("553"), "Nail Care"),
+    ("557", Some("553"), "Natural and Organic Beauty"),
+    ("558", Some("553"), "Perfume and Fragrance"),
+    ("559", Some("553"), "Skin Care"),
+    ("560", Some("552"), "Women's Fashion"),
+    ("561", Some("560"), "Women's Accessories"),
+    ("562", Some("561"), "Women's Glasses"),
+    ("563", Some("561"), "Women's Handbags and Wallets"),
+    ("564", Some("561"), "Women's Hats and Scarves"),
+    ("565", Some("561"), "Women's Jewelry and Watches"),
+    ("566", Some("560"), "Women's Clothing"),
+    ("567", Some("566"), "Women's Business Wear"),
+    ("568", Some("566"), "Women's Casual Wear"),
+    ("569", Some("566"), "Women's Formal Wear"),
+    ("570", Some("566"), "Women's Intimates and Sleepwear"),
+    ("571", Some("566"), "Women's Outerwear"),
+    ("572", Some("566"), "Women's Sportswear"),
+    ("573", Some("560"), "Women's Shoes and Footwear"),
+    ("574", Some("552"), "Body Art"),
+    ("575", Some("552"), "Children's Clothing"),
+    ("576", Some("552"), "Designer Clothing"),
+    ("577", Some("552"), "Fashion Trends"),
+    ("578", Some("552"), "High Fashion"),
+    ("579", Some("552"), "Men's Fashion"),
+    ("580", Some("579"), "Men's Accessories"),
+    ("581", Some("580"), "Men's Jewelry and Watches"),
+    ("582", Some("579"), "Men's Clothing"),
+    ("583", Some("582"), "Men's Business Wear"),
+    ("584", Some("582"), "Men's Casual Wear"),
+    ("585", Some("582"), "Men's Formal Wear"),
+    ("586", Some("582"), "Men's Outerwear"),
+    ("587", Some("582"), "Men's Sportswear"),
+    ("588", Some("582"), "Men's Underwear and Sleepwear"),
+    ("589", Some("579"), "Men's Shoes and Footwear"),
+    ("590", Some("552"), "Personal Care"),
+    ("591", Some("590"), "Bath and Shower"),
+    ("592", Some("590"), "Deodorant and Antiperspirant"),
+    ("593", Some("590"), "Oral care"),
+    ("594", Some("590"), "Shaving"),
+    ("595", Some("552"), "Street Style"),
+    ("596", None, "Technology & Computing"),
+    ("597", Some("596"), "Artificial Intelligence"),
+    ("598", Some("596"), "Augmented Reality"),
+    ("599", Some("596"), "Computing"),
+    ("600", Some("599"), "Computer Networking"),
+    ("601", Some("599"), "Computer Peripherals"),
+    ("602", Some("599"), "Computer Software and Applications"),
+    ("603", Some("602"), "3-D Graphics"),
+    ("604", Some("602"), "Photo Editing Software"),
+    ("605", Some("602"), "Shareware and Freeware"),
+    ("606", Some("602"), "Video Software"),
+    ("607", Some("602"), "Web Conferencing"),
+    ("608", Some("602"), "Antivirus Software"),
+    ("609", Some("602"), "Browsers"),
+    ("610", Some("602"), "Computer Animation"),
+    ("611", Some("602"), "Databases"),
+    ("612", Some("602"), "Desktop Publishing"),
+    ("613", Some("602"), "Digital Audio"),
+    ("614", Some("602"), "Graphics Software"),
+    ("615", Some("602"), "Operating Systems"),
+    ("616", Some("599"), "Data Storage and Warehousing"),
+    ("617", Some("599"), "Desktops"),
+    ("618", Some("599"), "Information and Network Security"),
+    ("619", Some("599"), "Internet"),
+    ("620", Some("619"), "Cloud Computing"),
+    ("621", Some("619"), "Web Development"),
+    ("622", Some("619"), "Web Hosting"),
+    ("623", Some("619"), "Email"),
+    ("624", Some("619"), "Internet for Beginners"),
+    ("625", Some("619"), "Internet of Things"),
+    ("626", Some("619"), "IT and Internet Support"),
+    ("627", Some("619"), "Search"),
+    ("628", Some("619"), "Social Networking"),
+    ("629", Some("619"), "Web Design and HTML"),
+    ("630", Some("599"), "Laptops"),
+    ("631", Some("599"), "Programming Languages"),
+    ("632", Some("596"), "Consumer Electronics"),
+    ("633", Some("632"), "Cameras and Camcorders"),
+    ("634", Some("632"), "Home Entertainment Systems"),
+    ("635", Some("632"), "Smartphones"),
+    ("636", Some("632"), "Tablets and E-readers"),
+    ("637", Some("632"), "Wearable Technology"),
+    ("638", Some("596"), "Robotics"),
+    ("639", Some("596"), "Virtual Reality"),
+    ("640", None, "Television"),
+    ("641", Some("640"), "Animation TV"),
+    ("642", Some("640"), "Soap Opera TV"),
+    ("643", Some("640"), "Special Interest TV"),
+    ("644", Some("640"), "Sports TV"),
+    ("645", Some("640"), "Children's TV"),
+    ("646", Some("640"), "Comedy TV"),
+    ("647", Some("640"), "Drama TV"),
+    ("648", Some("640"), "Factual TV"),
+    ("649", Some("640"), "Holiday TV"),
+    ("650", Some("640"), "Music TV"),
+    ("651", Some("640"), "Reality TV"),
+    ("652", Some("640"), "Science Fiction TV"),
+    ("653", None, "Travel"),
+    ("654", Some("653"), "Travel Accessories"),
+    ("655", Some("653"), "Travel Locations"),
+    ("656", Some("655"), "Africa Travel"),
+    ("657", Some("655"), "Asia Travel"),
+    ("658", Some("655"), "Australia and Oceania Travel"),
+    ("659", Some("655"), "Europe Travel"),
+    ("660", Some("655"), "North America Travel"),
+    ("661", Some("655"), "Polar Travel"),
+    ("662", Some("655"), "South America Travel"),
+    ("663", Some("653"), "Travel Preparation and Advice"),
+    ("664", Some("653"), "Travel Type"),
+    ("665", Some("664"), "Adventure Travel"),
+    ("666", Some("664"), "Family Travel"),
+    ("667", Some("664"), "Honeymoons and Getaways"),
+    ("668", Some("664"), "Hotels and Motels"),
+    ("669", Some("664"), "Rail Travel"),
+    ("670", Some("664"), "Road Trips"),
+    ("671", Some("664"), "Spas"),
+    ("672", Some("664"), "Air Travel"),
+    ("673", Some("664"), "Beach Travel"),
+    ("674", Some("664"), "Bed & Breakfasts"),
+    ("675", Some("664"), "Budget Travel"),
+    ("676", Some("664"), "Business Travel"),
+    ("677", Some("664"), "Camping"),
+    ("678", Some("664"), "Cruises"),
+    ("679", Some("664"), "Day Trips"),
+    ("680", None, "Video Gaming"),
+    ("681", Some("680"), "Console Games"),
+    ("682", Some("680"), "eSports"),
+    ("683", Some("680"), "Mobile Games"),
+    ("684", Some("680"), "PC Games"),
+    ("685", Some("680"), "Video Game Genres"),
+    ("686", Some("685"), "Action Video Games"),
+    ("687", Some("685"), "Role-Playing Video Games"),
+    ("688", Some("685"), "Simulation Video Games"),
+    ("689", Some("685"), "Sports Video Games"),
+    ("690", Some("685"), "Strategy Video Games"),
+    ("691", Some("685"), "Action-Adventure Video Games"),
+    ("692", Some("685"), "Adventure Video Games"),
+    ("693", Some("685"), "Casual Games"),
+    ("694", Some("685"), "Educational Video Games"),
+    ("695", Some("685"), "Exercise and Fitness Video Games"),
+    ("696", Some("685"), "MMOs"),
+    ("697", Some("685"), "Music and Party Video Games"),
+    ("698", Some("685"), "Puzzle Video Games"),
+];
+
+/// Content Taxonomy 1.0 codes with the Content Taxonomy 2.x and 3.0 IDs covering them
+///
+/// Codes without a counterpart, such as `IAB25` Non-Standard Content, map onto no IDs.
+const CONTENT_1_0_TO_2_X: &[(&str, &[&str])] = &[
+    ("IAB1", &["42", "201", "324", "338", "432", "640"]),
+    ("IAB1-1", &["42"]),
+    ("IAB1-2", &["432"]),
+    ("IAB1-3", &["201"]),
+    ("IAB1-4", &["440"]),
+    ("IAB1-5", &["324"]),
+    ("IAB1-6", &["338"]),
+    ("IAB1-7", &["640"]),
+    ("IAB2", &["1"]),
+    ("IAB2-1", &["28"]),
+    ("IAB2-2", &["29"]),
+    ("IAB2-3", &["30"]),
+    ("IAB2-4", &["26"]),
+    ("IAB2-5", &["19"]),
+    ("IAB2-6", &["8"]),
+    ("IAB2-7", &["9"]),
+    ("IAB2-8", &["10"]),
+    ("IAB2-10", &["23"]),
+    ("IAB2-11", &["11"]),
+    ("IAB2-12", &["23"]),
+    ("IAB2-13", &["24"]),
+    ("IAB2-14", &["13"]),
+    ("IAB2-15", &["16"]),
+    ("IAB2-16", &["14"]),
+    ("IAB2-17", &["25"]),
+    ("IAB2-18", &["15"]),
+    ("IAB2-19", &["38"]),
+    ("IAB2-20", &["4"]),
+    ("IAB2-21", &["3"]),
+    ("IAB2-22", &["20"]),
+    ("IAB2-23", &["5"]),
+    ("IAB3", &["52"]),
+    ("IAB3-1", &["91"]),
+    ("IAB3-2", &["102"]),
+    ("IAB3-3", &["119"]),
+    ("IAB3-4", &["72"]),
+    ("IAB3-5", &["121"]),
+    ("IAB3-7", &["77"]),
+    ("IAB3-8", &["78"]),
+    ("IAB3-9", &["55"]),
+    ("IAB3-10", &["57"]),
+    ("IAB3-11", &["58"]),
+    ("IAB3-12", &["107"]),
+    ("IAB4", &["123"]),
+    ("IAB4-1", &["126"]),
+    ("IAB4-2", &["137"]),
+    ("IAB4-3", &["395"]),
+    ("IAB4-4", &["128"]),
+    ("IAB4-5", &["127"]),
+    ("IAB4-6", &["129"]),
+    ("IAB4-8", &["395"]),
+    ("IAB4-9", &["130"]),
+    ("IAB4-11", &["125"]),
+    ("IAB5", &["132"]),
+    ("IAB5-1", &["135"]),
+    ("IAB5-2", &["133"]),
+    ("IAB5-4", &["137"]),
+    ("IAB5-5", &["137"]),
+    ("IAB5-6", &["148"]),
+    ("IAB5-7", &["147"]),
+    ("IAB5-8", &["147"]),
+    ("IAB5-9", &["139"]),
+    ("IAB5-10", &["145"]),
+    ("IAB5-11", &["146"]),
+    ("IAB5-12", &["149"]),
+    ("IAB5-13", &["134"]),
+    ("IAB5-14", &["136"]),
+    ("IAB6", &["186"]),
+    ("IAB6-1", &["193"]),
+    ("IAB6-2", &["196"]),
+    ("IAB6-3", &["194"]),
+    ("IAB6-4", &["195"]),
+    ("IAB6-5", &["197"]),
+    ("IAB6-6", &["198"]),
+    ("IAB6-7", &["305"]),
+    ("IAB6-8", &["199"]),
+    ("IAB6-9", &["190"]),
+    ("IAB7", &["223", "286"]),
+    ("IAB7-1", &["225"]),
+    ("IAB7-2", &["301"]),
+    ("IAB7-3", &["297"]),
+    ("IAB7-4", &["288"]),
+    ("IAB7-5", &["233"]),
+    ("IAB7-6", &["312"]),
+    ("IAB7-7", &["300"]),
+    ("IAB7-8", &["313"]),
+    ("IAB7-9", &["301"]),
+    ("IAB7-10", &["314"]),
+    ("IAB7-11", &["314"]),
+    ("IAB7-12", &["296"]),
+    ("IAB7-13", &["287"]),
+    ("IAB7-14", &["287"]),
+    ("IAB7-15", &["315"]),
+    ("IAB7-16", &["289"]),
+    ("IAB7-17", &["316"]),
+    ("IAB7-18", &["301"]),
+    ("IAB7-19", &["309"]),
+    ("IAB7-20", &["317"]),
+    ("IAB7-21", &["313"]),
+    ("IAB7-22", &["318"]),
+    ("IAB7-23", &["313"]),
+    ("IAB7-24", &["296"]),
+    ("IAB7-25", &["234"]),
+    ("IAB7-26", &["235"]),
+    ("IAB7-27", &["318"]),
+    ("IAB7-28", &["301"]),
+    ("IAB7-29", &["287"]),
+    ("IAB7-30", &["304"]),
+    ("IAB7-31", &["228"]),
+    ("IAB7-32", &["229"]),
+    ("IAB7-33", &["312"]),
+    ("IAB7-34", &["301"]),
+    ("IAB7-35", &["224"]),
+    ("IAB7-36", &["236"]),
+    ("IAB7-37", &["301"]),
+    ("IAB7-38", &["230"]),
+    ("IAB7-39", &["307"]),
+    ("IAB7-40", &["310"]),
+    ("IAB7-41", &["237"]),
+    ("IAB7-42", &["311"]),
+    ("IAB7-43", &["293"]),
+    ("IAB7-44", &["231"]),
+    ("IAB7-45", &["238"]),
+    ("IAB8", &["210"]),
+    ("IAB8-1", &["214"]),
+    ("IAB8-2", &["215"]),
+    ("IAB8-3", &["214"]),
+    ("IAB8-4", &["214"]),
+    ("IAB8-5", &["211"]),
+    ("IAB8-6", &["222"]),
+    ("IAB8-7", &["214"]),
+    ("IAB8-8", &["217"]),
+    ("IAB8-9", &["218"]),
+    ("IAB8-10", &["219"]),
+    ("IAB8-11", &["214"]),
+    ("IAB8-12", &["221"]),
+    ("IAB8-13", &["214"]),
+    ("IAB8-14", &["214"]),
+    ("IAB8-15", &["214"]),
+    ("IAB8-16", &["212"]),
+    ("IAB8-17", &["213"]),
+    ("IAB8-18", &["211"]),
+    ("IAB9", &["239", "680"]),
+    ("IAB9-1", &["205"]),
+    ("IAB9-2", &["248"]),
+    ("IAB9-3", &["249"]),
+    ("IAB9-4", &["259"]),
+    ("IAB9-5", &["270"]),
+    ("IAB9-6", &["250"]),
+    ("IAB9-7", &["271"]),
+    ("IAB9-8", &["270"]),
+    ("IAB9-9", &["260"]),
+    ("IAB9-10", &["261"]),
+    ("IAB9-11", &["262"]),
+    ("IAB9-12", &["251"]),
+    ("IAB9-13", &["266"]),
+    ("IAB9-14", &["273"]),
+    ("IAB9-15", &["266"]),
+    ("IAB9-16", &["243"]),
+    ("IAB9-17", &["265"]),
+    ("IAB9-18", &["239"]),
+    ("IAB9-19", &["252"]),
+    ("IAB9-20", &["241"]),
+    ("IAB9-21", &["253"]),
+    ("IAB9-22", &["254"]),
+    ("IAB9-23", &["255"]),
+    ("IAB9-24", &["239"]),
+    ("IAB9-25", &["272"]),
+    ("IAB9-26", &["246"]),
+    ("IAB9-27", &["256"]),
+    ("IAB9-28", &["267"]),
+    ("IAB9-29", &["263"]),
+    ("IAB9-30", &["680"]),
+    ("IAB9-31", &["257"]),
+    ("IAB10", &["274"]),
+    ("IAB10-1", &["278"]),
+    ("IAB10-2", &["279"]),
+    ("IAB10-3", &["282"]),
+    ("IAB10-4", &["275"]),
+    ("IAB10-5", &["280"]),
+    ("IAB10-6", &["634"]),
+    ("IAB10-7", &["283"]),
+    ("IAB10-8", &["284"]),
+    ("IAB10-9", &["276"]),
+    ("IAB11", &["379"]),
+    ("IAB11-1", &["388"]),
+    ("IAB11-2", &["383"]),
+    ("IAB11-4", &["386"]),
+    ("IAB12", &["379"]),
+    ("IAB12-1", &["382"]),
+    ("IAB12-2", &["385"]),
+    ("IAB12-3", &["384"]),
+    ("IAB13", &["391"]),
+    ("IAB13-1", &["410"]),
+    ("IAB13-2", &["405"]),
+    ("IAB13-3", &["80"]),
+    ("IAB13-4", &["396"]),
+    ("IAB13-5", &["411"]),
+    ("IAB13-6", &["398"]),
+    ("IAB13-7", &["410"]),
+    ("IAB13-8", &["412"]),
+    ("IAB13-9", &["413"]),
+    ("IAB13-10", &["416"]),
+    ("IAB13-11", &["414"]),
+    ("IAB13-12", &["415"]),
+    ("IAB14", &["186"]),
+    ("IAB14-1", &["188"]),
+    ("IAB14-2", &["189"]),
+    ("IAB14-4", &["191"]),
+    ("IAB14-5", &["190"]),
+    ("IAB14-7", &["165"]),
+    ("IAB15", &["464"]),
+    ("IAB15-1", &["456"]),
+    ("IAB15-2", &["466"]),
+    ("IAB15-3", &["467"]),
+    ("IAB15-4", &["472"]),
+    ("IAB15-5", &["244"]),
+    ("IAB15-6", &["470"]),
+    ("IAB15-7", &["471"]),
+    ("IAB15-8", &["469"]),
+    ("IAB15-9", &["466"]),
+    ("IAB15-10", &["390"]),
+    ("IAB16", &["422"]),
+    ("IAB16-1", &["426"]),
+    ("IAB16-2", &["423"]),
+    ("IAB16-3", &["424"]),
+    ("IAB16-4", &["425"]),
+    ("IAB16-5", &["427"]),
+    ("IAB16-6", &["429"]),
+    ("IAB16-7", &["430"]),
+    ("IAB17", &["483"]),
+    ("IAB17-1", &["518"]),
+    ("IAB17-2", &["545"]),
+    ("IAB17-3", &["492"]),
+    ("IAB17-4", &["549"]),
+    ("IAB17-5", &["485"]),
+    ("IAB17-6", &["499"]),
+    ("IAB17-7", &["486"]),
+    ("IAB17-8", &["500"]),
+    ("IAB17-9", &["491"]),
+    ("IAB17-10", &["510"]),
+    ("IAB17-11", &["511"]),
+    ("IAB17-12", &["484"]),
+    ("IAB17-13", &["511"]),
+    ("IAB17-14", &["514"]),
+    ("IAB17-15", &["512"]),
+    ("IAB17-16", &["497"]),
+    ("IAB17-17", &["496"]),
+    ("IAB17-18", &["514"]),
+    ("IAB17-19", &["516"]),
+    ("IAB17-20", &["520"]),
+    ("IAB17-21", &["492"]),
+    ("IAB17-22", &["518"]),
+    ("IAB17-23", &["521"]),
+    ("IAB17-24", &["501"]),
+    ("IAB17-25", &["519"]),
+    ("IAB17-26", &["547"]),
+    ("IAB17-27", &["515"]),
+    ("IAB17-28", &["525"]),
+    ("IAB17-29", &["527"]),
+    ("IAB17-30", &["227"]),
+    ("IAB17-31", &["530"]),
+    ("IAB17-32", &["511"]),
+    ("IAB17-33", &["502"]),
+    ("IAB17-34", &["503"]),
+    ("IAB17-35", &["531"]),
+    ("IAB17-36", &["504"]),
+    ("IAB17-37", &["505"]),
+    ("IAB17-38", &["537"]),
+    ("IAB17-39", &["538"]),
+    ("IAB17-40", &["539"]),
+    ("IAB17-41", &["541"]),
+    ("IAB17-42", &["542"]),
+    ("IAB17-43", &["506"]),
+    ("IAB17-44", &["533"]),
+    ("IAB18", &["552"]),
+    ("IAB18-1", &["553"]),
+    ("IAB18-2", &["574"]),
+    ("IAB18-3", &["577"]),
+    ("IAB19", &["596"]),
+    ("IAB19-1", &["603"]),
+    ("IAB19-2", &["610"]),
+    ("IAB19-3", &["608"]),
+    ("IAB19-4", &["631"]),
+    ("IAB19-5", &["633"]),
+    ("IAB19-6", &["635"]),
+    ("IAB19-8", &["600"]),
+    ("IAB19-9", &["601"]),
+    ("IAB19-11", &["616"]),
+    ("IAB19-12", &["611"]),
+    ("IAB19-13", &["612"]),
+    ("IAB19-14", &["606"]),
+    ("IAB19-15", &["623"]),
+    ("IAB19-16", &["614"]),
+    ("IAB19-17", &["634"]),
+    ("IAB19-18", &["619"]),
+    ("IAB19-19", &["631"]),
+    ("IAB19-20", &["631"]),
+    ("IAB19-22", &["613"]),
+    ("IAB19-23", &["607"]),
+    ("IAB19-24", &["624"]),
+    ("IAB19-25", &["618"]),
+    ("IAB19-28", &["630"]),
+    ("IAB19-30", &["605"]),
+    ("IAB19-31", &["615"]),
+    ("IAB19-32", &["631"]),
+    ("IAB19-34", &["629"]),
+    ("IAB19-35", &["627"]),
+    ("IAB19-36", &["615"]),
+    ("IAB20", &["653"]),
+    ("IAB20-1", &["665"]),
+    ("IAB20-2", &["656"]),
+    ("IAB20-3", &["672"]),
+    ("IAB20-4", &["658"]),
+    ("IAB20-5", &["674"]),
+    ("IAB20-6", &["675"]),
+    ("IAB20-7", &["676"]),
+    ("IAB20-8", &["660"]),
+    ("IAB20-9", &["677"]),
+    ("IAB20-10", &["660"]),
+    ("IAB20-11", &["660"]),
+    ("IAB20-12", &["678"]),
+    ("IAB20-13", &["659"]),
+    ("IAB20-14", &["659"]),
+    ("IAB20-15", &["659"]),
+    ("IAB20-16", &["659"]),
+    ("IAB20-17", &["667"]),
+    ("IAB20-18", &["668"]),
+    ("IAB20-19", &["659"]),
+    ("IAB20-20", &["657"]),
+    ("IAB20-21", &["660"]),
+    ("IAB20-22", &["160"]),
+    ("IAB20-23", &["662"]),
+    ("IAB20-24", &["671"]),
+    ("IAB20-25", &["151"]),
+    ("IAB20-26", &["666"]),
+    ("IAB20-27", &["659"]),
+    ("IAB21", &["441"]),
+    ("IAB21-1", &["442"]),
+    ("IAB21-3", &["451"]),
+    ("IAB22", &["473"]),
+    ("IAB22-2", &["474"]),
+    ("IAB23", &["453"]),
+    ("IAB23-2", &["454", "457"]),
+    ("IAB23-3", &["458"]),
+    ("IAB23-4", &["459"]),
+    ("IAB23-5", &["459"]),
+    ("IAB23-6", &["460"]),
+    ("IAB23-7", &["461"]),
+    ("IAB23-8", &["462"]),
+    ("IAB23-9", &["459"]),
+    ("IAB24", &[]),
+    ("IAB25", &[]),
+    ("IAB26", &[]),
+];
+
+/// A category ID qualified by the taxonomy it is drawn from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Category<'a> {
+    /// Taxonomy of the ID
+    pub taxonomy: ContentTaxonomy,
+
+    /// Category ID within the taxonomy
+    pub id: &'a str,
+}
+
+/// Content Taxonomy 2.x and 3.0 category with the given ID
+fn content_category(
+    id: &str,
+) -> Option<&'static (&'static str, Option<&'static str>, &'static str)> {
+    CONTENT_2_X.iter().find(|(category, _, _)| *category == id)
+}
+
+/// `id` followed by the IDs of its Content Taxonomy 2.x and 3.0 ancestors
+fn content_ancestors(id: &str) -> impl Iterator<Item = &str> {
+    std::iter::successors(Some(id), |id| content_category(id)?.1)
+}
+
+impl<'a> Category<'a> {
+    /// Qualify `id` with its taxonomy
+    pub fn new(taxonomy: ContentTaxonomy, id: &'a str) -> Self {
+        Category { taxonomy, id }
+    }
+
+    /// Tier-1 and tier-2 numbers of a Content Taxonomy 1.0 code
+    fn content_1_0(&self) -> Option<(u32, Option<u32>)> {
+        let code = self.id.strip_prefix("IAB")?;
+        let (tier1, tier2) = match code.split_once('-') {
+            Some((tier1, tier2)) => (tier1, Some(tier2.parse().ok()?)),
+            None => (code, None),
+        };
+        Some((tier1.parse().ok()?, tier2))
+    }
+
+    /// Whether the ID names a category of its taxonomy
+    ///
+    /// Content Taxonomy 1.0 codes must name an existing tier-1 or tier-2 category and
+    /// Content Taxonomy 2.x and 3.0 IDs an embedded category. IDs of the Ad Product and
+    /// Audience taxonomies must be positive integers, and exchange specific IDs non-empty
+    /// and alphanumeric.
+    pub fn is_valid(&self) -> bool {
+        match self.taxonomy {
+            ContentTaxonomy::IabContentCategory1 => match self.content_1_0() {
+                Some((tier1 @ 1..=26, tier2)) => {
+                    let children = CONTENT_1_0[tier1 as usize - 1].1;
+                    tier2.is_none_or(|tier2| (1..=children).contains(&tier2))
+                }
+                _ => false,
+            },
+            taxonomy if taxonomy.uses_content_ids() => content_category(self.id).is_some(),
+            ContentTaxonomy::ExchangeSpecific(_) => {
+                !self.id.is_empty() && self.id.chars().all(|c| c.is_ascii_alphanumeric())
+            }
+            _ => !self.id.starts_with('0') && self.id.parse::<u32>().is_ok(),
+        }
+    }
+
+    /// Name of the category, for tier-1 categories of Content Taxonomy 1.0 and all
+    /// categories of Content Taxonomy 2.x and 3.0
+    pub fn name(&self) -> Option<&'static str> {
+        if self.taxonomy == ContentTaxonomy::IabContentCategory1 {
+            return match self.content_1_0() {
+                Some((tier1 @ 1..=26, None)) => Some(CONTENT_1_0[tier1 as usize - 1].0),
+                _ => None,
+            };
+        }
+        if self.taxonomy.uses_content_ids() {
+            return content_category(self.id).map(|(_, _, name)| *name);
+        }
+        None
+    }
+
+    /// Parent of the category, for Content Taxonomy 1.0 tier-2 codes and Content
+    /// Taxonomy 2.x and 3.0 subcategories
+    pub fn parent(&self) -> Option<Category<'a>> {
+        if self.taxonomy.uses_content_ids() {
+            let parent = content_category(self.id)?.1?;
+            return Some(Category::new(self.taxonomy, parent));
+        }
+        if self.taxonomy != ContentTaxonomy::IabContentCategory1 {
+            return None;
+        }
+        let (parent, _) = self.id.split_once('-')?;
+        Some(Category::new(self.taxonomy, parent))
+    }
+
+    /// Content Taxonomy 2.x and 3.0 IDs covering the category
+    fn content_ids(&self) -> Vec<&'a str> {
+        if self.taxonomy.uses_content_ids() {
+            return vec![self.id];
+        }
+        if self.taxonomy != ContentTaxonomy::IabContentCategory1 {
+            return Vec::new();
+        }
+        let mapped = |code: &str| {
+            CONTENT_1_0_TO_2_X
+                .iter()
+                .find(|(from, _)| *from == code)
+                .map(|(_, ids)| ids.to_vec())
+        };
+        mapped(self.id)
+            .or_else(|| {
+                let parent = mapped(self.parent()?.id)?;
+                (parent.len() == 1).then_some(parent)
+            })
+            .unwrap_or_default()
+    }
+
+    /// IDs of the categories of `taxonomy` covering this category
+    ///
+    /// Content Taxonomy 2.x and 3.0 share their IDs, so converting between them keeps the
+    /// ID. Converting 2.x or 3.0 IDs into 1.0 yields every 1.0 code mapped onto them.
+    pub fn to_taxonomy(&self, taxonomy: ContentTaxonomy) -> Vec<String> {
+        if taxonomy == self.taxonomy {
+            return vec![self.id.to_string()];
+        }
+        let ids = self.content_ids();
+        if taxonomy.uses_content_ids() {
+            return ids.into_iter().map(str::to_string).collect();
+        }
+        if taxonomy == ContentTaxonomy::IabContentCategory1 {
+            return CONTENT_1_0_TO_2_X
+                .iter()
+                .filter(|(_, mapped)| mapped.iter().any(|id| ids.contains(id)))
+                .map(|(code, _)| code.to_string())
+                .collect();
+        }
+        Vec::new()
+    }
+
+    /// Whether this block list entry blocks `category`
+    ///
+    /// An entry blocks its own category and every category below it. Across the content
+    /// taxonomies both sides are read as 2.x and 3.0 IDs, and the entry blocks `category`
+    /// when one of its IDs is one of the IDs of `category` or their ancestors.
+    pub fn blocks(&self, category: &Category) -> bool {
+        let same_ids = self.taxonomy.uses_content_ids() && category.taxonomy.uses_content_ids();
+        if self.taxonomy == category.taxonomy || same_ids {
+            return std::iter::successors(Some(*category), Category::parent)
+                .any(|ancestor| ancestor.id == self.id);
+        }
+        let blocked = self.content_ids();
+        category
+            .content_ids()
+            .into_iter()
+            .flat_map(content_ancestors)
+            .any(|id| blocked.contains(&id))
+    }
+}
+
+/// Taxonomy of a `cattax` field, which defaults to Content Taxonomy 1.0
+fn taxonomy_or_default(cattax: Option<ContentTaxonomy>) -> ContentTaxonomy {
+    cattax.unwrap_or(ContentTaxonomy::IabContentCategory1)
+}
+
+fn check_categories(
+    invalid: &mut Vec<String>,
+    path: &str,
+    cattax: Option<ContentTaxonomy>,
+    ids: &[Option<&Vec<String>>],
+) {
+    let taxonomy = taxonomy_or_default(cattax);
+    for id in ids.iter().flatten().flat_map(|ids| ids.iter()) {
+        if !Category::new(taxonomy, id).is_valid() {
+            invalid.push(format!("{path}: {id}"));
+        }
+    }
+}
+
+fn check_content(invalid: &mut Vec<String>, path: &str, content: Option<&Content>) {
+    let Some(content) = content else {
+        return;
+    };
+    check_categories(invalid, path, content.cattax, &[content.cat.as_ref()]);
+    if let Some(producer) = &content.producer {
+        check_categories(
+            invalid,
+            &format!("{path}.producer"),
+            producer.cattax,
+            &[producer.cat.as_ref()],
+        );
+    }
+}
+
+impl<Ext> BidRequest<Ext> {
+    /// The first `bcat` entry blocking one of the categories of `bid`, with the blocked
+    /// category
+    ///
+    /// Both `bcat` and the bid categories are read in the taxonomy given by their
+    /// `cattax`, so a bid tagged `IAB1-6` is caught by a `bcat` of Content Taxonomy 2.2
+    /// listing `338`.
+    pub fn blocked_category<'a, BidExt>(
+        &'a self,
+        bid: &'a Bid<BidExt>,
+    ) -> Option<(&'a str, &'a str)> {
+        let blocked = self.bcat.as_deref()?;
+        let bcat_taxonomy = taxonomy_or_default(self.cattax);
+        let bid_taxonomy = taxonomy_or_default(bid.cattax);
+        bid.cat.iter().flatten().find_map(|cat| {
+            let category = Category::new(bid_taxonomy, cat);
+            blocked
+                .iter()
+                .find(|entry| Category::new(bcat_taxonomy, entry).blocks(&category))
+                .map(|entry| (entry.as_str(), cat.as_str()))
+        })
+    }
+
+    /// Category IDs that are not valid in the taxonomy of their object, as
+    /// `"path: id"` entries
+    pub fn invalid_categories(&self) -> Vec<String> {
+        let mut invalid = Vec::new();
+        check_categories(&mut invalid, "bcat", self.cattax, &[self.bcat.as_ref()]);
+        if let Some(site) = &self.site {
+            check_categories(
+                &mut invalid,
+                "site",
+                site.cattax,
+                &[
+                    site.cat.as_ref(),
+                    site.sectioncat.as_ref(),
+                    site.pagecat.as_ref(),
+                ],
+            );
+            if let Some(publisher) = &site.publisher {
+                check_categories(
+                    &mut invalid,
+                    "site.publisher",
+                    publisher.cattax,
+                    &[publisher.cat.as_ref()],
+                );
+            }
+            check_content(&mut invalid, "site.content", site.content.as_ref());
+        }
+        if let Some(app) = &self.app {
+            check_categories(
+                &mut invalid,
+                "app",
+                app.cattax,
+                &[
+                    app.cat.as_ref(),
+                    app.sectioncat.as_ref(),
+                    app.pagecat.as_ref(),
+                ],
+            );
+            if let Some(publisher) = &app.publisher {
+                check_categories(
+                    &mut invalid,
+                    "app.publisher",
+                    publisher.cattax,
+                    &[publisher.cat.as_ref()],
+                );
+            }
+            check_content(&mut invalid, "app.content", app.content.as_ref());
+        }
+        if let Some(dooh) = &self.dooh {
+            check_categories(&mut invalid, "dooh", dooh.cattax, &[dooh.cat.as_ref()]);
+            check_content(&mut invalid, "dooh.content", dooh.content.as_ref());
+        }
+        invalid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_cross_taxonomy_block_list() {
+        let v1 = |id| Category::new(ContentTaxonomy::IabContentCategory1, id);
+        let v22 = |id| Category::new(ContentTaxonomy::IabContent22, id);
+        assert!(v1("IAB1-7").is_valid());
+        assert!(!v1("IAB1-8").is_valid());
+        assert!(!v1("IAB27").is_valid());
+        assert_eq!(v1("IAB17").name(), Some("Sports"));
+        assert_eq!(
+            v1("IAB1-6").to_taxonomy(ContentTaxonomy::IabContent30),
+            ["338"]
+        );
+        assert_eq!(
+            v22("680").to_taxonomy(ContentTaxonomy::IabContentCategory1),
+            ["IAB9", "IAB9-30"]
+        );
+        assert!(v22("483").blocks(&v1("IAB17-44")));
+        assert!(v22("483").blocks(&v22("533")));
+        assert!(!v22("533").blocks(&v22("483")));
+        assert!(v1("IAB1").blocks(&v1("IAB1-6")));
+        assert!(!v1("IAB1-6").blocks(&v1("IAB1")));
+        assert!(!v22("338").blocks(&v1("IAB2")));
+
+        let request: BidRequest = serde_json::from_value(json!({
+            "id": "req",
+            "imp": [{"id": "1"}],
+            "site": {"cat": ["IAB1", "IAB99"], "content": {"cat": ["IAB1-6"], "cattax": 1}},
+            "bcat": ["483", "338"],
+            "cattax": 6
+        }))
+        .unwrap();
+        let bid: Bid = serde_json::from_value(json!({
+            "id": "b", "impid": "1", "price": 1.0, "cat": ["IAB2", "IAB1-6"]
+        }))
+        .unwrap();
+        assert_eq!(request.blocked_category(&bid), Some(("338", "IAB1-6")));
+        assert_eq!(request.invalid_categories(), ["site: IAB99"]);
+    }
+
+    #[test]
+    fn test_content_taxonomy_tree() {
+        let v1 = |id| Category::new(ContentTaxonomy::IabContentCategory1, id);
+        let v30 = |id| Category::new(ContentTaxonomy::IabContent30, id);
+        let product = |id| Category::new(ContentTaxonomy::IabAdProduct2, id);
+        assert!(v30("533").is_valid());
+        assert!(!v30("999").is_valid());
+        assert!(!v30("Sports").is_valid());
+        assert!(product("1001").is_valid());
+        assert!(!product("abc").is_valid());
+        assert!(v1("IAB25-3").is_valid());
+        assert_eq!(v30("533").name(), Some("Soccer"));
+        assert_eq!(v30("533").parent(), Some(v30("483")));
+        assert_eq!(v30("483").parent(), None);
+        assert!(
+            v1("IAB25-3")
+                .to_taxonomy(ContentTaxonomy::IabContent30)
+                .is_empty()
+        );
+        assert_eq!(
+            v1("IAB14-1").to_taxonomy(ContentTaxonomy::IabContent30),
+            ["188"]
+        );
+    }
+
+    #[test]
+    fn test_cross_taxonomy_block_list_both_directions() {
+        let v1 = |id| Category::new(ContentTaxonomy::IabContentCategory1, id);
+        let v22 = |id| Category::new(ContentTaxonomy::IabContent22, id);
+
+        // A 2.2 entry blocks the 1.0 codes mapped onto it or below it, and no others
+        assert!(v22("680").blocks(&v1("IAB9-30")));
+        assert!(!v22("680").blocks(&v1("IAB9-5")));
+        assert!(v22("239").blocks(&v1("IAB9-5")));
+        assert!(v22("287").blocks(&v1("IAB7-11")));
+        assert!(!v22("223").blocks(&v1("IAB7-11")));
+
+        // A 1.0 entry blocks the 2.2 categories mapped from it and their subcategories
+        assert!(v1("IAB17").blocks(&v22("533")));
+        assert!(v1("IAB9").blocks(&v22("270")));
+        assert!(v1("IAB9-5").blocks(&v22("270")));
+        assert!(!v1("IAB9-30").blocks(&v22("270")));
+        assert!(v1("IAB9-30").blocks(&v22("681")));
+        assert!(!v1("IAB17-44").blocks(&v22("483")));
+        assert!(!v1("IAB25").blocks(&v22("483")));
+    }
+}
@@ -0,0 +1,259 @@
+//! Migration between the OpenRTB 2.5 and 2.6 request layouts
+//!
+//! OpenRTB 2.5 partners carry several attributes in `ext` objects that 2.6 promotes to
+//! first-class fields. [`BidRequest::upgrade_from_v2_5`] lifts them into place and
+//! [`BidRequest::downgrade_to_v2_5`] moves them back for bidders that only read 2.5.
+
+use super::enums::*;
+use super::*;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+
+/// A field moved between its OpenRTB 2.5 and 2.6 locations
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FieldMove {
+    /// Path of the field before the migration
+    pub from: &'static str,
+
+    /// Path of the field after the migration
+    pub to: &'static str,
+}
+
+/// Move `ext[key]` into an unset first-class field
+///
+/// Values that do not decode into the field type are left in `ext`.
+pub(crate) fn lift<T: DeserializeOwned>(
+    ext: &mut Option<Value>,
+    key: &str,
+    field: &mut Option<T>,
+) -> bool {
+    if field.is_some() {
+        return false;
+    }
+    let Some(object) = ext.as_mut().and_then(Value::as_object_mut) else {
+        return false;
+    };
+    let Some(value) = object
+        .get(key)
+        .and_then(|v| serde_json::from_value(v.clone()).ok())
+    else {
+        return false;
+    };
+    object.remove(key);
+    if object.is_empty() {
+        *ext = None;
+    }
+    *field = Some(value);
+    true
+}
+
+/// Move a first-class field into `ext[key]`, creating the `ext` object if needed
+pub(crate) fn lower<T: Serialize>(
+    field: &mut Option<T>,
+    ext: &mut Option<Value>,
+    key: &str,
+) -> bool {
+    let Some(value) = field.as_ref().and_then(|v| serde_json::to_value(v).ok()) else {
+        return false;
+    };
+    let Value::Object(object) = ext.get_or_insert_with(|| Value::Object(Map::new())) else {
+        return false;
+    };
+    object.insert(key.to_string(), value);
+    *field = None;
+    true
+}
+
+pub(crate) fn plcmt_from_placement(placement: VideoPlacementType) -> VideoPlcmtSubtype {
+    match placement {
+        VideoPlacementType::InStream => VideoPlcmtSubtype::Instream,
+        VideoPlacementType::InBanner
+        | VideoPlacementType::InArticle
+        | VideoPlacementType::InFeed => VideoPlcmtSubtype::NoContentStandalone,
+        VideoPlacementType::InterstitialSliderFloating => VideoPlcmtSubtype::Interstitial,
+    }
+}
+
+fn placement_from_plcmt(plcmt: VideoPlcmtSubtype) -> Option<VideoPlacementType> {
+    match plcmt {
+        VideoPlcmtSubtype::Instream => Some(VideoPlacementType::InStream),
+        VideoPlcmtSubtype::Interstitial => Some(VideoPlacementType::InterstitialSliderFloating),
+        VideoPlcmtSubtype::AccompanyingContent | VideoPlcmtSubtype::NoContentStandalone => None,
+    }
+}
+
+impl<Ext> BidRequest<Ext> {
+    /// Lift OpenRTB 2.5 extension fields into their 2.6 first-class fields
+    ///
+    /// Moves `regs.ext.gdpr`, `regs.ext.us_privacy`, `user.ext.consent`, `user.ext.eids`
+    /// and `source.ext.schain`, derives `plcmt` from the deprecated video `placement` and
+    /// folds the deprecated `protocol` into `protocols`. Fields already set in 2.6 form
+    /// are kept.
+    pub fn upgrade_from_v2_5(&mut self) -> Vec<FieldMove> {
+        let mut moves = Vec::new();
+        let mut record = |moved: bool, from, to| {
+            if moved {
+                moves.push(FieldMove { from, to });
+            }
+        };
+
+        if let Some(regs) = self.regs.as_mut() {
+            record(
+                lift(&mut regs.ext, "gdpr", &mut regs.gdpr),
+                "regs.ext.gdpr",
+                "regs.gdpr",
+            );
+            record(
+                lift(&mut regs.ext, "us_privacy", &mut regs.us_privacy),
+                "regs.ext.us_privacy",
+                "regs.us_privacy",
+            );
+        }
+        if let Some(user) = self.user.as_mut() {
+            record(
+                lift(&mut user.ext, "consent", &mut user.consent),
+                "user.ext.consent",
+                "user.consent",
+            );
+            record(
+                lift(&mut user.ext, "eids", &mut user.eids),
+                "user.ext.eids",
+                "user.eids",
+            );
+        }
+        if let Some(source) = self.source.as_mut() {
+            record(
+                lift(&mut source.ext, "schain", &mut source.schain),
+                "source.ext.schain",
+                "source.schain",
+            );
+        }
+
+        for video in self.imp.iter_mut().filter_map(|imp| imp.video.as_mut()) {
+            if video.plcmt.is_none()
+                && let Some(placement) = video.placement
+            {
+                video.plcmt = Some(plcmt_from_placement(placement));
+                record(true, "imp.video.placement", "imp.video.plcmt");
+            }
+            if video.protocols.is_none()
+                && let Some(protocol) = video.protocol.take()
+            {
+                video.protocols = Some(vec![protocol]);
+                record(true, "imp.video.protocol", "imp.video.protocols");
+            }
+        }
+
+        moves
+    }
+
+    /// Move 2.6 first-class fields back to their OpenRTB 2.5 extension locations
+    ///
+    /// The reverse of [`BidRequest::upgrade_from_v2_5`]. Video `placement` is derived
+    /// from `plcmt` where 2.5 has an equivalent, and SIMID API frameworks, which 2.5 does
+    /// not define, are removed from video and banner `api` lists.
+    pub fn downgrade_to_v2_5(&mut self) -> Vec<FieldMove> {
+        let mut moves = Vec::new();
+        let mut record = |moved: bool, from, to| {
+            if moved {
+                moves.push(FieldMove { from, to });
+            }
+        };
+
+        if let Some(regs) = self.regs.as_mut() {
+            record(
+                lower(&mut regs.gdpr, &mut regs.ext, "gdpr"),
+                "regs.gdpr",
+                "regs.ext.gdpr",
+            );
+            record(
+                lower(&mut regs.us_privacy, &mut regs.ext, "us_privacy"),
+                "regs.us_privacy",
+                "regs.ext.us_privacy",
+            );
+        }
+        if let Some(user) = self.user.as_mut() {
+            record(
+                lower(&mut user.consent, &mut user.ext, "consent"),
+                "user.consent",
+                "user.ext.consent",
+            );
+            record(
+                lower(&mut user.eids, &mut user.ext, "eids"),
+                "user.eids",
+                "user.ext.eids",
+            );
+        }
+        if let Some(source) = self.source.as_mut() {
+            record(
+                lower(&mut source.schain, &mut source.ext, "schain"),
+                "source.schain",
+                "source.ext.schain",
+            );
+        }
+
+        let is_v2_5_api =
+            |api: &ApiFramework| !matches!(api, ApiFramework::Simid1 | ApiFramework::Simid11);
+        for imp in &mut self.imp {
+            if let Some(video) = imp.video.as_mut() {
+                if video.placement.is_none()
+                    && let Some(placement) = video.plcmt.and_then(placement_from_plcmt)
+                {
+                    video.placement = Some(placement);
+                    record(true, "imp.video.plcmt", "imp.video.placement");
+                }
+                if let Some(api) = video.api.as_mut() {
+                    api.retain(is_v2_5_api);
+                }
+            }
+            if let Some(api) = imp.banner.as_mut().and_then(|banner| banner.api.as_mut()) {
+                api.retain(is_v2_5_api);
+            }
+        }
+
+        moves
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_upgrade_and_downgrade() {
+        let v2_5 = json!({
+            "id": "req",
+            "imp": [{"id": "1", "video": {"mimes": ["video/mp4"], "placement": 1, "protocol": 2}}],
+            "user": {"id": "u", "ext": {"consent": "CO-consent", "eids": [{"source": "id5-sync.com", "uids": [{"id": "abc", "atype": 1}]}]}},
+            "source": {"tid": "t", "ext": {"schain": {"complete": 1, "ver": "1.0", "nodes": [{"asi": "ssp.com", "sid": "42", "hp": 1}]}}},
+            "regs": {"ext": {"gdpr": 1, "us_privacy": "1YNN", "other": true}}
+        });
+        let mut request: BidRequest = serde_json::from_value(v2_5.clone()).unwrap();
+
+        let moves = request.upgrade_from_v2_5();
+        assert_eq!(moves.len(), 7);
+        let regs = request.regs.as_ref().unwrap();
+        assert_eq!(regs.gdpr, Some(GdprFlag::Yes));
+        assert_eq!(regs.us_privacy.as_deref(), Some("1YNN"));
+        assert_eq!(regs.ext, Some(json!({"other": true})));
+        let user = request.user.as_ref().unwrap();
+        assert_eq!(user.consent.as_deref(), Some("CO-consent"));
+        assert_eq!(user.eids.as_ref().unwrap()[0].uids[0].id, "abc");
+        assert_eq!(user.ext, None);
+        let schain = request.source.as_ref().unwrap().schain.as_ref().unwrap();
+        assert_eq!(schain.nodes[0].sid, "42");
+        let video = request.imp[0].video.as_ref().unwrap();
+        assert_eq!(video.plcmt, Some(VideoPlcmtSubtype::Instream));
+        assert_eq!(video.protocol, None);
+        assert_eq!(video.protocols.as_ref().unwrap().len(), 1);
+
+        let moves = request.downgrade_to_v2_5();
+        assert_eq!(moves.len(), 5);
+        let downgraded = serde_json::to_value(&request).unwrap();
+        assert_eq!(downgraded["regs"], v2_5["regs"]);
+        assert_eq!(downgraded["user"], v2_5["user"]);
+        assert_eq!(downgraded["source"], v2_5["source"]);
+    }
+}
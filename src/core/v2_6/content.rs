@@ -1,3 +1,4 @@
+use super::enums::*;
 use super::*;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -56,6 +57,10 @@ pub struct Content<Ext = Value> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cat: Option<Vec<String>>,
 
+    /// The taxonomy in use for the cat attribute.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cattax: Option<ContentTaxonomy>,
+
     /// Production quality.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub prodq: Option<u32>,
@@ -0,0 +1,221 @@
+//! Currency normalization of floors and bid prices
+//!
+//! OpenRTB defaults every currency field to USD when it is absent. Floors are rounded up
+//! and bid prices down after conversion, so a converted floor never drops below the
+//! original and a converted bid never exceeds it.
+
+use super::*;
+use crate::currency::*;
+
+/// Parse an optional currency code, defaulting to USD
+fn currency_or_usd(code: Option<&str>) -> Result<Currency, CurrencyError> {
+    code.map_or(Ok(Currency::USD), Currency::new)
+}
+
+impl<Ext> Deal<Ext> {
+    /// Currency of the deal floor
+    pub fn floor_currency(&self) -> Result<Currency, CurrencyError> {
+        currency_or_usd(self.bidfloorcur.as_deref())
+    }
+
+    /// Deal floor converted into `target`, rounded up
+    pub fn floor_in(
+        &self,
+        target: Currency,
+        rates: &RateTable,
+        rounding: Rounding,
+    ) -> Result<Option<f64>, CurrencyError> {
+        convert_floor(
+            self.bidfloor,
+            self.floor_currency()?,
+            target,
+            rates,
+            rounding,
+        )
+    }
+}
+
+impl<Ext> Impression<Ext> {
+    /// Currency of the impression floor
+    pub fn floor_currency(&self) -> Result<Currency, CurrencyError> {
+        currency_or_usd(self.bidfloorcur.as_deref())
+    }
+
+    /// Impression floor converted into `target`, rounded up
+    pub fn floor_in(
+        &self,
+        target: Currency,
+        rates: &RateTable,
+        rounding: Rounding,
+    ) -> Result<Option<f64>, CurrencyError> {
+        convert_floor(
+            self.bidfloor,
+            self.floor_currency()?,
+            target,
+            rates,
+            rounding,
+        )
+    }
+}
+
+fn convert_floor(
+    floor: Option<f64>,
+    from: Currency,
+    to: Currency,
+    rates: &RateTable,
+    rounding: Rounding,
+) -> Result<Option<f64>, CurrencyError> {
+    floor
+        .map(|floor| Ok(to.round_up(rates.convert(floor, from, to)?, rounding)))
+        .transpose()
+}
+
+impl<Ext> BidRequest<Ext> {
+    /// Currencies bids may be placed in
+    pub fn allowed_currencies(&self) -> Result<Vec<Currency>, CurrencyError> {
+        match self.cur.as_deref() {
+            Some(codes) if !codes.is_empty() => codes.iter().map(|code| code.parse()).collect(),
+            _ => Ok(vec![Currency::USD]),
+        }
+    }
+
+    /// Check that bids in `currency` are allowed by the request
+    pub fn check_bid_currency(&self, currency: Currency) -> Result<(), CurrencyError> {
+        if self.allowed_currencies()?.contains(&currency) {
+            Ok(())
+        } else {
+            Err(CurrencyError::NotAllowed(currency))
+        }
+    }
+
+    /// Convert every impression and deal floor into `target`
+    ///
+    /// Floors are rounded up and their currency set to `target`. The request is left
+    /// unchanged when any floor cannot be converted.
+    pub fn normalize_floors(
+        &mut self,
+        target: Currency,
+        rates: &RateTable,
+        rounding: Rounding,
+    ) -> Result<(), CurrencyError> {
+        let floors = self
+            .imp
+            .iter()
+            .map(|imp| {
+                let deals = imp
+                    .pmp
+                    .iter()
+                    .flat_map(|pmp| pmp.deals.iter().flatten())
+                    .map(|deal| deal.floor_in(target, rates, rounding))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok((imp.floor_in(target, rates, rounding)?, deals))
+            })
+            .collect::<Result<Vec<_>, CurrencyError>>()?;
+
+        for (imp, (floor, deal_floors)) in self.imp.iter_mut().zip(floors) {
+            if floor.is_some() {
+                imp.bidfloor = floor;
+                imp.bidfloorcur = Some(target.to_string());
+            }
+            let deals = imp
+                .pmp
+                .iter_mut()
+                .flat_map(|pmp| pmp.deals.iter_mut().flatten());
+            for (deal, floor) in deals.zip(deal_floors) {
+                if floor.is_some() {
+                    deal.bidfloor = floor;
+                    deal.bidfloorcur = Some(target.to_string());
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<Ext> BidResponse<Ext> {
+    /// Currency of the bid prices
+    pub fn currency(&self) -> Result<Currency, CurrencyError> {
+        currency_or_usd(self.cur.as_deref())
+    }
+
+    /// Convert every bid price into `target`
+    ///
+    /// Prices are rounded down and the response currency set to `target`. The response
+    /// is left unchanged when the prices cannot be converted.
+    pub fn normalize_prices(
+        &mut self,
+        target: Currency,
+        rates: &RateTable,
+        rounding: Rounding,
+    ) -> Result<(), CurrencyError> {
+        let from = self.currency()?;
+        let rate = rates.convert(1.0, from, target)?;
+        let bids = self
+            .seatbid
+            .iter_mut()
+            .flatten()
+            .flat_map(|seatbid| &mut seatbid.bid);
+        for bid in bids {
+            bid.price = target.round_down(bid.price * rate, rounding);
+        }
+        self.cur = Some(target.to_string());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_normalize_floors_and_prices() {
+        let rates = RateTable::new(Currency::USD)
+            .with_rate(Currency::EUR, 0.8)
+            .with_rate(Currency::JPY, 150.0);
+        let mut request: BidRequest = serde_json::from_value(json!({
+            "id": "req",
+            "imp": [
+                {"id": "1", "bidfloor": 1.0, "bidfloorcur": "eur",
+                 "pmp": {"deals": [{"id": "d", "bidfloor": 300, "bidfloorcur": "JPY"}]}},
+                {"id": "2", "bidfloor": 0.001}
+            ],
+            "cur": ["USD", "EUR"]
+        }))
+        .unwrap();
+
+        request
+            .normalize_floors(Currency::USD, &rates, Rounding::MinorUnits)
+            .unwrap();
+        assert_eq!(request.imp[0].bidfloor, Some(1.25));
+        assert_eq!(request.imp[0].bidfloorcur.as_deref(), Some("USD"));
+        let deal = &request.imp[0].pmp.as_ref().unwrap().deals.as_ref().unwrap()[0];
+        assert_eq!(deal.bidfloor, Some(2.0));
+        assert_eq!(request.imp[1].bidfloor, Some(0.01));
+
+        assert!(request.check_bid_currency(Currency::EUR).is_ok());
+        assert_eq!(
+            request.check_bid_currency(Currency::JPY),
+            Err(CurrencyError::NotAllowed(Currency::JPY))
+        );
+
+        let mut response: BidResponse = serde_json::from_value(json!({
+            "id": "req",
+            "seatbid": [{"bid": [{"id": "b", "impid": "1", "price": 1.337}]}],
+            "cur": "JPY"
+        }))
+        .unwrap();
+        let unchanged = response.clone();
+        assert!(
+            response
+                .normalize_prices(Currency::GBP, &rates, Rounding::MinorUnits)
+                .is_err()
+        );
+        assert_eq!(response, unchanged);
+        response
+            .normalize_prices(Currency::EUR, &rates, Rounding::Micros)
+            .unwrap();
+        assert_eq!(response.seatbid.unwrap()[0].bid[0].price, 0.00713);
+        assert_eq!(response.cur.as_deref(), Some("EUR"));
+    }
+}
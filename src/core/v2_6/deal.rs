@@ -38,6 +38,6 @@ pub struct Deal<Ext = Value> {
     pub ext: Option<Ext>,
 }
 
-fn default_bidfloorcur() -> Option<String> {
+pub(super) fn default_bidfloorcur() -> Option<String> {
     Some("USD".to_string())
 }
@@ -1,3 +1,4 @@
+use super::enums::*;
 use super::*;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -37,7 +38,7 @@ pub struct Dooh<Ext = Value> {
 
     /// The taxonomy in use for the cat attribute.
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub cattax: Option<u32>,
+    pub cattax: Option<ContentTaxonomy>,
 
     /// Comma separated list of keywords about the DOOH medium.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Extended identifiers support in the OpenRTB specification allows buyers to use audience data
+/// in real-time bidding.
+///
+/// This object can contain one or more UIDs from a single source or a technology provider.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Eid<Ext = Value> {
+    /// The canonical domain name of the entity that caused the ID array element to be added.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inserter: Option<String>,
+
+    /// Canonical domain of the ID.
+    pub source: String,
+
+    /// Technology providing the match method as defined in `mm`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub matcher: Option<String>,
+
+    /// Match method used by the matcher.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mm: Option<u32>,
+
+    /// Array of extended ID `Uid` objects from the given source.
+    pub uids: Vec<Uid>,
+
+    /// Placeholder for exchange-specific extensions to OpenRTB.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ext: Option<Ext>,
+}
+
+/// This object contains a single user identifier provided as part of extended identifiers.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Uid<Ext = Value> {
+    /// The identifier for the user.
+    pub id: String,
+
+    /// Type of user agent the ID is from.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub atype: Option<u32>,
+
+    /// Placeholder for exchange-specific extensions to OpenRTB.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ext: Option<Ext>,
+}
@@ -17,6 +17,10 @@ pub enum ApiFramework {
     Mraid3,
     /// OMID-1
     Omid1,
+    /// SIMID 1.0
+    Simid1,
+    /// SIMID 1.1
+    Simid11,
 }
 
 impl Serialize for ApiFramework {
@@ -32,6 +36,8 @@ impl Serialize for ApiFramework {
             ApiFramework::Mraid2 => serializer.serialize_u32(5),
             ApiFramework::Mraid3 => serializer.serialize_u32(6),
             ApiFramework::Omid1 => serializer.serialize_u32(7),
+            ApiFramework::Simid1 => serializer.serialize_u32(8),
+            ApiFramework::Simid11 => serializer.serialize_u32(9),
         }
     }
 }
@@ -50,6 +56,8 @@ impl<'de> Deserialize<'de> for ApiFramework {
             5 => Ok(ApiFramework::Mraid2),
             6 => Ok(ApiFramework::Mraid3),
             7 => Ok(ApiFramework::Omid1),
+            8 => Ok(ApiFramework::Simid1),
+            9 => Ok(ApiFramework::Simid11),
             _ => Err(serde::de::Error::custom(format!(
                 "Invalid ApiFramework value: {}",
                 value
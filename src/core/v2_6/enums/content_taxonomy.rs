@@ -1,12 +1,80 @@
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-/// Content Taxonomy
-#[derive(Debug, Clone, Copy, PartialEq)]
+/// Category Taxonomies
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ContentTaxonomy {
     /// IAB Content Category Taxonomy 1.0
     IabContentCategory1,
     /// IAB Content Category Taxonomy 2.0
     IabContentCategory2,
+    /// IAB Ad Product Taxonomy 1.0
+    IabAdProduct1,
+    /// IAB Audience Taxonomy 1.1
+    IabAudience11,
+    /// IAB Content Taxonomy 2.1
+    IabContent21,
+    /// IAB Content Taxonomy 2.2
+    IabContent22,
+    /// IAB Content Taxonomy 3.0
+    IabContent30,
+    /// IAB Ad Product Taxonomy 2.0
+    IabAdProduct2,
+    /// Exchange specific taxonomy (values 500 and above)
+    ExchangeSpecific(u32),
+}
+
+impl ContentTaxonomy {
+    /// Whether the taxonomy is a version of the IAB Content Taxonomy
+    pub fn is_content(&self) -> bool {
+        matches!(
+            self,
+            ContentTaxonomy::IabContentCategory1
+                | ContentTaxonomy::IabContentCategory2
+                | ContentTaxonomy::IabContent21
+                | ContentTaxonomy::IabContent22
+                | ContentTaxonomy::IabContent30
+        )
+    }
+
+    /// Whether the taxonomy shares the category IDs of IAB Content Taxonomy 2.x and 3.0
+    pub fn uses_content_ids(&self) -> bool {
+        self.is_content() && *self != ContentTaxonomy::IabContentCategory1
+    }
+}
+
+impl From<ContentTaxonomy> for u32 {
+    fn from(value: ContentTaxonomy) -> Self {
+        match value {
+            ContentTaxonomy::IabContentCategory1 => 1,
+            ContentTaxonomy::IabContentCategory2 => 2,
+            ContentTaxonomy::IabAdProduct1 => 3,
+            ContentTaxonomy::IabAudience11 => 4,
+            ContentTaxonomy::IabContent21 => 5,
+            ContentTaxonomy::IabContent22 => 6,
+            ContentTaxonomy::IabContent30 => 7,
+            ContentTaxonomy::IabAdProduct2 => 8,
+            ContentTaxonomy::ExchangeSpecific(value) => value,
+        }
+    }
+}
+
+impl TryFrom<u32> for ContentTaxonomy {
+    type Error = u32;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(ContentTaxonomy::IabContentCategory1),
+            2 => Ok(ContentTaxonomy::IabContentCategory2),
+            3 => Ok(ContentTaxonomy::IabAdProduct1),
+            4 => Ok(ContentTaxonomy::IabAudience11),
+            5 => Ok(ContentTaxonomy::IabContent21),
+            6 => Ok(ContentTaxonomy::IabContent22),
+            7 => Ok(ContentTaxonomy::IabContent30),
+            8 => Ok(ContentTaxonomy::IabAdProduct2),
+            v if v >= 500 => Ok(ContentTaxonomy::ExchangeSpecific(v)),
+            v => Err(v),
+        }
+    }
 }
 
 impl Serialize for ContentTaxonomy {
@@ -14,10 +82,7 @@ impl Serialize for ContentTaxonomy {
     where
         S: Serializer,
     {
-        match *self {
-            ContentTaxonomy::IabContentCategory1 => serializer.serialize_u32(1),
-            ContentTaxonomy::IabContentCategory2 => serializer.serialize_u32(2),
-        }
+        serializer.serialize_u32((*self).into())
     }
 }
 
@@ -27,13 +92,8 @@ impl<'de> Deserialize<'de> for ContentTaxonomy {
         D: Deserializer<'de>,
     {
         let value = u32::deserialize(deserializer)?;
-        match value {
-            1 => Ok(ContentTaxonomy::IabContentCategory1),
-            2 => Ok(ContentTaxonomy::IabContentCategory2),
-            _ => Err(serde::de::Error::custom(format!(
-                "Invalid ContentTaxonomy value: {}",
-                value
-            ))),
-        }
+        ContentTaxonomy::try_from(value).map_err(|value| {
+            serde::de::Error::custom(format!("Invalid ContentTaxonomy value: {}", value))
+        })
     }
-}
\ No newline at end of file
+}
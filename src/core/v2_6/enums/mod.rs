@@ -21,13 +21,16 @@ pub mod loss_reason;
 pub mod no_bid_reason;
 pub mod playback_cessation;
 pub mod playback_method;
+pub mod pod_sequence;
 pub mod production_quality;
 pub mod qag_media_rating;
+pub mod slot_position_in_pod;
 pub mod start_delay;
 pub mod user_agent_source;
 pub mod venue_taxonomy;
 pub mod video_linearity;
 pub mod video_placement;
+pub mod video_plcmt;
 pub mod video_protocols;
 pub mod volume_normalization;
 
@@ -55,13 +58,16 @@ pub use loss_reason::*;
 pub use no_bid_reason::*;
 pub use playback_cessation::*;
 pub use playback_method::*;
+pub use pod_sequence::*;
 pub use production_quality::*;
 pub use qag_media_rating::*;
+pub use slot_position_in_pod::*;
 pub use start_delay::*;
 pub use user_agent_source::*;
 pub use venue_taxonomy::*;
 pub use video_linearity::*;
 pub use video_placement::*;
+pub use video_plcmt::*;
 pub use video_protocols::*;
 pub use volume_normalization::*;
 
@@ -0,0 +1,43 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Pod Sequence
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PodSequence {
+    /// Last pod in the content stream
+    Last,
+    /// Any pod in the content stream
+    Any,
+    /// First pod in the content stream
+    First,
+}
+
+impl Serialize for PodSequence {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match *self {
+            PodSequence::Last => serializer.serialize_i32(-1),
+            PodSequence::Any => serializer.serialize_i32(0),
+            PodSequence::First => serializer.serialize_i32(1),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for PodSequence {
+    fn deserialize<D>(deserializer: D) -> Result<PodSequence, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = i32::deserialize(deserializer)?;
+        match value {
+            -1 => Ok(PodSequence::Last),
+            0 => Ok(PodSequence::Any),
+            1 => Ok(PodSequence::First),
+            _ => Err(serde::de::Error::custom(format!(
+                "Invalid PodSequence value: {}",
+                value
+            ))),
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Slot Position in Pod
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SlotPositionInPod {
+    /// Any position in the pod
+    Any,
+    /// Last position in the pod
+    Last,
+    /// First position in the pod
+    First,
+    /// First or last position in the pod
+    FirstOrLast,
+}
+
+impl Serialize for SlotPositionInPod {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match *self {
+            SlotPositionInPod::Any => serializer.serialize_i32(0),
+            SlotPositionInPod::Last => serializer.serialize_i32(-1),
+            SlotPositionInPod::First => serializer.serialize_i32(1),
+            SlotPositionInPod::FirstOrLast => serializer.serialize_i32(2),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for SlotPositionInPod {
+    fn deserialize<D>(deserializer: D) -> Result<SlotPositionInPod, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = i32::deserialize(deserializer)?;
+        match value {
+            0 => Ok(SlotPositionInPod::Any),
+            -1 => Ok(SlotPositionInPod::Last),
+            1 => Ok(SlotPositionInPod::First),
+            2 => Ok(SlotPositionInPod::FirstOrLast),
+            _ => Err(serde::de::Error::custom(format!(
+                "Invalid SlotPositionInPod value: {}",
+                value
+            ))),
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Video Placement Subtypes
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum VideoPlcmtSubtype {
+    /// Instream: Pre-roll, mid-roll, and post-roll ads that are played before, during or after the streaming video content that the consumer has requested.
+    Instream,
+    /// Accompanying Content: Pre-roll, mid-roll, and post-roll ads that are played before, during, or after streaming video content, where the video content is not the primary focus of the page.
+    AccompanyingContent,
+    /// Interstitial: Video ads that are played without video content, covering the entire or a portion of the screen.
+    Interstitial,
+    /// No Content/Standalone: Video ads that are played without streaming video content, in placements such as slideshows, native feeds, in-content or sticky/floating.
+    NoContentStandalone,
+}
+
+impl Serialize for VideoPlcmtSubtype {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match *self {
+            VideoPlcmtSubtype::Instream => serializer.serialize_u32(1),
+            VideoPlcmtSubtype::AccompanyingContent => serializer.serialize_u32(2),
+            VideoPlcmtSubtype::Interstitial => serializer.serialize_u32(3),
+            VideoPlcmtSubtype::NoContentStandalone => serializer.serialize_u32(4),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for VideoPlcmtSubtype {
+    fn deserialize<D>(deserializer: D) -> Result<VideoPlcmtSubtype, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = u32::deserialize(deserializer)?;
+        match value {
+            1 => Ok(VideoPlcmtSubtype::Instream),
+            2 => Ok(VideoPlcmtSubtype::AccompanyingContent),
+            3 => Ok(VideoPlcmtSubtype::Interstitial),
+            4 => Ok(VideoPlcmtSubtype::NoContentStandalone),
+            _ => Err(serde::de::Error::custom(format!(
+                "Invalid VideoPlcmtSubtype value: {}",
+                value
+            ))),
+        }
+    }
+}
@@ -0,0 +1,973 @@
+//! Seeded synthetic traffic for load tests and offline exchange simulations
+//!
+//! [`TrafficGenerator`] draws bid requests from the weighted distributions of a
+//! [`TrafficConfig`] and can answer them with matching bid responses. The same seed and
+//! configuration always produce the same stream.
+
+use super::enums::*;
+use super::*;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde_json::{Value, json};
+
+/// Choices drawn with probability proportional to their weight
+pub type Weighted<T> = Vec<(T, u32)>;
+
+/// Impression format drawn for each generated impression
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AdFormat {
+    /// Banner offering a single size
+    Banner {
+        /// Width in device independent pixels
+        w: u32,
+        /// Height in device independent pixels
+        h: u32,
+    },
+    /// Video accepting creatives within a duration range
+    Video {
+        /// Minimum duration in seconds
+        minduration: u32,
+        /// Maximum duration in seconds
+        maxduration: u32,
+        /// Video placement subtype
+        plcmt: VideoPlcmtSubtype,
+    },
+    /// Audio accepting creatives within a duration range
+    Audio {
+        /// Minimum duration in seconds
+        minduration: u32,
+        /// Maximum duration in seconds
+        maxduration: u32,
+    },
+    /// Native ad unit
+    Native {
+        /// Native 1.2 placement type: 1 in-feed, 2 in-content, 3 outside content,
+        /// 4 recommendation widget
+        plcmttype: u32,
+    },
+}
+
+impl AdFormat {
+    /// Whether the format can be served on the device type
+    ///
+    /// Connected TVs and set top boxes only receive video and audio.
+    fn fits(&self, devicetype: DeviceType) -> bool {
+        match devicetype {
+            DeviceType::ConnectedTv | DeviceType::SetTopBox | DeviceType::ConnectedDevice => {
+                matches!(self, AdFormat::Video { .. } | AdFormat::Audio { .. })
+            }
+            _ => true,
+        }
+    }
+}
+
+/// Location a generated user is placed in
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeoProfile {
+    /// Country code using ISO-3166-1-alpha-3
+    pub country: String,
+
+    /// Region code using ISO-3166-2
+    pub region: String,
+
+    /// City name
+    pub city: String,
+
+    /// Latitude of the city centre
+    pub lat: f64,
+
+    /// Longitude of the city centre
+    pub lon: f64,
+
+    /// Whether GDPR applies to users in this location
+    pub gdpr: bool,
+
+    /// Whether the US Privacy string is sent for users in this location
+    pub us_privacy: bool,
+}
+
+impl GeoProfile {
+    fn new(country: &str, region: &str, city: &str, lat: f64, lon: f64) -> Self {
+        GeoProfile {
+            country: country.to_string(),
+            region: region.to_string(),
+            city: city.to_string(),
+            lat,
+            lon,
+            gdpr: false,
+            us_privacy: false,
+        }
+    }
+}
+
+/// Extended identifier source attached to a share of generated users
+#[derive(Debug, Clone, PartialEq)]
+pub struct EidSource {
+    /// Source or technology provider responsible for the identifiers
+    pub source: String,
+
+    /// Agent type of the identifiers
+    pub atype: u32,
+
+    /// Share of users carrying an identifier from this source, between 0 and 1
+    pub rate: f64,
+}
+
+impl EidSource {
+    fn new(source: &str, atype: u32, rate: f64) -> Self {
+        EidSource {
+            source: source.to_string(),
+            atype,
+            rate,
+        }
+    }
+}
+
+/// Distributions the generator draws requests and responses from
+///
+/// Rates are probabilities between 0 and 1. The default configuration approximates a
+/// general purpose exchange with mostly mobile traffic from the US and Europe.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrafficConfig {
+    /// Device types of the requests
+    pub device_types: Weighted<DeviceType>,
+
+    /// Locations of the users
+    pub geos: Weighted<GeoProfile>,
+
+    /// Impression formats, filtered by what the device type can render
+    pub formats: Weighted<AdFormat>,
+
+    /// Maximum number of impressions per request
+    pub max_imps: u32,
+
+    /// Share of phone and tablet requests coming from apps rather than sites
+    pub app_rate: f64,
+
+    /// Range of impression floors in USD CPM
+    pub bidfloor: (f64, f64),
+
+    /// Deals offered in private marketplaces
+    pub deals: Vec<Deal>,
+
+    /// Share of impressions carrying a private marketplace
+    pub deal_rate: f64,
+
+    /// Extended identifier sources
+    pub eids: Vec<EidSource>,
+
+    /// Share of GDPR users for whom a consent string is present
+    pub consent_rate: f64,
+
+    /// Share of requests subject to COPPA, which carry no user identifiers
+    pub coppa_rate: f64,
+
+    /// Share of devices with limit ad tracking enabled
+    pub lmt_rate: f64,
+
+    /// Maximum time in milliseconds to submit a bid
+    pub tmax: u32,
+
+    /// Share of impressions receiving a bid in generated responses
+    pub bid_rate: f64,
+
+    /// Bidder seats of generated bids
+    pub seats: Weighted<String>,
+
+    /// Advertiser domains of generated bids
+    pub advertisers: Vec<String>,
+}
+
+impl Default for TrafficConfig {
+    fn default() -> Self {
+        let eu = |profile: GeoProfile| GeoProfile {
+            gdpr: true,
+            ..profile
+        };
+        let us = |profile: GeoProfile| GeoProfile {
+            us_privacy: true,
+            ..profile
+        };
+        let deal = |id: &str, bidfloor: f64| {
+            Deal::builder(id)
+                .bidfloor(bidfloor)
+                .bidfloorcur("USD")
+                .at(1)
+                .build()
+        };
+
+        TrafficConfig {
+            device_types: vec![
+                (DeviceType::Phone, 55),
+                (DeviceType::PersonalComputer, 25),
+                (DeviceType::Tablet, 8),
+                (DeviceType::ConnectedTv, 12),
+            ],
+            geos: vec![
+                (
+                    us(GeoProfile::new("USA", "US-NY", "New York", 40.71, -74.01)),
+                    30,
+                ),
+                (
+                    us(GeoProfile::new(
+                        "USA",
+                        "US-CA",
+                        "Los Angeles",
+                        34.05,
+                        -118.24,
+                    )),
+                    20,
+                ),
+                (
+                    eu(GeoProfile::new("GBR", "GB-LND", "London", 51.51, -0.13)),
+                    12,
+                ),
+                (
+                    eu(GeoProfile::new("DEU", "DE-BE", "Berlin", 52.52, 13.40)),
+                    10,
+                ),
+                (
+                    eu(GeoProfile::new("FRA", "FR-IDF", "Paris", 48.86, 2.35)),
+                    8,
+                ),
+                (
+                    GeoProfile::new("BRA", "BR-SP", "São Paulo", -23.55, -46.63),
+                    10,
+                ),
+                (GeoProfile::new("JPN", "JP-13", "Tokyo", 35.68, 139.69), 10),
+            ],
+            formats: vec![
+                (AdFormat::Banner { w: 300, h: 250 }, 30),
+                (AdFormat::Banner { w: 320, h: 50 }, 20),
+                (AdFormat::Banner { w: 728, h: 90 }, 8),
+                (
+                    AdFormat::Video {
+                        minduration: 15,
+                        maxduration: 30,
+                        plcmt: VideoPlcmtSubtype::Instream,
+                    },
+                    18,
+                ),
+                (
+                    AdFormat::Video {
+                        minduration: 5,
+                        maxduration: 30,
+                        plcmt: VideoPlcmtSubtype::AccompanyingContent,
+                    },
+                    6,
+                ),
+                (
+                    AdFormat::Video {
+                        minduration: 5,
+                        maxduration: 60,
+                        plcmt: VideoPlcmtSubtype::Interstitial,
+                    },
+                    4,
+                ),
+                (
+                    AdFormat::Audio {
+                        minduration: 15,
+                        maxduration: 30,
+                    },
+                    4,
+                ),
+                (AdFormat::Native { plcmttype: 1 }, 8),
+                (AdFormat::Native { plcmttype: 4 }, 2),
+            ],
+            max_imps: 3,
+            app_rate: 0.6,
+            bidfloor: (0.05, 2.5),
+            deals: vec![
+                deal("deal-premium-video", 12.0),
+                deal("deal-auto-intenders", 4.5),
+                deal("deal-sports-fans", 3.0),
+            ],
+            deal_rate: 0.15,
+            eids: vec![
+                EidSource::new("id5-sync.com", 1, 0.35),
+                EidSource::new("liveramp.com", 3, 0.2),
+                EidSource::new("uidapi.com", 3, 0.15),
+            ],
+            consent_rate: 0.8,
+            coppa_rate: 0.01,
+            lmt_rate: 0.1,
+            tmax: 120,
+            bid_rate: 0.3,
+            seats: vec![
+                ("seat-alpha".to_string(), 50),
+                ("seat-beta".to_string(), 30),
+                ("seat-gamma".to_string(), 20),
+            ],
+            advertisers: vec![
+                "brand-one.com".to_string(),
+                "brand-two.com".to_string(),
+                "brand-three.com".to_string(),
+            ],
+        }
+    }
+}
+
+/// Device attributes filled in for a device type
+struct DeviceProfile {
+    make: &'static str,
+    model: &'static str,
+    os: &'static str,
+    osv: &'static str,
+    ua: &'static str,
+    w: u32,
+    h: u32,
+    pxratio: f64,
+}
+
+fn device_profile(devicetype: DeviceType) -> DeviceProfile {
+    match devicetype {
+        DeviceType::PersonalComputer => DeviceProfile {
+            make: "Apple",
+            model: "Macintosh",
+            os: "macOS",
+            osv: "14.5",
+            ua: "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/126.0.0.0 Safari/537.36",
+            w: 1920,
+            h: 1080,
+            pxratio: 2.0,
+        },
+        DeviceType::Tablet => DeviceProfile {
+            make: "Apple",
+            model: "iPad",
+            os: "iOS",
+            osv: "17.5",
+            ua: "Mozilla/5.0 (iPad; CPU OS 17_5 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/17.5 Mobile/15E148 Safari/604.1",
+            w: 820,
+            h: 1180,
+            pxratio: 2.0,
+        },
+        DeviceType::ConnectedTv | DeviceType::SetTopBox | DeviceType::ConnectedDevice => {
+            DeviceProfile {
+                make: "Roku",
+                model: "Roku Ultra",
+                os: "Roku OS",
+                osv: "13.0",
+                ua: "Roku/DVP-13.0 (13.0.0.4193-46)",
+                w: 1920,
+                h: 1080,
+                pxratio: 1.0,
+            }
+        }
+        DeviceType::Phone | DeviceType::MobileTablet => DeviceProfile {
+            make: "Samsung",
+            model: "SM-S921B",
+            os: "Android",
+            osv: "14",
+            ua: "Mozilla/5.0 (Linux; Android 14; SM-S921B) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/126.0.0.0 Mobile Safari/537.36",
+            w: 360,
+            h: 780,
+            pxratio: 3.0,
+        },
+    }
+}
+
+/// Sites as domain, IAB category and publisher id
+const SITES: [(&str, &str, &str); 4] = [
+    ("news.example.com", "IAB12", "pub-1001"),
+    ("sports.example.com", "IAB17", "pub-1002"),
+    ("recipes.example.com", "IAB8", "pub-1003"),
+    ("autos.example.com", "IAB2", "pub-1004"),
+];
+
+/// Apps as bundle, name, IAB category and publisher id
+const APPS: [(&str, &str, &str, &str); 4] = [
+    ("com.example.puzzle", "Puzzle Quest", "IAB9-30", "pub-2001"),
+    ("com.example.weather", "Weather Now", "IAB15-10", "pub-2002"),
+    ("com.example.news", "Daily News", "IAB12", "pub-2003"),
+    ("com.example.streaming", "Stream TV", "IAB1-7", "pub-2004"),
+];
+
+/// Asset ids of generated native requests, referenced by generated native responses
+const NATIVE_TITLE: u32 = 1;
+const NATIVE_MAIN_IMAGE: u32 = 2;
+const NATIVE_ICON: u32 = 3;
+const NATIVE_SPONSORED: u32 = 4;
+
+/// Seeded generator of bid requests and matching bid responses
+///
+/// Also an endless [`Iterator`] of requests.
+#[derive(Debug, Clone)]
+pub struct TrafficGenerator {
+    config: TrafficConfig,
+    rng: StdRng,
+}
+
+impl TrafficGenerator {
+    /// Create a generator drawing from `config`, seeded with `seed`
+    pub fn new(seed: u64, config: TrafficConfig) -> Self {
+        TrafficGenerator {
+            config,
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    /// Configuration the generator draws from
+    pub fn config(&self) -> &TrafficConfig {
+        &self.config
+    }
+
+    /// Generate the next bid request
+    pub fn request(&mut self) -> BidRequest {
+        let devicetype = pick(&mut self.rng, &self.config.device_types)
+            .copied()
+            .unwrap_or(DeviceType::Phone);
+        let geo = pick(&mut self.rng, &self.config.geos).cloned();
+        let coppa = chance(&mut self.rng, self.config.coppa_rate);
+
+        let imps = self.rng.random_range(1..=self.config.max_imps.max(1));
+        let imp = (1..=imps)
+            .map(|n| self.impression(n, devicetype))
+            .collect::<Vec<_>>();
+
+        let in_app = match devicetype {
+            DeviceType::PersonalComputer => false,
+            DeviceType::Phone | DeviceType::Tablet | DeviceType::MobileTablet => {
+                chance(&mut self.rng, self.config.app_rate)
+            }
+            _ => true,
+        };
+        let mut request = BidRequest::builder(uuid(&mut self.rng), imp)
+            .device(self.device(devicetype, geo.as_ref(), in_app, coppa))
+            .user(self.user(geo.as_ref(), coppa))
+            .at(AuctionType::FirstPrice)
+            .tmax(self.config.tmax)
+            .cur(["USD"])
+            .regs(self.regs(geo.as_ref(), coppa));
+        let publisher = if in_app {
+            let (bundle, name, cat, publisher) = APPS[self.rng.random_range(0..APPS.len())];
+            request = request.app(
+                App::builder()
+                    .bundle(bundle)
+                    .name(name)
+                    .storeurl(format!(
+                        "https://play.google.com/store/apps/details?id={bundle}"
+                    ))
+                    .cat([cat])
+                    .publisher(Publisher::builder().id(publisher).build())
+                    .build(),
+            );
+            publisher
+        } else {
+            let (domain, cat, publisher) = SITES[self.rng.random_range(0..SITES.len())];
+            request = request.site(
+                Site::builder()
+                    .domain(domain)
+                    .page(format!(
+                        "https://{domain}/article/{}",
+                        self.rng.random::<u16>()
+                    ))
+                    .cat([cat])
+                    .publisher(Publisher::builder().id(publisher).build())
+                    .build(),
+            );
+            publisher
+        };
+        let schain = SupplyChain::builder(
+            1,
+            [SupplyChainNode::builder("exchange.example", publisher)
+                .hp(1)
+                .build()],
+            "1.0",
+        )
+        .build();
+        request
+            .source(
+                Source::builder()
+                    .tid(uuid(&mut self.rng))
+                    .schain(schain)
+                    .build(),
+            )
+            .build()
+    }
+
+    /// Generate a response to `request`, or `None` when no impression is bid on
+    ///
+    /// Each impression is bid on with the configured bid rate. Bids price at or above the
+    /// impression floor, or at or above the deal floor when bidding on one of its deals,
+    /// and carry markup matching the impression format.
+    pub fn response(&mut self, request: &BidRequest) -> Option<BidResponse> {
+        let mut seatbids: Vec<SeatBid> = Vec::new();
+        for imp in &request.imp {
+            if !chance(&mut self.rng, self.config.bid_rate) {
+                continue;
+            }
+            let bid = self.bid(imp);
+            let seat = pick(&mut self.rng, &self.config.seats).cloned();
+            match seatbids.iter_mut().find(|seatbid| seatbid.seat == seat) {
+                Some(seatbid) => seatbid.bid.push(bid),
+                None => seatbids.push(SeatBid {
+                    bid: vec![bid],
+                    seat,
+                    ..Default::default()
+                }),
+            }
+        }
+        if seatbids.is_empty() {
+            return None;
+        }
+        Some(
+            BidResponse::builder(request.id.as_str())
+                .seatbid(seatbids)
+                .bidid(uuid(&mut self.rng))
+                .cur("USD")
+                .build(),
+        )
+    }
+
+    fn impression(&mut self, n: u32, devicetype: DeviceType) -> Impression {
+        let fitting = self
+            .config
+            .formats
+            .iter()
+            .filter(|(format, _)| format.fits(devicetype))
+            .cloned()
+            .collect::<Vec<_>>();
+        let format = pick(&mut self.rng, &fitting)
+            .or_else(|| pick(&mut self.rng, &self.config.formats))
+            .copied()
+            .unwrap_or(AdFormat::Banner { w: 300, h: 250 });
+        let screen = device_profile(devicetype);
+
+        let mut imp = match format {
+            AdFormat::Banner { w, h } => Impression::banner_sized(n.to_string(), w, h),
+            AdFormat::Video {
+                minduration,
+                maxduration,
+                plcmt,
+            } => {
+                let mut video = Video::instream(minduration, maxduration);
+                video.plcmt = Some(plcmt);
+                video.w = Some(screen.w);
+                video.h = Some(screen.h);
+                if plcmt != VideoPlcmtSubtype::Instream {
+                    video.startdelay = None;
+                }
+                if chance(&mut self.rng, 0.5) {
+                    video.skip = Some(SkipFlag::Skippable);
+                    video.skipafter = Some(5);
+                }
+                let mut imp = Impression::builder(n.to_string()).video(video).build();
+                if plcmt == VideoPlcmtSubtype::Interstitial {
+                    imp.instl = Some(InterstitialFlag::Interstitial);
+                }
+                imp
+            }
+            AdFormat::Audio {
+                minduration,
+                maxduration,
+            } => Impression::builder(n.to_string())
+                .audio(
+                    Audio::builder(["audio/mp4", "audio/mpeg"])
+                        .minduration(minduration)
+                        .maxduration(maxduration)
+                        .protocols([2, 3, 5, 6, 7, 8])
+                        .startdelay(0)
+                        .build(),
+                )
+                .build(),
+            AdFormat::Native { plcmttype } => Impression::builder(n.to_string())
+                .native(
+                    Native::builder(native_request(native_request_json(plcmttype)))
+                        .ver("1.2")
+                        .build(),
+                )
+                .build(),
+        };
+
+        let (low, high) = self.config.bidfloor;
+        let bidfloor = low + (high - low).max(0.0) * self.rng.random::<f64>();
+        imp.bidfloor = Some(round_cents(bidfloor));
+        imp.bidfloorcur = Some("USD".to_string());
+        imp.tagid = Some(format!("slot-{n}"));
+        imp.secure = Some(SecureFlag::Secure);
+
+        if !self.config.deals.is_empty() && chance(&mut self.rng, self.config.deal_rate) {
+            let count = self.rng.random_range(1..=self.config.deals.len().min(2));
+            let start = self.rng.random_range(0..self.config.deals.len());
+            let deals = (0..count)
+                .map(|i| self.config.deals[(start + i) % self.config.deals.len()].clone())
+                .collect::<Vec<_>>();
+            imp.pmp = Some(Pmp::builder().private_auction(0).deals(deals).build());
+        }
+        imp
+    }
+
+    fn device(
+        &mut self,
+        devicetype: DeviceType,
+        geo: Option<&GeoProfile>,
+        in_app: bool,
+        coppa: bool,
+    ) -> Device {
+        let profile = device_profile(devicetype);
+        let lmt = chance(&mut self.rng, self.config.lmt_rate);
+        let connectiontype = match devicetype {
+            DeviceType::Phone | DeviceType::MobileTablet if chance(&mut self.rng, 0.5) => {
+                ConnectionType::Cellular4G
+            }
+            DeviceType::PersonalComputer | DeviceType::ConnectedTv
+                if chance(&mut self.rng, 0.3) =>
+            {
+                ConnectionType::Ethernet
+            }
+            _ => ConnectionType::Wifi,
+        };
+        let ip = format!(
+            "{}.{}.{}.0",
+            self.rng.random_range(11..=99),
+            self.rng.random_range(0..=255),
+            self.rng.random_range(0..=255)
+        );
+
+        let mut device = Device::builder()
+            .ua(profile.ua)
+            .devicetype(devicetype)
+            .make(profile.make)
+            .model(profile.model)
+            .os(profile.os)
+            .osv(profile.osv)
+            .w(profile.w)
+            .h(profile.h)
+            .pxratio(profile.pxratio)
+            .js(JavaScriptFlag::Supported)
+            .language("en")
+            .ip(ip)
+            .connectiontype(connectiontype)
+            .dnt(DoNotTrackFlag::TrackingUnrestricted)
+            .build();
+        if let Some(geo) = geo {
+            device.geo = Some(self.geo(geo));
+        }
+        if lmt {
+            device.lmt = Some(LimitAdTrackingFlag::TrackingLimited);
+        } else {
+            device.lmt = Some(LimitAdTrackingFlag::TrackingUnrestricted);
+        }
+        if in_app {
+            device.ifa = Some(if lmt || coppa {
+                "00000000-0000-0000-0000-000000000000".to_string()
+            } else {
+                uuid(&mut self.rng)
+            });
+        }
+        device
+    }
+
+    fn geo(&mut self, profile: &GeoProfile) -> Geo {
+        let jitter = |rng: &mut StdRng| (rng.random::<f64>() - 0.5) * 0.2;
+        Geo::builder()
+            .lat(profile.lat + jitter(&mut self.rng))
+            .lon(profile.lon + jitter(&mut self.rng))
+            .r#type(LocationType::IpAddress)
+            .country(profile.country.as_str())
+            .region(profile.region.as_str())
+            .city(profile.city.as_str())
+            .build()
+    }
+
+    fn user(&mut self, geo: Option<&GeoProfile>, coppa: bool) -> User {
+        if coppa {
+            return User::builder().build();
+        }
+        let mut user = User::builder().id(uuid(&mut self.rng)).build();
+        if geo.is_some_and(|geo| geo.gdpr) && chance(&mut self.rng, self.config.consent_rate) {
+            user.consent = Some(consent_string(&mut self.rng));
+        }
+        if chance(&mut self.rng, 0.3) {
+            user.yob = Some(self.rng.random_range(1950..=2005));
+        }
+        let mut eids = Vec::new();
+        for source in &self.config.eids {
+            if chance(&mut self.rng, source.rate) {
+                let uid = Uid::builder(uuid(&mut self.rng))
+                    .atype(source.atype)
+                    .build();
+                eids.push(Eid::builder(source.source.as_str(), [uid]).build());
+            }
+        }
+        if !eids.is_empty() {
+            user.eids = Some(eids);
+        }
+        user
+    }
+
+    fn regs(&mut self, geo: Option<&GeoProfile>, coppa: bool) -> Regs {
+        let mut regs = Regs::builder()
+            .coppa(if coppa { CoppaFlag::Yes } else { CoppaFlag::No })
+            .build();
+        if let Some(geo) = geo {
+            regs.gdpr = Some(if geo.gdpr {
+                GdprFlag::Yes
+            } else {
+                GdprFlag::No
+            });
+            if geo.us_privacy {
+                let opt_out = if chance(&mut self.rng, 0.1) { 'Y' } else { 'N' };
+                regs.us_privacy = Some(format!("1Y{opt_out}N"));
+            }
+        }
+        regs
+    }
+
+    fn bid(&mut self, imp: &Impression) -> Bid {
+        let deal = imp
+            .pmp
+            .as_ref()
+            .and_then(|pmp| pmp.deals.as_ref())
+            .filter(|_| chance(&mut self.rng, 0.5))
+            .and_then(|deals| deals.get(self.rng.random_range(0..deals.len().max(1))));
+        let floor = deal
+            .and_then(|deal| deal.bidfloor)
+            .or(imp.bidfloor)
+            .unwrap_or(0.0);
+        let price = ceil_cents(floor * (1.0 + self.rng.random::<f64>() * 0.5)).max(0.01);
+
+        let advertiser = if self.config.advertisers.is_empty() {
+            "advertiser.example".to_string()
+        } else {
+            self.config.advertisers[self.rng.random_range(0..self.config.advertisers.len())].clone()
+        };
+        let crid = format!("cr-{}", self.rng.random::<u16>());
+        let mut bid = Bid::builder(uuid(&mut self.rng), imp.id.as_str(), price)
+            .adomain([advertiser.as_str()])
+            .crid(crid.as_str())
+            .cid(format!("cmp-{}", self.rng.random::<u8>()))
+            .burl("https://bidder.example/win?price=${AUCTION_PRICE}")
+            .build();
+        bid.dealid = deal.map(|deal| deal.id.clone());
+
+        if let Some(banner) = &imp.banner {
+            let (w, h) = (banner.w.unwrap_or(300), banner.h.unwrap_or(250));
+            bid.adm = Some(format!(
+                r#"<a href="https://{advertiser}/"><img src="https://cdn.{advertiser}/{crid}.png" width="{w}" height="{h}"></a>"#
+            ));
+            bid.w = Some(w);
+            bid.h = Some(h);
+        } else if let Some(video) = &imp.video {
+            let dur = self.duration(video.minduration, video.maxduration);
+            bid.adm = Some(vast_inline(&crid, &advertiser, dur, "video/mp4"));
+            bid.w = video.w;
+            bid.h = video.h;
+            bid.dur = Some(dur);
+        } else if let Some(audio) = &imp.audio {
+            let dur = self.duration(audio.minduration, audio.maxduration);
+            bid.adm = Some(vast_inline(&crid, &advertiser, dur, "audio/mp4"));
+            bid.dur = Some(dur);
+        } else if imp.native.is_some() {
+            bid.adm = Some(
+                json!({
+                    "native": {
+                        "ver": "1.2",
+                        "assets": [
+                            {"id": NATIVE_TITLE, "title": {"text": format!("Discover {advertiser}")}},
+                            {"id": NATIVE_MAIN_IMAGE, "img": {"url": format!("https://cdn.{advertiser}/{crid}.jpg"), "w": 1200, "h": 627}},
+                            {"id": NATIVE_SPONSORED, "data": {"value": advertiser}}
+                        ],
+                        "link": {"url": format!("https://{advertiser}/")}
+                    }
+                })
+                .to_string(),
+            );
+        }
+        bid
+    }
+
+    /// Creative duration within the accepted range, on a 5 second grid where possible
+    fn duration(&mut self, minduration: Option<u32>, maxduration: Option<u32>) -> u32 {
+        let min = minduration.unwrap_or(5).max(1);
+        let max = maxduration.unwrap_or(30).max(min);
+        let dur = self.rng.random_range(min..=max);
+        let rounded = dur - dur % 5;
+        if rounded >= min { rounded } else { dur }
+    }
+}
+
+impl Iterator for TrafficGenerator {
+    type Item = BidRequest;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.request())
+    }
+}
+
+/// Draw one of the `choices` with probability proportional to its weight
+fn pick<'a, T>(rng: &mut StdRng, choices: &'a [(T, u32)]) -> Option<&'a T> {
+    let total = choices
+        .iter()
+        .map(|(_, weight)| *weight as u64)
+        .sum::<u64>();
+    if total == 0 {
+        return None;
+    }
+    let mut n = rng.random_range(0..total);
+    for (choice, weight) in choices {
+        let weight = *weight as u64;
+        if n < weight {
+            return Some(choice);
+        }
+        n -= weight;
+    }
+    None
+}
+
+fn chance(rng: &mut StdRng, rate: f64) -> bool {
+    rng.random::<f64>() < rate
+}
+
+fn uuid(rng: &mut StdRng) -> String {
+    let hex = format!("{:032x}", rng.random::<u128>());
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..]
+    )
+}
+
+/// A string shaped like a TCF v2 consent string
+fn consent_string(rng: &mut StdRng) -> String {
+    const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+    let body = (0..60)
+        .map(|_| ALPHABET[rng.random_range(0..ALPHABET.len())] as char)
+        .collect::<String>();
+    format!("CP{body}")
+}
+
+fn round_cents(value: f64) -> f64 {
+    (value * 100.0).round() / 100.0
+}
+
+fn ceil_cents(value: f64) -> f64 {
+    (value * 100.0).ceil() / 100.0
+}
+
+fn native_request_json(plcmttype: u32) -> Value {
+    json!({
+        "ver": "1.2",
+        "context": 1,
+        "plcmttype": plcmttype,
+        "plcmtcnt": 1,
+        "assets": [
+            {"id": NATIVE_TITLE, "required": 1, "title": {"len": 90}},
+            {"id": NATIVE_MAIN_IMAGE, "required": 1, "img": {"type": 3, "wmin": 600, "hmin": 314}},
+            {"id": NATIVE_ICON, "img": {"type": 1, "w": 128, "h": 128}},
+            {"id": NATIVE_SPONSORED, "required": 1, "data": {"type": 1, "len": 25}}
+        ],
+        "eventtrackers": [{"event": 1, "methods": [1]}]
+    })
+}
+
+#[cfg(feature = "native")]
+fn native_request(request: Value) -> crate::native::v1_2::NativeRequest {
+    serde_json::from_value(request).unwrap_or_default()
+}
+
+#[cfg(not(feature = "native"))]
+fn native_request(request: Value) -> String {
+    request.to_string()
+}
+
+/// Minimal VAST 4.0 inline ad with a single progressive media file
+fn vast_inline(crid: &str, advertiser: &str, duration: u32, mime: &str) -> String {
+    format!(
+        r#"<VAST version="4.0"><Ad id="{crid}"><InLine><AdSystem>Synthetic</AdSystem><AdTitle>{advertiser}</AdTitle><Impression><![CDATA[https://bidder.example/imp?crid={crid}]]></Impression><Creatives><Creative id="{crid}"><Linear><Duration>00:{:02}:{:02}</Duration><MediaFiles><MediaFile delivery="progressive" type="{mime}"><![CDATA[https://cdn.{advertiser}/{crid}.mp4]]></MediaFile></MediaFiles></Linear></Creative></Creatives></InLine></Ad></VAST>"#,
+        duration / 60,
+        duration % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_generated_traffic() {
+        let config = TrafficConfig {
+            bid_rate: 0.5,
+            ..Default::default()
+        };
+        let requests = TrafficGenerator::new(7, config.clone())
+            .take(300)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            TrafficGenerator::new(7, config.clone())
+                .take(300)
+                .collect::<Vec<_>>(),
+            requests
+        );
+
+        let mut generator = TrafficGenerator::new(11, config);
+        let mut responses = 0;
+        for request in &requests {
+            let json = serde_json::to_string(request).unwrap();
+            assert!(serde_json::from_str::<BidRequest>(&json).is_ok());
+            assert!(request.site.is_some() != request.app.is_some());
+            assert!(request.device.as_ref().unwrap().ua.is_some());
+
+            let ids = request
+                .imp
+                .iter()
+                .map(|imp| &imp.id)
+                .collect::<HashSet<_>>();
+            assert_eq!(ids.len(), request.imp.len());
+            for imp in &request.imp {
+                let media = [
+                    imp.banner.is_some(),
+                    imp.video.is_some(),
+                    imp.audio.is_some(),
+                    imp.native.is_some(),
+                ];
+                assert_eq!(media.iter().filter(|m| **m).count(), 1);
+                if let Some(video) = &imp.video {
+                    assert!(!video.mimes.is_empty());
+                    assert!(video.minduration <= video.maxduration);
+                }
+                #[cfg(feature = "native")]
+                if let Some(native) = &imp.native {
+                    assert_eq!(native.request.assets.len(), 4);
+                }
+                assert!(imp.bidfloor.unwrap() >= 0.0);
+            }
+
+            let Some(response) = generator.response(request) else {
+                continue;
+            };
+            responses += 1;
+            assert_eq!(response.id, request.id);
+            for bid in response.seatbid.iter().flatten().flat_map(|seat| &seat.bid) {
+                let imp = request.imp.iter().find(|imp| imp.id == bid.impid).unwrap();
+                let deals = imp.pmp.as_ref().and_then(|pmp| pmp.deals.as_ref());
+                let floor = match &bid.dealid {
+                    Some(id) => deals
+                        .and_then(|deals| deals.iter().find(|deal| &deal.id == id))
+                        .unwrap()
+                        .bidfloor
+                        .unwrap(),
+                    None => imp.bidfloor.unwrap(),
+                };
+                assert!(bid.price >= floor);
+                assert!(bid.adm.is_some());
+            }
+        }
+        assert!(responses > 0 && responses < requests.len());
+
+        let config = TrafficConfig {
+            coppa_rate: 1.0,
+            ..Default::default()
+        };
+        for request in TrafficGenerator::new(13, config).take(50) {
+            assert_eq!(request.regs.unwrap().coppa, Some(CoppaFlag::Yes));
+            let user = request.user.unwrap();
+            assert!(user.id.is_none() && user.yob.is_none() && user.eids.is_none());
+            assert!(user.consent.is_none());
+        }
+    }
+}
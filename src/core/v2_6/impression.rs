@@ -81,6 +81,6 @@ pub struct Impression<Ext = Value> {
     pub ext: Option<Ext>,
 }
 
-fn default_bidfloorcur() -> Option<String> {
+pub(super) fn default_bidfloorcur() -> Option<String> {
     Some("USD".to_string())
 }
@@ -0,0 +1,311 @@
+//! Lazily parsed view of a bid request for fast pre-filtering
+//!
+//! [`BidRequestView`] splits the top level of the JSON into unparsed [`RawValue`]s and
+//! only decodes the objects an accessor touches. A view can be promoted to a fully
+//! parsed [`BidRequest`] once a request passes the filters.
+
+use super::borrowed::borrow_option_str;
+use super::enums::CoppaFlag;
+use super::*;
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use serde_json::value::RawValue;
+use std::borrow::Cow;
+
+#[derive(Debug, Clone, Default, Deserialize)]
+struct RawBidRequest<'a> {
+    #[serde(borrow, default)]
+    id: Option<&'a RawValue>,
+    #[serde(borrow, default)]
+    imp: Option<&'a RawValue>,
+    #[serde(borrow, default)]
+    site: Option<&'a RawValue>,
+    #[serde(borrow, default)]
+    app: Option<&'a RawValue>,
+    #[serde(borrow, default)]
+    dooh: Option<&'a RawValue>,
+    #[serde(borrow, default)]
+    device: Option<&'a RawValue>,
+    #[serde(borrow, default)]
+    user: Option<&'a RawValue>,
+    #[serde(borrow, default)]
+    source: Option<&'a RawValue>,
+    #[serde(borrow, default)]
+    regs: Option<&'a RawValue>,
+    #[serde(borrow, default)]
+    ext: Option<&'a RawValue>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+struct RawImpression<'a> {
+    #[serde(borrow)]
+    id: Cow<'a, str>,
+    #[serde(default)]
+    bidfloor: Option<f64>,
+    #[serde(borrow, default, deserialize_with = "borrow_option_str")]
+    bidfloorcur: Option<Cow<'a, str>>,
+    #[serde(borrow, default)]
+    banner: Option<&'a RawValue>,
+    #[serde(borrow, default)]
+    video: Option<&'a RawValue>,
+    #[serde(borrow, default)]
+    audio: Option<&'a RawValue>,
+    #[serde(borrow, default)]
+    native: Option<&'a RawValue>,
+    #[serde(borrow, default)]
+    ext: Option<&'a RawValue>,
+}
+
+#[derive(Deserialize)]
+struct RawNative<'a> {
+    #[serde(borrow, default)]
+    request: Option<&'a RawValue>,
+}
+
+#[derive(Deserialize)]
+struct AppBundle<'a> {
+    #[serde(borrow, default, deserialize_with = "borrow_option_str")]
+    bundle: Option<Cow<'a, str>>,
+}
+
+#[derive(Deserialize)]
+struct SiteDomain<'a> {
+    #[serde(borrow, default, deserialize_with = "borrow_option_str")]
+    domain: Option<Cow<'a, str>>,
+}
+
+#[derive(Deserialize)]
+struct DeviceCountry<'a> {
+    #[serde(borrow, default)]
+    geo: Option<GeoCountry<'a>>,
+}
+
+#[derive(Deserialize)]
+struct GeoCountry<'a> {
+    #[serde(borrow, default, deserialize_with = "borrow_option_str")]
+    country: Option<Cow<'a, str>>,
+}
+
+#[derive(Deserialize)]
+struct RegsCoppa {
+    #[serde(default)]
+    coppa: Option<CoppaFlag>,
+}
+
+fn decode<'a, T: Deserialize<'a>>(raw: Option<&'a RawValue>) -> serde_json::Result<Option<T>> {
+    raw.map(|raw| serde_json::from_str(raw.get())).transpose()
+}
+
+/// Bid request view that decodes fields on access
+#[derive(Debug, Clone)]
+pub struct BidRequestView<'a> {
+    json: &'a str,
+    raw: RawBidRequest<'a>,
+}
+
+impl<'a> BidRequestView<'a> {
+    /// Split the top level of a bid request without decoding its objects
+    pub fn parse(json: &'a str) -> serde_json::Result<Self> {
+        Ok(BidRequestView {
+            json,
+            raw: serde_json::from_str(json)?,
+        })
+    }
+
+    /// The JSON the view was parsed from
+    pub fn json(&self) -> &'a str {
+        self.json
+    }
+
+    /// ID of the bid request
+    pub fn id(&self) -> serde_json::Result<Cow<'a, str>> {
+        decode::<Cow<'a, str>>(self.raw.id)?.ok_or_else(|| serde::de::Error::missing_field("id"))
+    }
+
+    /// Impressions of the request, each decoding only its floor and identifiers
+    pub fn imps(&self) -> serde_json::Result<Vec<ImpView<'a>>> {
+        decode::<Vec<&'a RawValue>>(self.raw.imp)?
+            .unwrap_or_default()
+            .into_iter()
+            .map(|json| {
+                Ok(ImpView {
+                    json,
+                    raw: serde_json::from_str(json.get())?,
+                })
+            })
+            .collect()
+    }
+
+    /// `device.geo.country`
+    pub fn device_country(&self) -> serde_json::Result<Option<Cow<'a, str>>> {
+        Ok(decode::<DeviceCountry>(self.raw.device)?
+            .and_then(|device| device.geo)
+            .and_then(|geo| geo.country))
+    }
+
+    /// `app.bundle`
+    pub fn app_bundle(&self) -> serde_json::Result<Option<Cow<'a, str>>> {
+        Ok(decode::<AppBundle>(self.raw.app)?.and_then(|app| app.bundle))
+    }
+
+    /// `site.domain`
+    pub fn site_domain(&self) -> serde_json::Result<Option<Cow<'a, str>>> {
+        Ok(decode::<SiteDomain>(self.raw.site)?.and_then(|site| site.domain))
+    }
+
+    /// `regs.coppa`
+    pub fn coppa(&self) -> serde_json::Result<Option<CoppaFlag>> {
+        Ok(decode::<RegsCoppa>(self.raw.regs)?.and_then(|regs| regs.coppa))
+    }
+
+    /// Decode the `site` object
+    pub fn site(&self) -> serde_json::Result<Option<Site>> {
+        decode(self.raw.site)
+    }
+
+    /// Decode the `app` object
+    pub fn app(&self) -> serde_json::Result<Option<App>> {
+        decode(self.raw.app)
+    }
+
+    /// Decode the `dooh` object
+    pub fn dooh(&self) -> serde_json::Result<Option<Dooh>> {
+        decode(self.raw.dooh)
+    }
+
+    /// Decode the `device` object
+    pub fn device(&self) -> serde_json::Result<Option<Device>> {
+        decode(self.raw.device)
+    }
+
+    /// Decode the `user` object
+    pub fn user(&self) -> serde_json::Result<Option<User>> {
+        decode(self.raw.user)
+    }
+
+    /// Decode the `source` object
+    pub fn source(&self) -> serde_json::Result<Option<Source>> {
+        decode(self.raw.source)
+    }
+
+    /// Decode the `regs` object
+    pub fn regs(&self) -> serde_json::Result<Option<Regs>> {
+        decode(self.raw.regs)
+    }
+
+    /// The undecoded `ext` object
+    pub fn ext(&self) -> Option<&'a RawValue> {
+        self.raw.ext
+    }
+
+    /// Fully parse the request
+    pub fn to_bid_request<Ext: DeserializeOwned>(&self) -> serde_json::Result<BidRequest<Ext>> {
+        serde_json::from_str(self.json)
+    }
+}
+
+/// Impression view holding its media objects undecoded
+#[derive(Debug, Clone)]
+pub struct ImpView<'a> {
+    json: &'a RawValue,
+    raw: RawImpression<'a>,
+}
+
+impl<'a> ImpView<'a> {
+    /// ID of the impression
+    pub fn id(&self) -> &str {
+        &self.raw.id
+    }
+
+    /// Minimum bid in CPM
+    pub fn bidfloor(&self) -> Option<f64> {
+        self.raw.bidfloor
+    }
+
+    /// Currency of the floor as sent; `None` means the OpenRTB default of USD
+    pub fn bidfloorcur(&self) -> Option<&str> {
+        self.raw.bidfloorcur.as_deref()
+    }
+
+    /// The undecoded `banner` object
+    pub fn banner(&self) -> Option<&'a RawValue> {
+        self.raw.banner
+    }
+
+    /// The undecoded `video` object
+    pub fn video(&self) -> Option<&'a RawValue> {
+        self.raw.video
+    }
+
+    /// The undecoded `audio` object
+    pub fn audio(&self) -> Option<&'a RawValue> {
+        self.raw.audio
+    }
+
+    /// The undecoded `native` object
+    pub fn native(&self) -> Option<&'a RawValue> {
+        self.raw.native
+    }
+
+    /// The undecoded `native.request`, either a JSON string or an object
+    pub fn native_request(&self) -> serde_json::Result<Option<&'a RawValue>> {
+        Ok(decode::<RawNative>(self.raw.native)?.and_then(|native| native.request))
+    }
+
+    /// The undecoded `ext` object
+    pub fn ext(&self) -> Option<&'a RawValue> {
+        self.raw.ext
+    }
+
+    /// Fully parse the impression
+    pub fn to_impression<Ext: DeserializeOwned>(&self) -> serde_json::Result<Impression<Ext>> {
+        serde_json::from_str(self.json.get())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REQUEST: &str = r#"{
+        "id": "req-1",
+        "imp": [
+            {"id": "1", "bidfloor": 1.5, "banner": {"w": 300, "h": 250}},
+            {"id": "2", "native": {"request": "{\"ver\":\"1.2\",\"assets\":[]}"}, "ext": {"k": 1}}
+        ],
+        "app": {"bundle": "com.example.app", "publisher": {"id": "p"}},
+        "device": {"ua": "Mozilla/5.0", "geo": {"country": "USA", "lat": 1.0}},
+        "regs": {"coppa": 1},
+        "ext": {"schain": {}}
+    }"#;
+
+    #[test]
+    fn test_lazy_view() {
+        let view = BidRequestView::parse(REQUEST).unwrap();
+        assert_eq!(view.id().unwrap(), "req-1");
+        assert_eq!(view.device_country().unwrap().as_deref(), Some("USA"));
+        assert_eq!(
+            view.app_bundle().unwrap().as_deref(),
+            Some("com.example.app")
+        );
+        assert_eq!(view.site_domain().unwrap(), None);
+        assert_eq!(view.coppa().unwrap(), Some(CoppaFlag::Yes));
+        assert_eq!(view.ext().unwrap().get(), "{\"schain\": {}}");
+
+        let imps = view.imps().unwrap();
+        assert_eq!(imps[0].bidfloor(), Some(1.5));
+        assert_eq!(imps[0].bidfloorcur(), None);
+        assert!(imps[0].native_request().unwrap().is_none());
+        assert_eq!(
+            imps[1].native_request().unwrap().unwrap().get(),
+            r#""{\"ver\":\"1.2\",\"assets\":[]}""#
+        );
+        assert_eq!(imps[1].to_impression::<serde_json::Value>().unwrap().id, "2");
+
+        let request: BidRequest = view.to_bid_request().unwrap();
+        assert_eq!(
+            request,
+            serde_json::from_str::<BidRequest>(REQUEST).unwrap()
+        );
+    }
+}
@@ -14,6 +14,7 @@ pub mod data;
 pub mod deal;
 pub mod device;
 pub mod dooh;
+pub mod eid;
 pub mod format;
 pub mod geo;
 pub mod impression;
@@ -27,6 +28,7 @@ pub mod regs;
 pub mod segment;
 pub mod site;
 pub mod source;
+pub mod supply_chain;
 pub mod user;
 pub mod user_agent;
 pub mod video;
@@ -36,6 +38,37 @@ pub mod bid;
 pub mod bid_response;
 pub mod seat_bid;
 
+// Traversal
+pub mod visitor;
+
+// Zero-copy variants of request objects, kept in their own namespace
+pub mod borrowed;
+
+// Lazily decoded view of a request for pre-filtering
+pub mod lazy;
+
+// OpenRTB 2.5 compatibility
+pub mod compat;
+
+// Typed builders and common request shapes
+pub mod builder;
+
+// Enforcement of privacy signals
+pub mod privacy;
+
+// Currency normalization of floors and bid prices
+pub mod currency_conversion;
+
+// Category validation and cross-taxonomy block lists
+pub mod category;
+
+// Structured user agents derived from User-Agent strings and Client Hints
+pub mod user_agent_parsing;
+
+// Seeded synthetic traffic for load tests
+#[cfg(feature = "generator")]
+pub mod generator;
+
 // Re-export all structs for easy access
 pub use app::*;
 pub use audio::*;
@@ -44,19 +77,27 @@ pub use bid::*;
 pub use bid_request::*;
 pub use bid_response::*;
 pub use brand::*;
+pub use builder::*;
+pub use category::*;
 pub use channel::*;
+pub use compat::*;
 pub use content::*;
 pub use data::*;
 pub use deal::*;
 pub use device::*;
 pub use dooh::*;
+pub use eid::*;
 pub use format::*;
+#[cfg(feature = "generator")]
+pub use generator::*;
 pub use geo::*;
 pub use impression::*;
+pub use lazy::*;
 pub use metric::*;
 pub use native::*;
 pub use network::*;
 pub use pmp::*;
+pub use privacy::*;
 pub use producer::*;
 pub use publisher::*;
 pub use regs::*;
@@ -64,8 +105,11 @@ pub use seat_bid::*;
 pub use segment::*;
 pub use site::*;
 pub use source::*;
+pub use supply_chain::*;
 pub use user::*;
 pub use user_agent::*;
+pub use user_agent_parsing::*;
 pub use video::*;
+pub use visitor::*;
 
 pub mod enums;
@@ -0,0 +1,578 @@
+//! Enforcement of the privacy signals carried by a bid request
+//!
+//! A [`PrivacyPolicy`] maps each signal (COPPA, GDPR without consent, a US Privacy sale
+//! opt-out, limit ad tracking and do not track) to the [`PrivacyActions`] taken when it is
+//! present. [`PrivacyPolicy::enforce`] applies the union of the triggered actions to a
+//! request and reports every field it modified.
+//!
+//! [`PrivacyPolicies`] keys policies by [`Jurisdiction`], such as the EU or a single US
+//! state, and enforces the policy of the jurisdiction a request's geo falls in.
+
+use super::enums::*;
+use super::*;
+use std::collections::HashMap;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+/// Member states of the European Economic Area, by ISO-3166-1 alpha-3 code
+const EEA_COUNTRIES: [&str; 30] = [
+    "AUT", "BEL", "BGR", "CYP", "CZE", "DEU", "DNK", "ESP", "EST", "FIN", "FRA", "GRC", "HRV",
+    "HUN", "IRL", "ISL", "ITA", "LIE", "LTU", "LUX", "LVA", "MLT", "NLD", "NOR", "POL", "PRT",
+    "ROU", "SVK", "SVN", "SWE",
+];
+
+/// Privacy signal that triggers a set of actions
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrivacySignal {
+    /// `regs.coppa` is set
+    Coppa,
+    /// `regs.gdpr` is set and the request carries no `user.consent`
+    Gdpr,
+    /// `regs.us_privacy` signals an opt-out of sale
+    UsPrivacyOptOut,
+    /// `device.lmt` is set
+    LimitAdTracking,
+    /// `device.dnt` is set
+    DoNotTrack,
+}
+
+/// Modifications applied when a privacy signal is present
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PrivacyActions {
+    /// Remove `user.id`, `user.buyeruid`, `user.yob` and `user.gender`
+    pub remove_user_ids: bool,
+
+    /// Remove `device.ifa`
+    pub remove_ifa: bool,
+
+    /// Remove `user.eids`
+    pub remove_eids: bool,
+
+    /// Remove the hashed device identifiers `didsha1`, `didmd5`, `dpidsha1`, `dpidmd5`,
+    /// `macsha1` and `macmd5`
+    pub remove_hashed_device_ids: bool,
+
+    /// Truncate IPv4 addresses to /24 and IPv6 addresses to /48
+    pub truncate_ip: bool,
+
+    /// Round `lat` and `lon` of the device and user geos to this many decimals
+    pub round_geo: Option<u32>,
+}
+
+impl PrivacyActions {
+    /// Every action, with coordinates rounded to two decimals
+    pub fn all() -> Self {
+        PrivacyActions {
+            remove_user_ids: true,
+            remove_ifa: true,
+            remove_eids: true,
+            remove_hashed_device_ids: true,
+            truncate_ip: true,
+            round_geo: Some(2),
+        }
+    }
+
+    /// Combine with `other`, keeping every action of both and the coarser rounding
+    pub fn union(self, other: Self) -> Self {
+        PrivacyActions {
+            remove_user_ids: self.remove_user_ids || other.remove_user_ids,
+            remove_ifa: self.remove_ifa || other.remove_ifa,
+            remove_eids: self.remove_eids || other.remove_eids,
+            remove_hashed_device_ids: self.remove_hashed_device_ids
+                || other.remove_hashed_device_ids,
+            truncate_ip: self.truncate_ip || other.truncate_ip,
+            round_geo: match (self.round_geo, other.round_geo) {
+                (Some(a), Some(b)) => Some(a.min(b)),
+                (a, b) => a.or(b),
+            },
+        }
+    }
+}
+
+/// Actions taken for each privacy signal
+///
+/// The default policy strips every identifier for COPPA, GDPR without consent and US
+/// Privacy opt-outs, and drops the advertising ID and hashed device IDs for limit ad
+/// tracking and do not track.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PrivacyPolicy {
+    /// Actions for requests subject to COPPA
+    pub coppa: PrivacyActions,
+
+    /// Actions for requests subject to GDPR without a consent string
+    pub gdpr: PrivacyActions,
+
+    /// Actions for requests whose US Privacy string opts out of sale
+    pub us_privacy_opt_out: PrivacyActions,
+
+    /// Actions for devices with limit ad tracking enabled
+    pub lmt: PrivacyActions,
+
+    /// Actions for devices with do not track enabled
+    pub dnt: PrivacyActions,
+}
+
+impl Default for PrivacyPolicy {
+    fn default() -> Self {
+        let device_tracking = PrivacyActions {
+            remove_ifa: true,
+            remove_hashed_device_ids: true,
+            ..Default::default()
+        };
+        PrivacyPolicy {
+            coppa: PrivacyActions::all(),
+            gdpr: PrivacyActions::all(),
+            us_privacy_opt_out: PrivacyActions::all(),
+            lmt: device_tracking,
+            dnt: device_tracking,
+        }
+    }
+}
+
+/// How a field was modified
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrivacyEdit {
+    /// The field was removed
+    Removed,
+    /// The IP address was truncated
+    Truncated,
+    /// The coordinate was rounded
+    Rounded,
+}
+
+/// A field modified while enforcing a policy
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PrivacyAudit {
+    /// Path of the modified field
+    pub path: &'static str,
+
+    /// How the field was modified
+    pub edit: PrivacyEdit,
+}
+
+/// Outcome of enforcing a policy on a request
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PrivacyReport {
+    /// Jurisdiction whose policy was enforced; `None` for the default policy
+    pub jurisdiction: Option<Jurisdiction>,
+
+    /// Signals present on the request
+    pub signals: Vec<PrivacySignal>,
+
+    /// Fields modified, in the order they were changed
+    pub audit: Vec<PrivacyAudit>,
+}
+
+impl PrivacyPolicy {
+    /// Signals present on `request`
+    pub fn signals<Ext>(&self, request: &BidRequest<Ext>) -> Vec<PrivacySignal> {
+        let regs = request.regs.as_ref();
+        let device = request.device.as_ref();
+        let consent = request
+            .user
+            .as_ref()
+            .and_then(|user| user.consent.as_deref())
+            .is_some_and(|consent| !consent.is_empty());
+
+        let mut signals = Vec::new();
+        if regs.and_then(|regs| regs.coppa) == Some(CoppaFlag::Yes) {
+            signals.push(PrivacySignal::Coppa);
+        }
+        if regs.and_then(|regs| regs.gdpr) == Some(GdprFlag::Yes) && !consent {
+            signals.push(PrivacySignal::Gdpr);
+        }
+        if regs
+            .and_then(|regs| regs.us_privacy.as_deref())
+            .is_some_and(us_privacy_opt_out)
+        {
+            signals.push(PrivacySignal::UsPrivacyOptOut);
+        }
+        if device.and_then(|device| device.lmt) == Some(LimitAdTrackingFlag::TrackingLimited) {
+            signals.push(PrivacySignal::LimitAdTracking);
+        }
+        if device.and_then(|device| device.dnt) == Some(DoNotTrackFlag::DoNotTrack) {
+            signals.push(PrivacySignal::DoNotTrack);
+        }
+        signals
+    }
+
+    /// Actions configured for `signal`
+    pub fn actions(&self, signal: PrivacySignal) -> PrivacyActions {
+        match signal {
+            PrivacySignal::Coppa => self.coppa,
+            PrivacySignal::Gdpr => self.gdpr,
+            PrivacySignal::UsPrivacyOptOut => self.us_privacy_opt_out,
+            PrivacySignal::LimitAdTracking => self.lmt,
+            PrivacySignal::DoNotTrack => self.dnt,
+        }
+    }
+
+    /// Apply the actions of every signal present on `request`
+    pub fn enforce<Ext>(&self, request: &mut BidRequest<Ext>) -> PrivacyReport {
+        let signals = self.signals(request);
+        let actions = signals
+            .iter()
+            .map(|signal| self.actions(*signal))
+            .fold(PrivacyActions::default(), PrivacyActions::union);
+
+        let mut audit = Vec::new();
+        let mut record = |modified: bool, path, edit| {
+            if modified {
+                audit.push(PrivacyAudit { path, edit });
+            }
+        };
+
+        if let Some(user) = request.user.as_mut() {
+            if actions.remove_user_ids {
+                record(user.id.take().is_some(), "user.id", PrivacyEdit::Removed);
+                record(
+                    user.buyeruid.take().is_some(),
+                    "user.buyeruid",
+                    PrivacyEdit::Removed,
+                );
+                record(user.yob.take().is_some(), "user.yob", PrivacyEdit::Removed);
+                record(
+                    user.gender.take().is_some(),
+                    "user.gender",
+                    PrivacyEdit::Removed,
+                );
+            }
+            if actions.remove_eids {
+                record(
+                    user.eids.take().is_some(),
+                    "user.eids",
+                    PrivacyEdit::Removed,
+                );
+            }
+            if let (Some(decimals), Some(geo)) = (actions.round_geo, user.geo.as_mut()) {
+                record(
+                    round(&mut geo.lat, decimals),
+                    "user.geo.lat",
+                    PrivacyEdit::Rounded,
+                );
+                record(
+                    round(&mut geo.lon, decimals),
+                    "user.geo.lon",
+                    PrivacyEdit::Rounded,
+                );
+            }
+        }
+
+        if let Some(device) = request.device.as_mut() {
+            if actions.remove_ifa {
+                record(
+                    device.ifa.take().is_some(),
+                    "device.ifa",
+                    PrivacyEdit::Removed,
+                );
+            }
+            if actions.remove_hashed_device_ids {
+                for (field, path) in [
+                    (&mut device.didsha1, "device.didsha1"),
+                    (&mut device.didmd5, "device.didmd5"),
+                    (&mut device.dpidsha1, "device.dpidsha1"),
+                    (&mut device.dpidmd5, "device.dpidmd5"),
+                    (&mut device.macsha1, "device.macsha1"),
+                    (&mut device.macmd5, "device.macmd5"),
+                ] {
+                    record(field.take().is_some(), path, PrivacyEdit::Removed);
+                }
+            }
+            if actions.truncate_ip {
+                for (field, path) in [
+                    (&mut device.ip, "device.ip"),
+                    (&mut device.ipv6, "device.ipv6"),
+                ] {
+                    match field.as_deref().map(str::parse::<IpAddr>) {
+                        Some(Ok(ip)) => {
+                            let truncated = truncate_ip(ip).to_string();
+                            record(
+                                field.as_deref() != Some(truncated.as_str()),
+                                path,
+                                PrivacyEdit::Truncated,
+                            );
+                            *field = Some(truncated);
+                        }
+                        Some(Err(_)) => {
+                            *field = None;
+                            record(true, path, PrivacyEdit::Removed);
+                        }
+                        None => {}
+                    }
+                }
+            }
+            if let (Some(decimals), Some(geo)) = (actions.round_geo, device.geo.as_mut()) {
+                record(
+                    round(&mut geo.lat, decimals),
+                    "device.geo.lat",
+                    PrivacyEdit::Rounded,
+                );
+                record(
+                    round(&mut geo.lon, decimals),
+                    "device.geo.lon",
+                    PrivacyEdit::Rounded,
+                );
+            }
+        }
+
+        PrivacyReport {
+            jurisdiction: None,
+            signals,
+            audit,
+        }
+    }
+}
+
+/// Region whose privacy rules a policy implements
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Jurisdiction {
+    /// The European Union together with the rest of the European Economic Area
+    Eu,
+    /// A country, by ISO-3166-1 alpha-3 code (e.g. "BRA")
+    Country(String),
+    /// A region of a country, by ISO-3166-1 alpha-3 country code and ISO-3166-2 region
+    /// code (e.g. "USA" and "CA" for California)
+    Region(String, String),
+}
+
+impl Jurisdiction {
+    /// A US state, by its 2-letter code
+    pub fn us_state(state: &str) -> Self {
+        Jurisdiction::Region("USA".to_string(), state.to_ascii_uppercase())
+    }
+}
+
+/// Privacy policies keyed by jurisdiction
+///
+/// The jurisdiction of a request comes from the `country` and `region` of `device.geo`,
+/// falling back to `user.geo`. A region policy takes precedence over a country policy,
+/// which takes precedence over the EU policy. A request without a country that sets
+/// `regs.gdpr` falls under the EU policy. Other requests get the default policy.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PrivacyPolicies {
+    /// Policy of each configured jurisdiction
+    pub jurisdictions: HashMap<Jurisdiction, PrivacyPolicy>,
+
+    /// Policy for requests outside every configured jurisdiction
+    pub default: PrivacyPolicy,
+}
+
+impl PrivacyPolicies {
+    /// Policies applying `default` everywhere
+    pub fn new(default: PrivacyPolicy) -> Self {
+        PrivacyPolicies {
+            jurisdictions: HashMap::new(),
+            default,
+        }
+    }
+
+    /// Apply `policy` to requests of `jurisdiction`
+    pub fn with(mut self, jurisdiction: Jurisdiction, policy: PrivacyPolicy) -> Self {
+        self.jurisdictions.insert(jurisdiction, policy);
+        self
+    }
+
+    /// The configured jurisdiction of `request` and its policy
+    pub fn resolve<Ext>(
+        &self,
+        request: &BidRequest<Ext>,
+    ) -> (Option<&Jurisdiction>, &PrivacyPolicy) {
+        let geo = [
+            request
+                .device
+                .as_ref()
+                .and_then(|device| device.geo.as_ref()),
+            request.user.as_ref().and_then(|user| user.geo.as_ref()),
+        ]
+        .into_iter()
+        .flatten()
+        .find(|geo| geo.country.is_some());
+        let country = geo
+            .and_then(|geo| geo.country.as_deref())
+            .map(str::to_ascii_uppercase);
+        // ISO-3166-2 codes may carry the country prefix, as in "US-CA"
+        let region = geo.and_then(|geo| geo.region.as_deref()).map(|region| {
+            region
+                .rsplit('-')
+                .next()
+                .unwrap_or(region)
+                .to_ascii_uppercase()
+        });
+
+        let mut candidates = Vec::new();
+        if let Some(country) = &country {
+            if let Some(region) = region {
+                candidates.push(Jurisdiction::Region(country.clone(), region));
+            }
+            candidates.push(Jurisdiction::Country(country.clone()));
+        }
+        let gdpr = request.regs.as_ref().and_then(|regs| regs.gdpr) == Some(GdprFlag::Yes);
+        if country.map_or(gdpr, |country| EEA_COUNTRIES.contains(&country.as_str())) {
+            candidates.push(Jurisdiction::Eu);
+        }
+
+        candidates
+            .iter()
+            .find_map(|candidate| self.jurisdictions.get_key_value(candidate))
+            .map_or((None, &self.default), |(jurisdiction, policy)| {
+                (Some(jurisdiction), policy)
+            })
+    }
+
+    /// Enforce the policy of the jurisdiction of `request`
+    pub fn enforce<Ext>(&self, request: &mut BidRequest<Ext>) -> PrivacyReport {
+        let (jurisdiction, policy) = self.resolve(request);
+        let jurisdiction = jurisdiction.cloned();
+        PrivacyReport {
+            jurisdiction,
+            ..policy.enforce(request)
+        }
+    }
+}
+
+/// Whether a US Privacy string signals an opt-out of sale
+///
+/// The third character of a version 1 string is `Y` when the user opted out.
+fn us_privacy_opt_out(us_privacy: &str) -> bool {
+    us_privacy.starts_with('1') && us_privacy.chars().nth(2) == Some('Y')
+}
+
+/// Zero the host bits of an IPv4 /24 or IPv6 /48 network
+fn truncate_ip(ip: IpAddr) -> IpAddr {
+    match ip {
+        IpAddr::V4(ip) => Ipv4Addr::from(u32::from(ip) & 0xffff_ff00).into(),
+        IpAddr::V6(ip) => Ipv6Addr::from(u128::from(ip) & !((1u128 << 80) - 1)).into(),
+    }
+}
+
+/// Round a coordinate to `decimals` places, returning whether it changed
+fn round(value: &mut Option<f64>, decimals: u32) -> bool {
+    let Some(current) = *value else {
+        return false;
+    };
+    let scale = 10f64.powi(decimals as i32);
+    let rounded = (current * scale).round() / scale;
+    *value = Some(rounded);
+    rounded != current
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_enforce_policy() {
+        let mut request: BidRequest = serde_json::from_value(json!({
+            "id": "req",
+            "imp": [{"id": "1", "banner": {"w": 300, "h": 250}}],
+            "device": {
+                "ip": "203.0.113.77",
+                "ipv6": "2001:db8:85a3:8d3:1319:8a2e:370:7348",
+                "ifa": "6D92078A-8246-4BA4-AE5B-76104861E7DC",
+                "dpidsha1": "a9993e364706816aba3e25717850c26c9cd0d89d",
+                "lmt": 1,
+                "geo": {"lat": 40.712776, "lon": -74.005974}
+            },
+            "user": {"id": "u", "buyeruid": "b", "yob": 2012, "gender": "F", "geo": {"lat": 40.7}},
+            "regs": {"coppa": 1, "gdpr": 0}
+        }))
+        .unwrap();
+
+        let report = PrivacyPolicy::default().enforce(&mut request);
+        assert_eq!(
+            report.signals,
+            [PrivacySignal::Coppa, PrivacySignal::LimitAdTracking]
+        );
+        let paths = report
+            .audit
+            .iter()
+            .map(|audit| audit.path)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            paths,
+            [
+                "user.id",
+                "user.buyeruid",
+                "user.yob",
+                "user.gender",
+                "device.ifa",
+                "device.dpidsha1",
+                "device.ip",
+                "device.ipv6",
+                "device.geo.lat",
+                "device.geo.lon",
+            ]
+        );
+        let device = request.device.as_ref().unwrap();
+        assert_eq!(device.ip.as_deref(), Some("203.0.113.0"));
+        assert_eq!(device.ipv6.as_deref(), Some("2001:db8:85a3::"));
+        let geo = device.geo.as_ref().unwrap();
+        assert_eq!((geo.lat, geo.lon), (Some(40.71), Some(-74.01)));
+        assert_eq!(
+            request.user.as_ref().unwrap().geo.as_ref().unwrap().lat,
+            Some(40.7)
+        );
+
+        // Enforcing again modifies nothing, even with a new opt-out signal
+        let policy = PrivacyPolicy {
+            lmt: PrivacyActions::default(),
+            ..Default::default()
+        };
+        request.regs.as_mut().unwrap().coppa = Some(CoppaFlag::No);
+        request.regs.as_mut().unwrap().us_privacy = Some("1YYN".to_string());
+        let report = policy.enforce(&mut request);
+        assert_eq!(
+            report.signals,
+            [
+                PrivacySignal::UsPrivacyOptOut,
+                PrivacySignal::LimitAdTracking
+            ]
+        );
+        assert!(report.audit.is_empty());
+    }
+
+    #[test]
+    fn test_enforce_per_jurisdiction() {
+        let request: BidRequest = serde_json::from_value(json!({
+            "id": "req",
+            "imp": [{"id": "1"}],
+            "device": {"ifa": "ifa", "geo": {"country": "USA", "region": "CA"}},
+            "user": {"id": "u"},
+            "regs": {"us_privacy": "1YYN"}
+        }))
+        .unwrap();
+        let ignore_opt_out = PrivacyPolicy {
+            us_privacy_opt_out: PrivacyActions::default(),
+            ..Default::default()
+        };
+        let policies = PrivacyPolicies::new(ignore_opt_out)
+            .with(Jurisdiction::us_state("ca"), PrivacyPolicy::default())
+            .with(Jurisdiction::Eu, PrivacyPolicy::default());
+
+        // California enforces the opt-out
+        let mut california = request.clone();
+        let report = policies.enforce(&mut california);
+        assert_eq!(report.jurisdiction, Some(Jurisdiction::us_state("CA")));
+        assert_eq!(report.signals, [PrivacySignal::UsPrivacyOptOut]);
+        assert_eq!(report.audit.len(), 2);
+        assert_eq!(california.user.unwrap().id, None);
+
+        // Texas falls back to the default policy, which ignores it
+        let mut texas = request.clone();
+        texas.device.as_mut().unwrap().geo.as_mut().unwrap().region = Some("US-TX".to_string());
+        let report = policies.enforce(&mut texas);
+        assert_eq!(report.jurisdiction, None);
+        assert!(report.audit.is_empty());
+        assert_eq!(texas.user.unwrap().id.as_deref(), Some("u"));
+
+        // An EEA country resolves to the EU policy
+        let mut germany = request;
+        germany.device.as_mut().unwrap().geo = None;
+        germany.user.as_mut().unwrap().geo = Some(Geo {
+            country: Some("DEU".to_string()),
+            ..Default::default()
+        });
+        let (jurisdiction, _) = policies.resolve(&germany);
+        assert_eq!(jurisdiction, Some(&Jurisdiction::Eu));
+    }
+}
@@ -1,3 +1,4 @@
+use super::enums::*;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
@@ -19,6 +20,10 @@ pub struct Producer<Ext = Value> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cat: Option<Vec<String>>,
 
+    /// The taxonomy in use for the cat attribute.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cattax: Option<ContentTaxonomy>,
+
     /// Highest level domain of the content producer (e.g., "producer.com").
     #[serde(skip_serializing_if = "Option::is_none")]
     pub domain: Option<String>,
@@ -12,6 +12,11 @@ pub struct Regs<Ext = Value> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub gdpr: Option<GdprFlag>,
 
+    /// Communicates signals regarding consumer privacy under US privacy regulation under CCPA and
+    /// LSPA.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub us_privacy: Option<String>,
+
     /// Placeholder for exchange-specific extensions to OpenRTB.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ext: Option<Ext>,
@@ -1,4 +1,5 @@
 use super::enums::*;
+use super::*;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
@@ -16,6 +17,11 @@ pub struct Source<Ext = Value> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub pchain: Option<String>,
 
+    /// This object represents both the links in the supply chain as well as an indicator whether
+    /// or not the supply chain is complete.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schain: Option<SupplyChain>,
+
     /// Placeholder for exchange-specific extensions to OpenRTB.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ext: Option<Ext>,
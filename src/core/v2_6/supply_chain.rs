@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// This object is composed of a set of nodes where each node represents a specific entity that
+/// participates in the transacting of inventory.
+///
+/// The entire chain of nodes from beginning to end represents all entities who are involved in
+/// the direct flow of payment for inventory.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct SupplyChain<Ext = Value> {
+    /// Flag indicating whether the chain contains all nodes involved in the transaction leading
+    /// back to the owner of the site, app or other medium of the inventory, where 0 = no, 1 = yes.
+    pub complete: u32,
+
+    /// Array of `SupplyChainNode` objects in the order of the chain.
+    pub nodes: Vec<SupplyChainNode>,
+
+    /// Version of the supply chain specification in use, in the format of "major.minor".
+    pub ver: String,
+
+    /// Placeholder for exchange-specific extensions to OpenRTB.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ext: Option<Ext>,
+}
+
+/// This object is associated with a `SupplyChain` object as an array of nodes.
+///
+/// These nodes define the identity of an entity participating in the supply chain of a bid
+/// request.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct SupplyChainNode<Ext = Value> {
+    /// The canonical domain name of the SSP, Exchange, Header Wrapper, etc system that bidders
+    /// connect to.
+    pub asi: String,
+
+    /// The identifier associated with the seller or reseller account within the advertising
+    /// system.
+    pub sid: String,
+
+    /// The OpenRTB `BidRequest.id` issued by this seller.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rid: Option<String>,
+
+    /// The name of the company (the legal entity) that is paid for inventory transacted under
+    /// the given `sid`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// The business domain name of the entity represented by this node.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub domain: Option<String>,
+
+    /// Indicates whether this node will be involved in the flow of payment for the inventory,
+    /// where 1 = yes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hp: Option<u32>,
+
+    /// Placeholder for exchange-specific extensions to OpenRTB.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ext: Option<Ext>,
+}
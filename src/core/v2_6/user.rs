@@ -40,6 +40,15 @@ pub struct User<Ext = Value> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub data: Option<Vec<Data>>,
 
+    /// When GDPR regulations are in effect this attribute contains the Transparency and Consent
+    /// Framework's Consent String data structure.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub consent: Option<String>,
+
+    /// Details for support of a standard protocol for multiple third party identity providers.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub eids: Option<Vec<Eid>>,
+
     /// Structured user agent information.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sua: Option<UserAgent>,
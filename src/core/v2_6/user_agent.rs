@@ -10,23 +10,23 @@ pub struct UserAgent<Ext = Value> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub browsers: Option<Vec<Brand>>,
 
-    /// Device model.
+    /// Identifies the user agent's execution platform / OS.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub platform: Option<Brand>,
 
-    /// If the browser is running on a mobile device, this object represents the device.
+    /// 1 if the agent prefers a mobile version of the content, if available.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub mobile: Option<u32>,
 
-    /// 1 if the agent prefers a mobile version of the content, if available.
+    /// Device's major binary architecture.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub architecture: Option<String>,
 
-    /// Device's major binary architecture.
+    /// Device's bitness (e.g., "64" for a 64-bit CPU architecture).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub bitness: Option<String>,
 
-    /// Device's bitness (e.g., "64" for a 64-bit CPU architecture).
+    /// Device model.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub model: Option<String>,
 
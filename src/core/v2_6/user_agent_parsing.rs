@@ -0,0 +1,539 @@
+//! Derivation of structured user agents from User-Agent strings and Client Hints
+//!
+//! The User-Agent string is parsed heuristically: the platform, device model and
+//! architecture come from its parenthesized system information and the browsers from its
+//! product tokens. Client Hints are read as structured header values and take precedence
+//! over the string when both are available. The device fields derived from a
+//! [`UserAgent`] all come from its platform and model, so they agree with each other.
+
+use super::enums::*;
+use super::*;
+
+/// Product tokens of browsers, in order of precedence, with their Client Hints brand
+const BROWSERS: &[(&str, &str)] = &[
+    ("Edg", "Microsoft Edge"),
+    ("EdgA", "Microsoft Edge"),
+    ("EdgiOS", "Microsoft Edge"),
+    ("OPR", "Opera"),
+    ("SamsungBrowser", "Samsung Internet"),
+    ("Firefox", "Firefox"),
+    ("FxiOS", "Firefox"),
+    ("CriOS", "Google Chrome"),
+    ("Chrome", "Google Chrome"),
+];
+
+/// Android model prefixes with the make of the device
+const ANDROID_MAKES: &[(&str, &str)] = &[
+    ("Pixel", "Google"),
+    ("Nexus", "Google"),
+    ("SM-", "Samsung"),
+    ("GT-", "Samsung"),
+    ("moto", "Motorola"),
+    ("Redmi", "Xiaomi"),
+    ("ONEPLUS", "OnePlus"),
+    ("CPH", "OPPO"),
+    ("KF", "Amazon"),
+];
+
+/// User-Agent Client Hints headers of a request
+///
+/// Values are kept as sent, in the structured header syntax (e.g. `"Android"` with its
+/// quotes, or `?1`).
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ClientHints<'a> {
+    /// `Sec-CH-UA`
+    pub ua: Option<&'a str>,
+
+    /// `Sec-CH-UA-Full-Version-List`
+    pub full_version_list: Option<&'a str>,
+
+    /// `Sec-CH-UA-Platform`
+    pub platform: Option<&'a str>,
+
+    /// `Sec-CH-UA-Platform-Version`
+    pub platform_version: Option<&'a str>,
+
+    /// `Sec-CH-UA-Mobile`
+    pub mobile: Option<&'a str>,
+
+    /// `Sec-CH-UA-Arch`
+    pub arch: Option<&'a str>,
+
+    /// `Sec-CH-UA-Bitness`
+    pub bitness: Option<&'a str>,
+
+    /// `Sec-CH-UA-Model`
+    pub model: Option<&'a str>,
+}
+
+impl<'a> ClientHints<'a> {
+    /// Collect the Client Hints from request headers, matching names case-insensitively
+    pub fn from_headers<I>(headers: I) -> Self
+    where
+        I: IntoIterator<Item = (&'a str, &'a str)>,
+    {
+        let mut hints = ClientHints::default();
+        for (name, value) in headers {
+            let field = match name.to_ascii_lowercase().as_str() {
+                "sec-ch-ua" => &mut hints.ua,
+                "sec-ch-ua-full-version-list" => &mut hints.full_version_list,
+                "sec-ch-ua-platform" => &mut hints.platform,
+                "sec-ch-ua-platform-version" => &mut hints.platform_version,
+                "sec-ch-ua-mobile" => &mut hints.mobile,
+                "sec-ch-ua-arch" => &mut hints.arch,
+                "sec-ch-ua-bitness" => &mut hints.bitness,
+                "sec-ch-ua-model" => &mut hints.model,
+                _ => continue,
+            };
+            *field = Some(value);
+        }
+        hints
+    }
+
+    /// Whether no Client Hint is present
+    pub fn is_empty(&self) -> bool {
+        *self == ClientHints::default()
+    }
+}
+
+impl<Ext> UserAgent<Ext> {
+    /// Derive a structured user agent from a User-Agent string
+    pub fn from_ua(ua: &str) -> Self {
+        let info = system_info(ua);
+        let find = |prefix: &str| info.iter().find_map(|item| item.strip_prefix(prefix));
+        let has = |item: &str| info.contains(&item);
+
+        let mut sua = UserAgent {
+            browsers: Some(browsers(&products(ua))).filter(|browsers| !browsers.is_empty()),
+            platform: None,
+            mobile: None,
+            architecture: None,
+            bitness: None,
+            model: None,
+            source: Some(UserAgentSource::UserAgentString),
+            ext: None,
+        };
+        let (os, version) = if ua.contains("Tizen") {
+            ("Tizen", version_after(ua, "Tizen "))
+        } else if ua.contains("Web0S") || ua.contains("webOS") {
+            ("webOS", Vec::new())
+        } else if ua.contains("Roku") {
+            ("Roku", Vec::new())
+        } else if ua.contains("AppleTV") || ua.contains("tvOS") {
+            ("tvOS", version_after(ua, "tvOS "))
+        } else if let Some(model) = info.iter().find(|item| item.starts_with("AFT")) {
+            sua.model = Some(model.to_string());
+            ("Fire OS", Vec::new())
+        } else if let Some(model) = ["iPhone", "iPad", "iPod"]
+            .into_iter()
+            .find(|m| info.iter().any(|item| item.starts_with(m)))
+        {
+            sua.model = Some(model.to_string());
+            sua.mobile = Some(u32::from(model != "iPad"));
+            let version = info
+                .iter()
+                .find_map(|item| item.split_once(" OS ").map(|(_, version)| version))
+                .map(version)
+                .unwrap_or_default();
+            ("iOS", version)
+        } else if let Some(position) = info.iter().position(|item| item.starts_with("Android")) {
+            let is_locale = |item: &str| item.len() == 5 && item.as_bytes()[2] == b'-';
+            sua.model = info[position + 1..]
+                .iter()
+                .map(|item| item.split(" Build/").next().unwrap_or(item))
+                .find(|item| !matches!(*item, "K" | "U" | "wv") && !is_locale(item))
+                .map(str::to_string);
+            sua.mobile = Some(u32::from(ua.contains("Mobile")));
+            ("Android", version_after(info[position], "Android "))
+        } else if let Some(nt) = find("Windows NT ") {
+            if has("Win64") || has("x64") || has("WOW64") {
+                sua.architecture = Some("x86".to_string());
+                sua.bitness = Some("64".to_string());
+            } else if has("ARM64") {
+                sua.architecture = Some("arm".to_string());
+                sua.bitness = Some("64".to_string());
+            }
+            ("Windows", version(nt))
+        } else if let Some(cros) = find("CrOS ") {
+            let mut parts = cros.split_whitespace();
+            let (architecture, bitness) = parts.next().map(architecture).unwrap_or_default();
+            sua.architecture = architecture;
+            sua.bitness = bitness;
+            ("Chrome OS", parts.next().map(version).unwrap_or_default())
+        } else if let Some(mac) = find("Intel Mac OS X ").or_else(|| find("Mac OS X ")) {
+            if ua.contains("Intel Mac") {
+                sua.architecture = Some("x86".to_string());
+            }
+            ("macOS", version(mac))
+        } else if ua.contains("Linux") {
+            let (architecture, bitness) = find("Linux ").map(architecture).unwrap_or_default();
+            sua.architecture = architecture;
+            sua.bitness = bitness;
+            ("Linux", Vec::new())
+        } else {
+            return sua;
+        };
+        sua.platform = Some(brand(os, version));
+        sua.mobile = sua.mobile.or(Some(0));
+        sua
+    }
+
+    /// Derive a structured user agent from User-Agent Client Hints
+    pub fn from_client_hints(hints: &ClientHints) -> Self {
+        let browsers = hints
+            .full_version_list
+            .or(hints.ua)
+            .map(brand_list)
+            .filter(|browsers| !browsers.is_empty());
+        let platform = hints.platform.and_then(sf_string).map(|name| {
+            let platform_version = hints.platform_version.and_then(sf_string);
+            brand(
+                &name,
+                platform_version.as_deref().map(version).unwrap_or_default(),
+            )
+        });
+        UserAgent {
+            browsers,
+            platform,
+            mobile: hints.mobile.and_then(sf_boolean).map(u32::from),
+            architecture: hints.arch.and_then(sf_string),
+            bitness: hints.bitness.and_then(sf_string),
+            model: hints.model.and_then(sf_string),
+            source: Some(UserAgentSource::UserAgentClientHints),
+            ext: None,
+        }
+    }
+
+    /// Derive a structured user agent from a User-Agent string enhanced with Client Hints
+    ///
+    /// Each field present in the Client Hints replaces the one parsed from the string.
+    pub fn from_ua_and_client_hints(ua: &str, hints: &ClientHints) -> Self {
+        let parsed = Self::from_ua(ua);
+        let hinted = Self::from_client_hints(hints);
+        UserAgent {
+            browsers: hinted.browsers.or(parsed.browsers),
+            platform: hinted.platform.or(parsed.platform),
+            mobile: hinted.mobile.or(parsed.mobile),
+            architecture: hinted.architecture.or(parsed.architecture),
+            bitness: hinted.bitness.or(parsed.bitness),
+            model: hinted.model.or(parsed.model),
+            source: Some(UserAgentSource::Enhanced),
+            ext: None,
+        }
+    }
+
+    /// Operating system of the platform
+    pub fn os(&self) -> Option<&str> {
+        self.platform.as_ref()?.brand.as_deref()
+    }
+
+    /// Version of the platform, with its components joined by dots
+    pub fn os_version(&self) -> Option<String> {
+        let version = self.platform.as_ref()?.version.as_ref()?;
+        (!version.is_empty()).then(|| version.join("."))
+    }
+
+    /// Make of the device, implied by its platform or model
+    pub fn make(&self) -> Option<&'static str> {
+        match self.os()? {
+            "iOS" | "iPadOS" | "macOS" | "tvOS" => Some("Apple"),
+            "Tizen" => Some("Samsung"),
+            "webOS" => Some("LG"),
+            "Roku" => Some("Roku"),
+            "Fire OS" => Some("Amazon"),
+            "Android" => {
+                let model = self.model.as_deref()?;
+                ANDROID_MAKES
+                    .iter()
+                    .find(|(prefix, _)| model.starts_with(prefix))
+                    .map(|(_, make)| *make)
+            }
+            _ => None,
+        }
+    }
+
+    /// General type of the device, implied by its platform
+    pub fn device_type(&self) -> Option<DeviceType> {
+        let mobile = self.mobile.map(|mobile| mobile == 1);
+        match self.os()? {
+            "iOS" | "iPadOS" if self.model.as_deref() == Some("iPad") => Some(DeviceType::Tablet),
+            "iOS" | "iPadOS" => Some(DeviceType::Phone),
+            "Android" => match mobile {
+                Some(true) => Some(DeviceType::Phone),
+                Some(false) => Some(DeviceType::Tablet),
+                None => Some(DeviceType::MobileTablet),
+            },
+            "Tizen" | "webOS" => Some(DeviceType::ConnectedTv),
+            "Roku" | "tvOS" | "Fire OS" => Some(DeviceType::SetTopBox),
+            "Windows" | "macOS" | "Linux" | "Chrome OS" | "Chromium OS" => {
+                Some(DeviceType::PersonalComputer)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl<Ext> Device<Ext> {
+    /// Derive `sua` from `ua` and the Client Hints of the request, and fill the device
+    /// fields it implies
+    ///
+    /// An existing `sua` is kept. `os`, `osv`, `make`, `model` and `devicetype` are only
+    /// set when absent, and `osv` and `make` only when they would describe the `os` and
+    /// `model` already on the device.
+    pub fn derive_user_agent(&mut self, hints: Option<&ClientHints>) {
+        if self.sua.is_none() {
+            let hints = hints.filter(|hints| !hints.is_empty());
+            self.sua = match (self.ua.as_deref(), hints) {
+                (Some(ua), Some(hints)) => Some(UserAgent::from_ua_and_client_hints(ua, hints)),
+                (Some(ua), None) => Some(UserAgent::from_ua(ua)),
+                (None, Some(hints)) => Some(UserAgent::from_client_hints(hints)),
+                (None, None) => None,
+            };
+        }
+        let Some(sua) = &self.sua else {
+            return;
+        };
+
+        if let Some(os) = sua.os()
+            && self
+                .os
+                .as_deref()
+                .is_none_or(|current| current.eq_ignore_ascii_case(os))
+        {
+            self.os.get_or_insert_with(|| os.to_string());
+            if self.osv.is_none() {
+                self.osv = sua.os_version();
+            }
+        }
+        if self.model.is_none() || self.model == sua.model {
+            self.model = self.model.take().or_else(|| sua.model.clone());
+            if self.make.is_none() {
+                self.make = sua.make().map(str::to_string);
+            }
+        }
+        if self.devicetype.is_none() {
+            self.devicetype = sua.device_type();
+        }
+    }
+}
+
+fn brand(name: &str, version: Vec<String>) -> Brand {
+    Brand {
+        brand: Some(name.to_string()),
+        version: (!version.is_empty()).then_some(version),
+        ext: None,
+    }
+}
+
+/// Leading numeric components of a version such as `10.0` or `17_1_2`
+fn version(value: &str) -> Vec<String> {
+    value
+        .split(['.', '_'])
+        .map(|part| part.trim_end_matches(|c: char| !c.is_ascii_digit()))
+        .take_while(|part| !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit()))
+        .map(str::to_string)
+        .collect()
+}
+
+fn version_after(value: &str, prefix: &str) -> Vec<String> {
+    value
+        .split_once(prefix)
+        .map(|(_, rest)| version(rest.split([' ', ';', ')']).next().unwrap_or_default()))
+        .unwrap_or_default()
+}
+
+/// Architecture and bitness of a CPU name such as `x86_64` or `aarch64`
+fn architecture(cpu: &str) -> (Option<String>, Option<String>) {
+    let (architecture, bitness) = match cpu {
+        "x86_64" | "amd64" => ("x86", "64"),
+        "i686" | "i386" => ("x86", "32"),
+        "aarch64" | "arm64" => ("arm", "64"),
+        cpu if cpu.starts_with("armv") => ("arm", "32"),
+        _ => return (None, None),
+    };
+    (Some(architecture.to_string()), Some(bitness.to_string()))
+}
+
+/// Items of the first parenthesized section of a User-Agent string
+fn system_info(ua: &str) -> Vec<&str> {
+    ua.split_once('(')
+        .and_then(|(_, rest)| rest.split_once(')'))
+        .map(|(info, _)| {
+            info.split(';')
+                .map(str::trim)
+                .filter(|item| !item.is_empty())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// `name/version` product tokens of a User-Agent string, outside of comments
+fn products(ua: &str) -> Vec<(&str, &str)> {
+    let mut depth = 0usize;
+    let mut products = Vec::new();
+    for token in ua.split_whitespace() {
+        let opens = token.matches('(').count();
+        if depth == 0
+            && opens == 0
+            && let Some(product) = token.split_once('/')
+        {
+            products.push(product);
+        }
+        depth = (depth + opens).saturating_sub(token.matches(')').count());
+    }
+    products
+}
+
+fn browsers(products: &[(&str, &str)]) -> Vec<Brand> {
+    let find = |name: &str| {
+        products
+            .iter()
+            .find(|(product, _)| *product == name)
+            .map(|(_, value)| version(value))
+    };
+    let mut browsers = Vec::new();
+    if let Some((name, version)) = BROWSERS
+        .iter()
+        .find_map(|(token, name)| find(token).map(|version| (name, version)))
+    {
+        browsers.push(brand(name, version));
+    } else if let (Some(version), Some(_)) = (find("Version"), find("Safari")) {
+        browsers.push(brand("Safari", version));
+    }
+    if let Some(version) = find("Chrome") {
+        browsers.push(brand("Chromium", version));
+    }
+    browsers
+}
+
+/// Leading string of a structured header value and the rest of the value
+fn quoted(value: &str) -> Option<(String, &str)> {
+    let mut chars = value.strip_prefix('"')?.char_indices();
+    let mut string = String::new();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\\' => string.push(chars.next()?.1),
+            '"' => return Some((string, &value[i + 2..])),
+            c => string.push(c),
+        }
+    }
+    None
+}
+
+/// Non-empty structured header string, such as `"Android"`
+fn sf_string(value: &str) -> Option<String> {
+    quoted(value.trim())
+        .map(|(string, _)| string)
+        .filter(|string| !string.is_empty())
+}
+
+/// Structured header boolean, `?1` or `?0`
+fn sf_boolean(value: &str) -> Option<bool> {
+    match value.trim() {
+        "?1" => Some(true),
+        "?0" => Some(false),
+        _ => None,
+    }
+}
+
+/// Brands of a `Sec-CH-UA` list, without GREASE entries such as `"Not-A.Brand"`
+fn brand_list(value: &str) -> Vec<Brand> {
+    let mut brands = Vec::new();
+    let mut rest = value.trim_start();
+    while let Some((name, after)) = quoted(rest) {
+        rest = after;
+        let mut brand_version = None;
+        while let Some(param) = rest.trim_start().strip_prefix(';') {
+            let param = param.trim_start();
+            let (key, value) = param.split_at(
+                param
+                    .find(|c: char| !(c.is_ascii_alphanumeric() || "_-.*".contains(c)))
+                    .unwrap_or(param.len()),
+            );
+            rest = value;
+            if let Some(value) = value.strip_prefix('=') {
+                let (value, after) = quoted(value).unwrap_or_else(|| {
+                    let end = value.find([';', ',']).unwrap_or(value.len());
+                    (value[..end].to_string(), &value[end..])
+                });
+                rest = after;
+                if key == "v" {
+                    brand_version = Some(value);
+                }
+            }
+        }
+        if !(name.contains("Not") && name.contains("Brand")) {
+            brands.push(brand(
+                &name,
+                brand_version.as_deref().map(version).unwrap_or_default(),
+            ));
+        }
+        match rest.trim_start().strip_prefix(',') {
+            Some(next) => rest = next.trim_start(),
+            None => break,
+        }
+    }
+    brands
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_derive_user_agent() {
+        let ua = "Mozilla/5.0 (Linux; Android 13; Pixel 7 Build/TQ3A.230901.001) \
+                  AppleWebKit/537.36 (KHTML, like Gecko) Chrome/124.0.6367.82 Mobile Safari/537.36";
+        let sua = UserAgent::<()>::from_ua(ua);
+        assert_eq!(sua.os(), Some("Android"));
+        assert_eq!(sua.os_version().as_deref(), Some("13"));
+        assert_eq!(sua.model.as_deref(), Some("Pixel 7"));
+        assert_eq!(sua.make(), Some("Google"));
+        assert_eq!(sua.device_type(), Some(DeviceType::Phone));
+        let browsers = sua.browsers.unwrap();
+        assert_eq!(browsers[0].brand.as_deref(), Some("Google Chrome"));
+        assert_eq!(browsers[1].brand.as_deref(), Some("Chromium"));
+        assert_eq!(browsers[0].version.as_ref().unwrap().len(), 4);
+
+        let iphone = UserAgent::<()>::from_ua(
+            "Mozilla/5.0 (iPhone; CPU iPhone OS 17_1_2 like Mac OS X) AppleWebKit/605.1.15 \
+             (KHTML, like Gecko) Version/17.1 Mobile/15E148 Safari/604.1",
+        );
+        assert_eq!(iphone.os_version().as_deref(), Some("17.1.2"));
+        assert_eq!(iphone.make(), Some("Apple"));
+        assert_eq!(
+            iphone.browsers.unwrap()[0].version,
+            Some(vec!["17".to_string(), "1".to_string()])
+        );
+
+        let hints = ClientHints::from_headers([
+            (
+                "Sec-CH-UA",
+                r#""Chromium";v="124", "Google Chrome";v="124", "Not-A.Brand";v="99""#,
+            ),
+            ("sec-ch-ua-platform", r#""Windows""#),
+            ("Sec-CH-UA-Platform-Version", r#""15.0.0""#),
+            ("Sec-CH-UA-Mobile", "?0"),
+            ("Sec-CH-UA-Model", r#""""#),
+            ("Accept", "*/*"),
+        ]);
+        let mut device: Device = Device {
+            ua: Some(
+                "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 \
+                 (KHTML, like Gecko) Chrome/124.0.0.0 Safari/537.36"
+                    .to_string(),
+            ),
+            ..Default::default()
+        };
+        device.derive_user_agent(Some(&hints));
+        let sua = device.sua.as_ref().unwrap();
+        assert_eq!(sua.source, Some(UserAgentSource::Enhanced));
+        assert_eq!(sua.browsers.as_ref().unwrap().len(), 2);
+        assert_eq!(sua.bitness.as_deref(), Some("64"));
+        assert_eq!(sua.model, None);
+        assert_eq!(device.os.as_deref(), Some("Windows"));
+        assert_eq!(device.osv.as_deref(), Some("15.0.0"));
+        assert_eq!(device.devicetype, Some(DeviceType::PersonalComputer));
+        assert_eq!(device.make, None);
+    }
+}
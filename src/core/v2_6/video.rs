@@ -41,6 +41,15 @@ pub struct Video<Ext = Value> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub startdelay: Option<u32>,
 
+    /// Video placement type for the impression.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub plcmt: Option<VideoPlcmtSubtype>,
+
+    /// NOTE: Deprecated in favor of plcmt.
+    /// Placement type for the impression.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placement: Option<VideoPlacementType>,
+
     /// Indicates if the impression must be linear, nonlinear, etc.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub linearity: Option<VideoLinearity>,
@@ -119,6 +128,31 @@ pub struct Video<Ext = Value> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub maxseq: Option<u32>,
 
+    /// Unique identifier indicating that an impression opportunity belongs to a video ad pod.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub podid: Option<String>,
+
+    /// The sequence (position) of the video ad pod within a content stream.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub podseq: Option<PodSequence>,
+
+    /// Indicates the total amount of time in seconds that advertisers may fill for a dynamic video ad pod.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub poddur: Option<u32>,
+
+    /// Precise acceptable durations for video creatives in seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rqddurs: Option<Vec<u32>>,
+
+    /// For video ad pods, this value indicates that the seller can guarantee delivery against
+    /// the indicated slot position in the pod.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slotinpod: Option<SlotPositionInPod>,
+
+    /// Minimum CPM per second, used for dynamic pods.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mincpmpersec: Option<f64>,
+
     /// Type of video feed.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub feed: Option<FeedType>,
@@ -136,14 +170,14 @@ pub struct Video<Ext = Value> {
     pub ext: Option<Ext>,
 }
 
-fn default_skip_min() -> Option<u32> {
+pub(super) fn default_skip_min() -> Option<u32> {
     Some(0)
 }
 
-fn default_skip_after() -> Option<u32> {
+pub(super) fn default_skip_after() -> Option<u32> {
     Some(0)
 }
 
-fn default_boxingallowed() -> Option<BoxingAllowedFlag> {
+pub(super) fn default_boxingallowed() -> Option<BoxingAllowedFlag> {
     Some(BoxingAllowedFlag::Allowed)
 }
@@ -0,0 +1,484 @@
+use super::*;
+use serde_json::Value;
+
+/// Generate a visitor trait and its `walk_*` functions for one kind of reference
+macro_rules! visitor {
+    ($visitor:ident, $($lt:lifetime)?; $($m:tt)?) => {
+        use super::*;
+        use serde_json::Value;
+
+        /// Hooks called for every object of a bid request or bid response
+        ///
+        /// Every hook defaults to descending into the object's children through the
+        /// matching `walk_*` function. The `ext` of every object is passed to `visit_ext`
+        /// together with the name of the owning object.
+        #[allow(unused_variables)]
+        pub trait $visitor $(<$lt>)? {
+            fn visit_bid_request(&mut self, node: &$($lt)? $($m)? BidRequest) {
+                walk_bid_request(self, node)
+            }
+            fn visit_imp(&mut self, node: &$($lt)? $($m)? Impression) {
+                walk_imp(self, node)
+            }
+            fn visit_banner(&mut self, node: &$($lt)? $($m)? Banner) {
+                walk_banner(self, node)
+            }
+            fn visit_format(&mut self, node: &$($lt)? $($m)? Format) {
+                self.visit_ext(&$($m)? node.ext, "Format")
+            }
+            fn visit_video(&mut self, node: &$($lt)? $($m)? Video) {
+                walk_video(self, node)
+            }
+            fn visit_audio(&mut self, node: &$($lt)? $($m)? Audio) {
+                walk_audio(self, node)
+            }
+            fn visit_native(&mut self, node: &$($lt)? $($m)? Native) {
+                self.visit_ext(&$($m)? node.ext, "Native")
+            }
+            fn visit_pmp(&mut self, node: &$($lt)? $($m)? Pmp) {
+                walk_pmp(self, node)
+            }
+            fn visit_deal(&mut self, node: &$($lt)? $($m)? Deal) {
+                self.visit_ext(&$($m)? node.ext, "Deal")
+            }
+            fn visit_metric(&mut self, node: &$($lt)? $($m)? Metric) {
+                self.visit_ext(&$($m)? node.ext, "Metric")
+            }
+            fn visit_site(&mut self, node: &$($lt)? $($m)? Site) {
+                walk_site(self, node)
+            }
+            fn visit_app(&mut self, node: &$($lt)? $($m)? App) {
+                walk_app(self, node)
+            }
+            fn visit_dooh(&mut self, node: &$($lt)? $($m)? Dooh) {
+                walk_dooh(self, node)
+            }
+            fn visit_publisher(&mut self, node: &$($lt)? $($m)? Publisher) {
+                self.visit_ext(&$($m)? node.ext, "Publisher")
+            }
+            fn visit_content(&mut self, node: &$($lt)? $($m)? Content) {
+                walk_content(self, node)
+            }
+            fn visit_producer(&mut self, node: &$($lt)? $($m)? Producer) {
+                self.visit_ext(&$($m)? node.ext, "Producer")
+            }
+            fn visit_network(&mut self, node: &$($lt)? $($m)? Network) {
+                self.visit_ext(&$($m)? node.ext, "Network")
+            }
+            fn visit_channel(&mut self, node: &$($lt)? $($m)? Channel) {
+                self.visit_ext(&$($m)? node.ext, "Channel")
+            }
+            fn visit_device(&mut self, node: &$($lt)? $($m)? Device) {
+                walk_device(self, node)
+            }
+            fn visit_geo(&mut self, node: &$($lt)? $($m)? Geo) {
+                self.visit_ext(&$($m)? node.ext, "Geo")
+            }
+            fn visit_user_agent(&mut self, node: &$($lt)? $($m)? UserAgent) {
+                walk_user_agent(self, node)
+            }
+            fn visit_brand(&mut self, node: &$($lt)? $($m)? Brand) {
+                self.visit_ext(&$($m)? node.ext, "BrandVersion")
+            }
+            fn visit_user(&mut self, node: &$($lt)? $($m)? User) {
+                walk_user(self, node)
+            }
+            fn visit_data(&mut self, node: &$($lt)? $($m)? Data) {
+                walk_data(self, node)
+            }
+            fn visit_segment(&mut self, node: &$($lt)? $($m)? Segment) {
+                self.visit_ext(&$($m)? node.ext, "Segment")
+            }
+            fn visit_eid(&mut self, node: &$($lt)? $($m)? Eid) {
+                walk_eid(self, node)
+            }
+            fn visit_uid(&mut self, node: &$($lt)? $($m)? Uid) {
+                self.visit_ext(&$($m)? node.ext, "UID")
+            }
+            fn visit_source(&mut self, node: &$($lt)? $($m)? Source) {
+                walk_source(self, node)
+            }
+            fn visit_supply_chain(&mut self, node: &$($lt)? $($m)? SupplyChain) {
+                walk_supply_chain(self, node)
+            }
+            fn visit_supply_chain_node(&mut self, node: &$($lt)? $($m)? SupplyChainNode) {
+                self.visit_ext(&$($m)? node.ext, "SupplyChainNode")
+            }
+            fn visit_regs(&mut self, node: &$($lt)? $($m)? Regs) {
+                self.visit_ext(&$($m)? node.ext, "Regs")
+            }
+            fn visit_bid_response(&mut self, node: &$($lt)? $($m)? BidResponse) {
+                walk_bid_response(self, node)
+            }
+            fn visit_seat_bid(&mut self, node: &$($lt)? $($m)? SeatBid) {
+                walk_seat_bid(self, node)
+            }
+            fn visit_bid(&mut self, node: &$($lt)? $($m)? Bid) {
+                self.visit_ext(&$($m)? node.ext, "Bid")
+            }
+            fn visit_ext(&mut self, ext: &$($lt)? $($m)? Option<Value>, object: &'static str) {}
+        }
+
+        pub fn walk_bid_request<$($lt,)? V: $visitor $(<$lt>)? + ?Sized>(
+            v: &mut V,
+            node: &$($lt)? $($m)? BidRequest,
+        ) {
+            for imp in &$($m)? node.imp {
+                v.visit_imp(imp);
+            }
+            if let Some(site) = &$($m)? node.site {
+                v.visit_site(site);
+            }
+            if let Some(app) = &$($m)? node.app {
+                v.visit_app(app);
+            }
+            if let Some(dooh) = &$($m)? node.dooh {
+                v.visit_dooh(dooh);
+            }
+            if let Some(device) = &$($m)? node.device {
+                v.visit_device(device);
+            }
+            if let Some(user) = &$($m)? node.user {
+                v.visit_user(user);
+            }
+            if let Some(source) = &$($m)? node.source {
+                v.visit_source(source);
+            }
+            if let Some(regs) = &$($m)? node.regs {
+                v.visit_regs(regs);
+            }
+            v.visit_ext(&$($m)? node.ext, "BidRequest");
+        }
+
+        pub fn walk_imp<$($lt,)? V: $visitor $(<$lt>)? + ?Sized>(
+            v: &mut V,
+            node: &$($lt)? $($m)? Impression,
+        ) {
+            if let Some(banner) = &$($m)? node.banner {
+                v.visit_banner(banner);
+            }
+            if let Some(video) = &$($m)? node.video {
+                v.visit_video(video);
+            }
+            if let Some(audio) = &$($m)? node.audio {
+                v.visit_audio(audio);
+            }
+            if let Some(native) = &$($m)? node.native {
+                v.visit_native(native);
+            }
+            if let Some(pmp) = &$($m)? node.pmp {
+                v.visit_pmp(pmp);
+            }
+            for metric in (&$($m)? node.metric).into_iter().flatten() {
+                v.visit_metric(metric);
+            }
+            v.visit_ext(&$($m)? node.ext, "Imp");
+        }
+
+        pub fn walk_banner<$($lt,)? V: $visitor $(<$lt>)? + ?Sized>(
+            v: &mut V,
+            node: &$($lt)? $($m)? Banner,
+        ) {
+            for format in (&$($m)? node.format).into_iter().flatten() {
+                v.visit_format(format);
+            }
+            v.visit_ext(&$($m)? node.ext, "Banner");
+        }
+
+        pub fn walk_video<$($lt,)? V: $visitor $(<$lt>)? + ?Sized>(
+            v: &mut V,
+            node: &$($lt)? $($m)? Video,
+        ) {
+            for banner in (&$($m)? node.companionad).into_iter().flatten() {
+                v.visit_banner(banner);
+            }
+            v.visit_ext(&$($m)? node.ext, "Video");
+        }
+
+        pub fn walk_audio<$($lt,)? V: $visitor $(<$lt>)? + ?Sized>(
+            v: &mut V,
+            node: &$($lt)? $($m)? Audio,
+        ) {
+            for banner in (&$($m)? node.companionad).into_iter().flatten() {
+                v.visit_banner(banner);
+            }
+            v.visit_ext(&$($m)? node.ext, "Audio");
+        }
+
+        pub fn walk_pmp<$($lt,)? V: $visitor $(<$lt>)? + ?Sized>(
+            v: &mut V,
+            node: &$($lt)? $($m)? Pmp,
+        ) {
+            for deal in (&$($m)? node.deals).into_iter().flatten() {
+                v.visit_deal(deal);
+            }
+            v.visit_ext(&$($m)? node.ext, "Pmp");
+        }
+
+        pub fn walk_site<$($lt,)? V: $visitor $(<$lt>)? + ?Sized>(
+            v: &mut V,
+            node: &$($lt)? $($m)? Site,
+        ) {
+            if let Some(publisher) = &$($m)? node.publisher {
+                v.visit_publisher(publisher);
+            }
+            if let Some(content) = &$($m)? node.content {
+                v.visit_content(content);
+            }
+            v.visit_ext(&$($m)? node.ext, "Site");
+        }
+
+        pub fn walk_app<$($lt,)? V: $visitor $(<$lt>)? + ?Sized>(
+            v: &mut V,
+            node: &$($lt)? $($m)? App,
+        ) {
+            if let Some(publisher) = &$($m)? node.publisher {
+                v.visit_publisher(publisher);
+            }
+            if let Some(content) = &$($m)? node.content {
+                v.visit_content(content);
+            }
+            v.visit_ext(&$($m)? node.ext, "App");
+        }
+
+        pub fn walk_dooh<$($lt,)? V: $visitor $(<$lt>)? + ?Sized>(
+            v: &mut V,
+            node: &$($lt)? $($m)? Dooh,
+        ) {
+            if let Some(publisher) = &$($m)? node.publisher {
+                v.visit_publisher(publisher);
+            }
+            if let Some(content) = &$($m)? node.content {
+                v.visit_content(content);
+            }
+            v.visit_ext(&$($m)? node.ext, "Dooh");
+        }
+
+        pub fn walk_content<$($lt,)? V: $visitor $(<$lt>)? + ?Sized>(
+            v: &mut V,
+            node: &$($lt)? $($m)? Content,
+        ) {
+            if let Some(producer) = &$($m)? node.producer {
+                v.visit_producer(producer);
+            }
+            for data in (&$($m)? node.data).into_iter().flatten() {
+                v.visit_data(data);
+            }
+            if let Some(network) = &$($m)? node.network {
+                v.visit_network(network);
+            }
+            if let Some(channel) = &$($m)? node.channel {
+                v.visit_channel(channel);
+            }
+            v.visit_ext(&$($m)? node.ext, "Content");
+        }
+
+        pub fn walk_device<$($lt,)? V: $visitor $(<$lt>)? + ?Sized>(
+            v: &mut V,
+            node: &$($lt)? $($m)? Device,
+        ) {
+            if let Some(geo) = &$($m)? node.geo {
+                v.visit_geo(geo);
+            }
+            if let Some(sua) = &$($m)? node.sua {
+                v.visit_user_agent(sua);
+            }
+            v.visit_ext(&$($m)? node.ext, "Device");
+        }
+
+        pub fn walk_user_agent<$($lt,)? V: $visitor $(<$lt>)? + ?Sized>(
+            v: &mut V,
+            node: &$($lt)? $($m)? UserAgent,
+        ) {
+            for brand in (&$($m)? node.browsers).into_iter().flatten() {
+                v.visit_brand(brand);
+            }
+            if let Some(platform) = &$($m)? node.platform {
+                v.visit_brand(platform);
+            }
+            v.visit_ext(&$($m)? node.ext, "UserAgent");
+        }
+
+        pub fn walk_user<$($lt,)? V: $visitor $(<$lt>)? + ?Sized>(
+            v: &mut V,
+            node: &$($lt)? $($m)? User,
+        ) {
+            if let Some(geo) = &$($m)? node.geo {
+                v.visit_geo(geo);
+            }
+            for data in (&$($m)? node.data).into_iter().flatten() {
+                v.visit_data(data);
+            }
+            for eid in (&$($m)? node.eids).into_iter().flatten() {
+                v.visit_eid(eid);
+            }
+            if let Some(sua) = &$($m)? node.sua {
+                v.visit_user_agent(sua);
+            }
+            v.visit_ext(&$($m)? node.ext, "User");
+        }
+
+        pub fn walk_eid<$($lt,)? V: $visitor $(<$lt>)? + ?Sized>(
+            v: &mut V,
+            node: &$($lt)? $($m)? Eid,
+        ) {
+            for uid in &$($m)? node.uids {
+                v.visit_uid(uid);
+            }
+            v.visit_ext(&$($m)? node.ext, "EID");
+        }
+
+        pub fn walk_data<$($lt,)? V: $visitor $(<$lt>)? + ?Sized>(
+            v: &mut V,
+            node: &$($lt)? $($m)? Data,
+        ) {
+            for segment in (&$($m)? node.segment).into_iter().flatten() {
+                v.visit_segment(segment);
+            }
+            v.visit_ext(&$($m)? node.ext, "Data");
+        }
+
+        pub fn walk_source<$($lt,)? V: $visitor $(<$lt>)? + ?Sized>(
+            v: &mut V,
+            node: &$($lt)? $($m)? Source,
+        ) {
+            if let Some(schain) = &$($m)? node.schain {
+                v.visit_supply_chain(schain);
+            }
+            v.visit_ext(&$($m)? node.ext, "Source");
+        }
+
+        pub fn walk_supply_chain<$($lt,)? V: $visitor $(<$lt>)? + ?Sized>(
+            v: &mut V,
+            node: &$($lt)? $($m)? SupplyChain,
+        ) {
+            for supply_chain_node in &$($m)? node.nodes {
+                v.visit_supply_chain_node(supply_chain_node);
+            }
+            v.visit_ext(&$($m)? node.ext, "SupplyChain");
+        }
+
+        pub fn walk_bid_response<$($lt,)? V: $visitor $(<$lt>)? + ?Sized>(
+            v: &mut V,
+            node: &$($lt)? $($m)? BidResponse,
+        ) {
+            for seat_bid in (&$($m)? node.seatbid).into_iter().flatten() {
+                v.visit_seat_bid(seat_bid);
+            }
+            v.visit_ext(&$($m)? node.ext, "BidResponse");
+        }
+
+        pub fn walk_seat_bid<$($lt,)? V: $visitor $(<$lt>)? + ?Sized>(
+            v: &mut V,
+            node: &$($lt)? $($m)? SeatBid,
+        ) {
+            for bid in &$($m)? node.bid {
+                v.visit_bid(bid);
+            }
+            v.visit_ext(&$($m)? node.ext, "SeatBid");
+        }
+    };
+}
+
+/// Traversal by shared reference
+pub mod visit {
+    visitor!(Visitor, 'ast;);
+}
+
+/// Traversal by mutable reference
+pub mod visit_mut {
+    visitor!(VisitorMut, ; mut);
+}
+
+pub use visit::Visitor;
+pub use visit_mut::VisitorMut;
+
+struct ExtCollector<'a> {
+    exts: Vec<(&'static str, &'a Value)>,
+}
+
+impl<'a> Visitor<'a> for ExtCollector<'a> {
+    fn visit_ext(&mut self, ext: &'a Option<Value>, object: &'static str) {
+        if let Some(ext) = ext {
+            self.exts.push((object, ext));
+        }
+    }
+}
+
+impl BidRequest {
+    /// Every `ext` of the request with the name of the object that holds it
+    pub fn exts(&self) -> Vec<(&'static str, &Value)> {
+        let mut collector = ExtCollector { exts: Vec::new() };
+        collector.visit_bid_request(self);
+        collector.exts
+    }
+}
+
+impl BidResponse {
+    /// Every `ext` of the response with the name of the object that holds it
+    pub fn exts(&self) -> Vec<(&'static str, &Value)> {
+        let mut collector = ExtCollector { exts: Vec::new() };
+        collector.visit_bid_response(self);
+        collector.exts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    struct ScrubIds;
+
+    impl VisitorMut for ScrubIds {
+        fn visit_device(&mut self, node: &mut Device) {
+            node.ip = None;
+            node.ifa = None;
+            visit_mut::walk_device(self, node);
+        }
+
+        fn visit_user(&mut self, node: &mut User) {
+            node.id = None;
+            node.buyeruid = None;
+            visit_mut::walk_user(self, node);
+        }
+
+        fn visit_geo(&mut self, node: &mut Geo) {
+            node.lat = None;
+            node.lon = None;
+        }
+    }
+
+    #[test]
+    fn test_scrub_and_collect_exts() {
+        let mut request: BidRequest = serde_json::from_value(json!({
+            "id": "req",
+            "imp": [{
+                "id": "1",
+                "banner": {"format": [{"w": 300, "h": 250, "ext": {"f": 1}}]},
+                "ext": {"i": 1}
+            }],
+            "device": {"ip": "192.0.2.1", "ifa": "abc", "geo": {"lat": 1.5, "lon": 2.5}},
+            "user": {"id": "u", "buyeruid": "b", "geo": {"lat": 1.5}},
+            "ext": {"r": 1}
+        }))
+        .unwrap();
+
+        ScrubIds.visit_bid_request(&mut request);
+        let device = request.device.as_ref().unwrap();
+        assert_eq!(device.ip, None);
+        assert_eq!(device.geo.as_ref().unwrap().lat, None);
+        assert_eq!(request.user.as_ref().unwrap().id, None);
+        assert_eq!(
+            request.user.as_ref().unwrap().geo.as_ref().unwrap().lat,
+            None
+        );
+
+        let exts: Vec<_> = request
+            .exts()
+            .into_iter()
+            .map(|(object, _)| object)
+            .collect();
+        assert_eq!(exts, vec!["Format", "Imp", "BidRequest"]);
+    }
+}
@@ -0,0 +1,65 @@
+use super::*;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// A `Seatbid` object contains one or more `Bid` objects, each of which relates to a specific item
+/// in the bid request offer via the `item` attribute.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Bid<Ext = Value> {
+    /// Bidder generated bid ID to assist with logging/tracking.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
+    /// ID of the item object in the related bid request.
+    pub item: String,
+
+    /// Bid price expressed as CPM although the actual transaction is for a unit item only.
+    pub price: f64,
+
+    /// Reference to a deal from the bid request if this bid pertains to a private marketplace deal.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deal: Option<String>,
+
+    /// Campaign ID or other similar grouping of brand-related ads.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cid: Option<String>,
+
+    /// Tactic ID to enable buyers to label bids for reporting to the exchange the tactic through which
+    /// their bid was submitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tactic: Option<String>,
+
+    /// Pending notice URL called by the exchange when a bid has been declared the winner within the
+    /// scope of an OpenRTB compliant supply chain.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub purl: Option<String>,
+
+    /// Billing notice URL called by the exchange when a winning bid becomes billable.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub burl: Option<String>,
+
+    /// Loss notice URL called by the exchange when a bid is known to have been lost.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lurl: Option<String>,
+
+    /// Advisory as to the number of seconds the buyer is willing to wait between auction and
+    /// fulfilment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exp: Option<u32>,
+
+    /// ID to enable media to be specified by reference if previously uploaded to the exchange.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mid: Option<String>,
+
+    /// Array of `Macro` objects that enable bid specific values to be substituted into markup.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub r#macro: Option<Vec<Macro>>,
+
+    /// Layer-4 domain object structure that specifies the media to be presented if the bid is won.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub media: Option<Media>,
+
+    /// Placeholder for exchange-specific extensions to OpenRTB.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ext: Option<Ext>,
+}
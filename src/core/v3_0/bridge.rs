@@ -0,0 +1,1622 @@
+//! Conversions between OpenRTB 2.6 and OpenRTB 3.0 with AdCOM 1.0
+//!
+//! The two versions describe largely the same auction, but 3.0 splits it into transport
+//! ([`Request`], [`Item`]) and domain ([`Placement`], [`Context`], [`Ad`]) layers and renames or
+//! drops a number of attributes. Every conversion returns the paths of the source fields that
+//! have no equivalent in the target version so callers can decide whether the loss matters.
+
+use super::countries::{to_alpha2, to_alpha3};
+use super::*;
+use crate::adcom::v1_0::{self as adcom, *};
+use crate::core::v2_6::enums::*;
+use crate::core::v2_6::{self, lift, lower, plcmt_from_placement};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+/// Result of a conversion between OpenRTB versions
+#[derive(Debug, Clone, PartialEq)]
+pub struct Converted<T> {
+    /// The converted object
+    pub value: T,
+
+    /// Paths of the source fields that were dropped because the target version cannot hold them
+    pub unmapped: Vec<String>,
+}
+
+#[derive(Default)]
+struct Unmapped(Vec<String>);
+
+impl Unmapped {
+    fn push(&mut self, path: &str, field: &str) {
+        self.0.push(join(path, field));
+    }
+
+    fn check<T>(&mut self, path: &str, field: &str, value: &Option<T>) {
+        if value.is_some() {
+            self.push(path, field);
+        }
+    }
+
+    /// Convert between two encodings of the same list value, reporting values that do not fit
+    fn recode<T: Serialize, U: DeserializeOwned>(
+        &mut self,
+        path: &str,
+        field: &str,
+        value: Option<T>,
+    ) -> Option<U> {
+        let value = value?;
+        let recoded = serde_json::to_value(value)
+            .ok()
+            .and_then(|v| serde_json::from_value(v).ok());
+        if recoded.is_none() {
+            self.push(path, field);
+        }
+        recoded
+    }
+}
+
+/// Taxonomy of an AdCOM `cattax`, reporting values OpenRTB 2.6 does not define
+fn cattax_into_v2_6(cattax: Option<u32>, path: &str, u: &mut Unmapped) -> Option<ContentTaxonomy> {
+    let cattax = ContentTaxonomy::try_from(cattax?).ok();
+    if cattax.is_none() {
+        u.push(path, "cattax");
+    }
+    cattax
+}
+
+fn join(path: &str, field: &str) -> String {
+    if path.is_empty() {
+        field.to_string()
+    } else {
+        format!("{path}.{field}")
+    }
+}
+
+/// Operating systems of the AdCOM list with the name OpenRTB 2.6 partners commonly send
+const OPERATING_SYSTEMS: &[(u32, &str)] = &[
+    (2, "Android"),
+    (3, "tvOS"),
+    (8, "Chrome OS"),
+    (10, "Fire OS"),
+    (13, "iOS"),
+    (14, "Linux"),
+    (15, "macOS"),
+    (25, "Tizen"),
+    (26, "watchOS"),
+    (27, "webOS"),
+    (28, "Windows"),
+];
+
+fn os_code(name: &str) -> Option<u32> {
+    let normalize = |s: &str| s.replace(' ', "").to_ascii_lowercase();
+    let name = normalize(name);
+    OPERATING_SYSTEMS
+        .iter()
+        .find(|(_, known)| normalize(known) == name)
+        .map(|(code, _)| *code)
+}
+
+fn os_name(code: u32) -> Option<&'static str> {
+    OPERATING_SYSTEMS
+        .iter()
+        .find(|(known, _)| *known == code)
+        .map(|(_, name)| *name)
+}
+
+fn is_vast(adm: &str) -> bool {
+    adm.contains("<VAST")
+}
+
+impl<Ext> Request<Ext> {
+    /// Convert an OpenRTB 2.6 bid request
+    ///
+    /// Context objects move into the AdCOM [`Context`] and every impression becomes an
+    /// [`Item`] offering a single [`Placement`]. Native impressions are not converted.
+    pub fn from_v2_6(request: v2_6::BidRequest<Ext>) -> Converted<Self> {
+        let mut unmapped = Unmapped::default();
+        let value = request_from_v2_6(request, &mut unmapped);
+        Converted {
+            value,
+            unmapped: unmapped.0,
+        }
+    }
+
+    /// Convert into an OpenRTB 2.6 bid request
+    ///
+    /// Creative attributes blocked in the context restrictions are applied to every banner,
+    /// video and audio object of the request.
+    pub fn into_v2_6(self) -> Converted<v2_6::BidRequest<Ext>> {
+        let mut unmapped = Unmapped::default();
+        let value = request_into_v2_6(self, &mut unmapped);
+        Converted {
+            value,
+            unmapped: unmapped.0,
+        }
+    }
+}
+
+impl<Ext> Response<Ext> {
+    /// Convert an OpenRTB 2.6 bid response
+    ///
+    /// The markup of a bid becomes a video ad if it holds a VAST document and a display ad
+    /// otherwise.
+    pub fn from_v2_6(response: v2_6::BidResponse<Ext>) -> Converted<Self> {
+        let mut unmapped = Unmapped::default();
+        let value = response_from_v2_6(response, &mut unmapped);
+        Converted {
+            value,
+            unmapped: unmapped.0,
+        }
+    }
+
+    /// Convert into an OpenRTB 2.6 bid response
+    pub fn into_v2_6(self) -> Converted<v2_6::BidResponse<Ext>> {
+        let mut unmapped = Unmapped::default();
+        let value = response_into_v2_6(self, &mut unmapped);
+        Converted {
+            value,
+            unmapped: unmapped.0,
+        }
+    }
+}
+
+fn request_from_v2_6<Ext>(request: v2_6::BidRequest<Ext>, u: &mut Unmapped) -> Request<Ext> {
+    let (seat, wseat) = match (request.wseat, request.bseat) {
+        (Some(allowed), blocked) => {
+            u.check("", "bseat", &blocked);
+            (Some(allowed), Some(1))
+        }
+        (None, Some(blocked)) => (Some(blocked), Some(0)),
+        (None, None) => (None, None),
+    };
+    u.check("", "allimps", &request.allimps);
+    u.check("", "cacheid", &request.cacheid);
+
+    let mut user = request.user;
+    let cdata = user.as_mut().and_then(|user| user.customdata.take());
+    let restrictions = (request.bcat.is_some()
+        || request.cattax.is_some()
+        || request.badv.is_some()
+        || request.bapp.is_some())
+    .then(|| Restrictions {
+        bcat: request.bcat,
+        cattax: request.cattax.map(u32::from),
+        badv: request.badv,
+        bapp: request.bapp,
+        ..Default::default()
+    });
+    let context = Context {
+        site: request.site.map(|site| site_from_v2_6(site, u)),
+        app: request.app.map(|app| app_from_v2_6(app, u)),
+        dooh: request.dooh.map(|dooh| dooh_from_v2_6(dooh, u)),
+        user: user.map(|user| user_from_v2_6(user, u)),
+        device: request.device.map(|device| device_from_v2_6(device, u)),
+        regs: request.regs.map(|regs| regs_from_v2_6(regs, u)),
+        restrictions,
+        ext: None,
+    };
+
+    let item = request
+        .imp
+        .into_iter()
+        .enumerate()
+        .map(|(i, imp)| item_from_v2_6(imp, request.wlang.clone(), &format!("imp[{i}]"), u))
+        .collect();
+
+    Request {
+        id: request.id,
+        test: request.test,
+        tmax: request.tmax,
+        at: request.at,
+        cur: request.cur,
+        seat,
+        wseat,
+        cdata,
+        source: request.source.map(|source| source_from_v2_6(source, u)),
+        item,
+        package: None,
+        context: (context != Context::default()).then_some(context),
+        ext: request.ext,
+    }
+}
+
+fn request_into_v2_6<Ext>(request: Request<Ext>, u: &mut Unmapped) -> v2_6::BidRequest<Ext> {
+    let (wseat, bseat) = match request.wseat {
+        Some(0) => (None, request.seat),
+        _ => (request.seat, None),
+    };
+    u.check("", "package", &request.package);
+
+    let context = request.context.unwrap_or_default();
+    u.check("context", "ext", &context.ext);
+    let restrictions = context.restrictions.unwrap_or_default();
+    u.check("context.restrictions", "ext", &restrictions.ext);
+
+    let mut wlang = None;
+    let imp = request
+        .item
+        .into_iter()
+        .enumerate()
+        .map(|(i, item)| {
+            let path = format!("item[{i}]");
+            let placement = item.spec.placement.as_ref();
+            match (&wlang, placement.and_then(|p| p.wlang.as_ref())) {
+                (None, Some(languages)) => wlang = Some(languages.clone()),
+                (Some(first), Some(languages)) if first != languages => {
+                    u.push(&path, "spec.placement.wlang")
+                }
+                _ => {}
+            }
+            item_into_v2_6(item, restrictions.battr.as_ref(), &path, u)
+        })
+        .collect();
+
+    let mut user = context.user.map(|user| user_into_v2_6(user, u));
+    if let Some(cdata) = request.cdata {
+        user.get_or_insert_with(Default::default).customdata = Some(cdata);
+    }
+
+    v2_6::BidRequest {
+        id: request.id,
+        imp,
+        site: context.site.map(|site| site_into_v2_6(site, u)),
+        app: context.app.map(|app| app_into_v2_6(app, u)),
+        dooh: context.dooh.map(|dooh| dooh_into_v2_6(dooh, u)),
+        device: context.device.map(|device| device_into_v2_6(device, u)),
+        user,
+        test: request.test,
+        at: request.at,
+        tmax: request.tmax,
+        wseat,
+        bseat,
+        allimps: None,
+        cur: request.cur,
+        wlang,
+        cacheid: None,
+        bcat: restrictions.bcat,
+        cattax: cattax_into_v2_6(restrictions.cattax, "context.restrictions", u),
+        badv: restrictions.badv,
+        bapp: restrictions.bapp,
+        source: request.source.map(|source| source_into_v2_6(source, u)),
+        regs: context.regs.map(regs_into_v2_6),
+        ext: request.ext,
+    }
+}
+
+fn source_from_v2_6(source: v2_6::Source, u: &mut Unmapped) -> Source {
+    u.check("source", "fd", &source.fd);
+    Source {
+        tid: source.tid,
+        pchain: source.pchain,
+        schain: source.schain,
+        ext: source.ext,
+        ..Default::default()
+    }
+}
+
+fn source_into_v2_6(source: Source, u: &mut Unmapped) -> v2_6::Source {
+    u.check("source", "ts", &source.ts);
+    u.check("source", "ds", &source.ds);
+    u.check("source", "dsmap", &source.dsmap);
+    u.check("source", "cert", &source.cert);
+    u.check("source", "digest", &source.digest);
+    v2_6::Source {
+        fd: None,
+        tid: source.tid,
+        pchain: source.pchain,
+        schain: source.schain,
+        ext: source.ext,
+    }
+}
+
+fn item_from_v2_6(
+    imp: v2_6::Impression,
+    wlang: Option<Vec<String>>,
+    path: &str,
+    u: &mut Unmapped,
+) -> Item {
+    u.check(path, "native", &imp.native);
+    let (deal, private) = match imp.pmp {
+        Some(pmp) => {
+            u.check(&join(path, "pmp"), "ext", &pmp.ext);
+            let deals = pmp.deals.map(|deals| {
+                deals
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, deal)| deal_from_v2_6(deal, &format!("{path}.pmp.deals[{i}]"), u))
+                    .collect()
+            });
+            (deals, pmp.private_auction)
+        }
+        None => (None, None),
+    };
+
+    let mut display = imp
+        .banner
+        .map(|banner| display_from_v2_6(banner, &join(path, "banner"), u));
+    if let Some(display) = display.as_mut() {
+        display.instl = imp.instl;
+        display.clktype = imp.clickbrowser;
+        display.ifrbust = imp.iframebuster;
+    } else {
+        u.check(path, "instl", &imp.instl);
+        u.check(path, "clickbrowser", &imp.clickbrowser);
+        u.check(path, "iframebuster", &imp.iframebuster);
+    }
+
+    let placement = Placement {
+        tagid: imp.tagid,
+        sdk: imp.displaymanager,
+        sdkver: imp.displaymanagerver,
+        wlang,
+        secure: imp.secure,
+        display,
+        video: imp
+            .video
+            .map(|video| video_placement_from_v2_6(video, &join(path, "video"), u)),
+        audio: imp
+            .audio
+            .map(|audio| audio_placement_from_v2_6(audio, &join(path, "audio"), u)),
+        ..Default::default()
+    };
+
+    Item {
+        id: imp.id,
+        flr: imp.bidfloor,
+        flrcur: imp.bidfloorcur,
+        exp: imp.exp,
+        metric: imp
+            .metric
+            .map(|metrics| metrics.into_iter().map(metric_from_v2_6).collect()),
+        deal,
+        private,
+        spec: Spec {
+            placement: Some(placement),
+        },
+        ext: imp.ext,
+        ..Default::default()
+    }
+}
+
+fn item_into_v2_6(
+    item: Item,
+    battr: Option<&Vec<CreativeAttribute>>,
+    path: &str,
+    u: &mut Unmapped,
+) -> v2_6::Impression {
+    u.check(path, "qty", &item.qty);
+    u.check(path, "seq", &item.seq);
+    u.check(path, "dt", &item.dt);
+    u.check(path, "dlvy", &item.dlvy);
+
+    let pmp = (item.deal.is_some() || item.private.is_some()).then(|| v2_6::Pmp {
+        private_auction: item.private,
+        deals: item.deal.map(|deals| {
+            deals
+                .into_iter()
+                .enumerate()
+                .map(|(i, deal)| deal_into_v2_6(deal, &format!("{path}.deal[{i}]"), u))
+                .collect()
+        }),
+        ext: None,
+    });
+
+    let mut imp = v2_6::Impression {
+        id: item.id,
+        pmp,
+        bidfloor: item.flr,
+        bidfloorcur: item.flrcur,
+        exp: item.exp,
+        metric: item
+            .metric
+            .map(|metrics| metrics.into_iter().map(metric_into_v2_6).collect()),
+        ext: item.ext,
+        ..Default::default()
+    };
+
+    let Some(placement) = item.spec.placement else {
+        return imp;
+    };
+    let path = join(path, "spec.placement");
+    u.check(&path, "ssai", &placement.ssai);
+    u.check(&path, "reward", &placement.reward);
+    u.check(&path, "admx", &placement.admx);
+    u.check(&path, "curlx", &placement.curlx);
+    u.check(&path, "ext", &placement.ext);
+    imp.tagid = placement.tagid;
+    imp.displaymanager = placement.sdk;
+    imp.displaymanagerver = placement.sdkver;
+    imp.secure = placement.secure;
+
+    if let Some(mut display) = placement.display {
+        imp.instl = display.instl.take();
+        imp.clickbrowser = display.clktype.take();
+        imp.iframebuster = display.ifrbust.take();
+        let mut banner = display_into_v2_6(display, &join(&path, "display"), u);
+        banner.battr = battr.cloned();
+        imp.banner = Some(banner);
+    }
+    if let Some(video) = placement.video {
+        let mut video = video_placement_into_v2_6(video, &join(&path, "video"), u);
+        video.battr = battr.cloned();
+        imp.video = Some(video);
+    }
+    if let Some(audio) = placement.audio {
+        let mut audio = audio_placement_into_v2_6(audio, &join(&path, "audio"), u);
+        audio.battr = u.recode("context.restrictions", "battr", battr);
+        imp.audio = Some(audio);
+    }
+    imp
+}
+
+fn deal_from_v2_6(deal: v2_6::Deal, path: &str, u: &mut Unmapped) -> Deal {
+    Deal {
+        id: deal.id,
+        qty: None,
+        flr: deal.bidfloor,
+        flrcur: deal.bidfloorcur,
+        at: u.recode(path, "at", deal.at),
+        wseat: deal.wseat,
+        wadomain: deal.wadv,
+        ext: deal.ext,
+    }
+}
+
+fn deal_into_v2_6(deal: Deal, path: &str, u: &mut Unmapped) -> v2_6::Deal {
+    u.check(path, "qty", &deal.qty);
+    v2_6::Deal {
+        id: deal.id,
+        bidfloor: deal.flr,
+        bidfloorcur: deal.flrcur,
+        at: u.recode(path, "at", deal.at),
+        wseat: deal.wseat,
+        wadv: deal.wadomain,
+        ext: deal.ext,
+    }
+}
+
+fn metric_from_v2_6(metric: v2_6::Metric) -> Metric {
+    Metric {
+        r#type: metric.r#type,
+        value: metric.value,
+        vendor: metric.vendor,
+        ext: metric.ext,
+    }
+}
+
+fn metric_into_v2_6(metric: Metric) -> v2_6::Metric {
+    v2_6::Metric {
+        r#type: metric.r#type,
+        value: metric.value,
+        vendor: metric.vendor,
+        ext: metric.ext,
+    }
+}
+
+fn display_from_v2_6(banner: v2_6::Banner, path: &str, u: &mut Unmapped) -> DisplayPlacement {
+    u.check(path, "wmax", &banner.wmax);
+    u.check(path, "hmax", &banner.hmax);
+    u.check(path, "wmin", &banner.wmin);
+    u.check(path, "hmin", &banner.hmin);
+    u.check(path, "id", &banner.id);
+    u.check(path, "btype", &banner.btype);
+    u.check(path, "battr", &banner.battr);
+
+    let expdir = banner.expdir;
+    let displayfmt = banner.format.map(|formats| {
+        formats
+            .into_iter()
+            .enumerate()
+            .map(|(i, format)| {
+                u.check(&format!("{path}.format[{i}]"), "wmin", &format.wmin);
+                DisplayFormat {
+                    w: format.w,
+                    h: format.h,
+                    wratio: format.wratio,
+                    hratio: format.hratio,
+                    expdir: expdir.clone(),
+                    ext: format.ext,
+                }
+            })
+            .collect::<Vec<_>>()
+    });
+    if displayfmt.as_ref().is_none_or(Vec::is_empty) {
+        u.check(path, "expdir", &expdir);
+    }
+
+    DisplayPlacement {
+        pos: banner.pos,
+        topframe: banner.topframe,
+        mime: banner.mimes,
+        api: banner.api,
+        w: banner.w,
+        h: banner.h,
+        displayfmt,
+        ext: banner.ext,
+        ..Default::default()
+    }
+}
+
+fn display_into_v2_6(display: DisplayPlacement, path: &str, u: &mut Unmapped) -> v2_6::Banner {
+    u.check(path, "ampren", &display.ampren);
+    u.check(path, "ptype", &display.ptype);
+    u.check(path, "context", &display.context);
+    u.check(path, "ctype", &display.ctype);
+    u.check(path, "unit", &display.unit);
+    u.check(path, "priv", &display.r#priv);
+    u.check(path, "nativefmt", &display.nativefmt);
+    u.check(path, "event", &display.event);
+
+    let mut expdir = None;
+    let format = display.displayfmt.map(|formats| {
+        formats
+            .into_iter()
+            .enumerate()
+            .map(|(i, format)| {
+                match (&expdir, format.expdir) {
+                    (None, Some(directions)) => expdir = Some(directions),
+                    (Some(first), Some(directions)) if *first != directions => {
+                        u.push(&format!("{path}.displayfmt[{i}]"), "expdir")
+                    }
+                    _ => {}
+                }
+                v2_6::Format {
+                    w: format.w,
+                    h: format.h,
+                    wratio: format.wratio,
+                    hratio: format.hratio,
+                    wmin: None,
+                    ext: format.ext,
+                }
+            })
+            .collect()
+    });
+
+    v2_6::Banner {
+        format,
+        w: display.w,
+        h: display.h,
+        pos: display.pos,
+        mimes: display.mime,
+        topframe: display.topframe,
+        expdir,
+        api: display.api,
+        ext: display.ext,
+        ..Default::default()
+    }
+}
+
+fn video_placement_from_v2_6(video: v2_6::Video, path: &str, u: &mut Unmapped) -> VideoPlacement {
+    let ctype = match (video.protocols, video.protocol) {
+        (Some(protocols), protocol) => {
+            u.check(path, "protocol", &protocol);
+            Some(protocols)
+        }
+        (None, protocol) => protocol.map(|protocol| vec![protocol]),
+    };
+    let ptype = match video.plcmt {
+        Some(plcmt) => {
+            u.check(path, "placement", &video.placement);
+            Some(plcmt)
+        }
+        None => video.placement.map(plcmt_from_placement),
+    };
+    u.check(path, "sequence", &video.sequence);
+    u.check(path, "battr", &video.battr);
+    u.check(path, "companionad", &video.companionad);
+    u.check(path, "feed", &video.feed);
+    u.check(path, "stitched", &video.stitched);
+    u.check(path, "nvol", &video.nvol);
+
+    VideoPlacement {
+        ptype,
+        pos: video.pos,
+        delay: video.startdelay,
+        skip: video.skip,
+        skipmin: video.skipmin,
+        skipafter: video.skipafter,
+        mindur: video.minduration,
+        maxdur: video.maxduration,
+        maxext: video.maxextended,
+        mincpms: video.mincpmpersec,
+        maxseq: video.maxseq,
+        rqddurs: video.rqddurs,
+        poddur: video.poddur,
+        podid: video.podid,
+        podseq: video.podseq,
+        slotinpod: video.slotinpod,
+        boxing: video.boxingallowed,
+        unit: None,
+        mime: Some(video.mimes),
+        api: video.api,
+        ctype,
+        w: video.w,
+        h: video.h,
+        minbitr: video.minbitrate,
+        maxbitr: video.maxbitrate,
+        linear: video.linearity,
+        playmethod: video.playbackmethod,
+        playend: video.playbackend,
+        delivery: video.delivery,
+        comptype: video.companiontype,
+        comp: None,
+        ext: video.ext,
+    }
+}
+
+fn video_placement_into_v2_6(video: VideoPlacement, path: &str, u: &mut Unmapped) -> v2_6::Video {
+    u.check(path, "unit", &video.unit);
+    u.check(path, "comp", &video.comp);
+    v2_6::Video {
+        mimes: video.mime.unwrap_or_default(),
+        minduration: video.mindur,
+        maxduration: video.maxdur,
+        protocols: video.ctype,
+        w: video.w,
+        h: video.h,
+        startdelay: video.delay,
+        plcmt: video.ptype,
+        linearity: video.linear,
+        skip: video.skip,
+        skipmin: video.skipmin,
+        skipafter: video.skipafter,
+        maxextended: video.maxext,
+        minbitrate: video.minbitr,
+        maxbitrate: video.maxbitr,
+        boxingallowed: video.boxing,
+        playbackmethod: video.playmethod,
+        playbackend: video.playend,
+        delivery: video.delivery,
+        pos: video.pos,
+        api: video.api,
+        companiontype: video.comptype,
+        maxseq: video.maxseq,
+        podid: video.podid,
+        podseq: video.podseq,
+        poddur: video.poddur,
+        rqddurs: video.rqddurs,
+        slotinpod: video.slotinpod,
+        mincpmpersec: video.mincpms,
+        ext: video.ext,
+        ..Default::default()
+    }
+}
+
+fn audio_placement_from_v2_6(audio: v2_6::Audio, path: &str, u: &mut Unmapped) -> AudioPlacement {
+    u.check(path, "sequence", &audio.sequence);
+    u.check(path, "battr", &audio.battr);
+    u.check(path, "companionad", &audio.companionad);
+    u.check(path, "stitched", &audio.stitched);
+    AudioPlacement {
+        delay: audio.startdelay,
+        mindur: audio.minduration,
+        maxdur: audio.maxduration,
+        maxext: audio.maxextended,
+        maxseq: audio.maxseq,
+        feed: audio.feed,
+        nvol: audio.nvol,
+        mime: Some(audio.mimes),
+        api: audio.api,
+        ctype: audio.protocols,
+        minbitr: audio.minbitrate,
+        maxbitr: audio.maxbitrate,
+        delivery: audio.delivery,
+        comptype: audio.companiontype,
+        ext: audio.ext,
+        ..Default::default()
+    }
+}
+
+fn audio_placement_into_v2_6(audio: AudioPlacement, path: &str, u: &mut Unmapped) -> v2_6::Audio {
+    u.check(path, "skip", &audio.skip);
+    u.check(path, "skipmin", &audio.skipmin);
+    u.check(path, "skipafter", &audio.skipafter);
+    u.check(path, "mincpms", &audio.mincpms);
+    u.check(path, "rqddurs", &audio.rqddurs);
+    u.check(path, "poddur", &audio.poddur);
+    u.check(path, "podid", &audio.podid);
+    u.check(path, "podseq", &audio.podseq);
+    u.check(path, "slotinpod", &audio.slotinpod);
+    u.check(path, "comp", &audio.comp);
+    v2_6::Audio {
+        mimes: audio.mime.unwrap_or_default(),
+        minduration: audio.mindur,
+        maxduration: audio.maxdur,
+        protocols: audio.ctype,
+        startdelay: audio.delay,
+        maxextended: audio.maxext,
+        minbitrate: audio.minbitr,
+        maxbitrate: audio.maxbitr,
+        delivery: audio.delivery,
+        api: audio.api,
+        companiontype: audio.comptype,
+        maxseq: audio.maxseq,
+        feed: audio.feed,
+        nvol: audio.nvol,
+        ext: audio.ext,
+        ..Default::default()
+    }
+}
+
+fn site_from_v2_6(site: v2_6::Site, u: &mut Unmapped) -> adcom::Site {
+    adcom::Site {
+        id: site.id,
+        name: site.name,
+        r#pub: site.publisher.map(publisher_from_v2_6),
+        content: site
+            .content
+            .map(|content| content_from_v2_6(content, "site.content", u)),
+        domain: site.domain,
+        cat: site.cat,
+        cattax: site.cattax.map(u32::from),
+        sectcat: site.sectioncat,
+        pagecat: site.pagecat,
+        privpolicy: site.privacypolicy,
+        keywords: site.keywords,
+        page: site.page,
+        r#ref: site.r#ref,
+        search: site.search,
+        mobile: site.mobile,
+        amp: None,
+        ext: site.ext,
+    }
+}
+
+fn site_into_v2_6(site: adcom::Site, u: &mut Unmapped) -> v2_6::Site {
+    u.check("context.site", "amp", &site.amp);
+    v2_6::Site {
+        id: site.id,
+        name: site.name,
+        domain: site.domain,
+        cat: site.cat,
+        cattax: cattax_into_v2_6(site.cattax, "context.site", u),
+        sectioncat: site.sectcat,
+        pagecat: site.pagecat,
+        page: site.page,
+        r#ref: site.r#ref,
+        search: site.search,
+        mobile: site.mobile,
+        privacypolicy: site.privpolicy,
+        publisher: site
+            .r#pub
+            .map(|publisher| publisher_into_v2_6(publisher, "context.site.pub", u)),
+        content: site
+            .content
+            .map(|content| content_into_v2_6(content, "context.site.content", u)),
+        keywords: site.keywords,
+        ext: site.ext,
+    }
+}
+
+fn app_from_v2_6(app: v2_6::App, u: &mut Unmapped) -> adcom::App {
+    adcom::App {
+        id: app.id,
+        name: app.name,
+        r#pub: app.publisher.map(publisher_from_v2_6),
+        content: app
+            .content
+            .map(|content| content_from_v2_6(content, "app.content", u)),
+        domain: app.domain,
+        cat: app.cat,
+        cattax: app.cattax.map(u32::from),
+        sectcat: app.sectioncat,
+        pagecat: app.pagecat,
+        privpolicy: app.privacypolicy,
+        keywords: app.keywords,
+        ver: app.ver,
+        bundle: app.bundle,
+        storeid: None,
+        storeurl: app.storeurl,
+        paid: app.paid,
+        ext: app.ext,
+    }
+}
+
+fn app_into_v2_6(app: adcom::App, u: &mut Unmapped) -> v2_6::App {
+    u.check("context.app", "storeid", &app.storeid);
+    v2_6::App {
+        id: app.id,
+        name: app.name,
+        bundle: app.bundle,
+        domain: app.domain,
+        storeurl: app.storeurl,
+        cat: app.cat,
+        cattax: cattax_into_v2_6(app.cattax, "context.app", u),
+        sectioncat: app.sectcat,
+        pagecat: app.pagecat,
+        ver: app.ver,
+        privacypolicy: app.privpolicy,
+        paid: app.paid,
+        publisher: app
+            .r#pub
+            .map(|publisher| publisher_into_v2_6(publisher, "context.app.pub", u)),
+        content: app
+            .content
+            .map(|content| content_into_v2_6(content, "context.app.content", u)),
+        keywords: app.keywords,
+        ext: app.ext,
+    }
+}
+
+fn dooh_from_v2_6(dooh: v2_6::Dooh, u: &mut Unmapped) -> adcom::Dooh {
+    u.check("dooh", "cat", &dooh.cat);
+    u.check("dooh", "cattax", &dooh.cattax);
+    adcom::Dooh {
+        id: dooh.id,
+        name: dooh.name,
+        r#pub: dooh.publisher.map(publisher_from_v2_6),
+        content: dooh
+            .content
+            .map(|content| content_from_v2_6(content, "dooh.content", u)),
+        venuetype: dooh.venuetype,
+        venuetypetax: dooh.venuetax,
+        domain: dooh.domain,
+        keywords: dooh.keywords,
+        ext: dooh.ext,
+    }
+}
+
+fn dooh_into_v2_6(dooh: adcom::Dooh, u: &mut Unmapped) -> v2_6::Dooh {
+    v2_6::Dooh {
+        id: dooh.id,
+        name: dooh.name,
+        venuetype: dooh.venuetype,
+        venuetax: dooh.venuetypetax,
+        publisher: dooh
+            .r#pub
+            .map(|publisher| publisher_into_v2_6(publisher, "context.dooh.pub", u)),
+        domain: dooh.domain,
+        cat: None,
+        cattax: None,
+        keywords: dooh.keywords,
+        content: dooh
+            .content
+            .map(|content| content_into_v2_6(content, "context.dooh.content", u)),
+        ext: dooh.ext,
+    }
+}
+
+fn publisher_from_v2_6(publisher: v2_6::Publisher) -> adcom::Publisher {
+    adcom::Publisher {
+        id: publisher.id,
+        name: publisher.name,
+        domain: publisher.domain,
+        cat: publisher.cat,
+        cattax: publisher.cattax.map(u32::from),
+        ext: publisher.ext,
+    }
+}
+
+fn publisher_into_v2_6(
+    publisher: adcom::Publisher,
+    path: &str,
+    u: &mut Unmapped,
+) -> v2_6::Publisher {
+    v2_6::Publisher {
+        id: publisher.id,
+        name: publisher.name,
+        cat: publisher.cat,
+        cattax: cattax_into_v2_6(publisher.cattax, path, u),
+        domain: publisher.domain,
+        ext: publisher.ext,
+    }
+}
+
+fn content_from_v2_6(content: v2_6::Content, path: &str, u: &mut Unmapped) -> adcom::Content {
+    u.check(path, "videoquality", &content.videoquality);
+    adcom::Content {
+        id: content.id,
+        episode: content.episode,
+        title: content.title,
+        series: content.series,
+        season: content.season,
+        artist: content.artist,
+        genre: content.genre,
+        album: content.album,
+        isrc: content.isrc,
+        url: content.url,
+        cat: content.cat,
+        cattax: content.cattax.map(u32::from),
+        prodq: content.prodq,
+        context: content.context,
+        rating: content.contentrating,
+        urating: content.userrating,
+        mrating: content.qagmediarating,
+        keywords: content.keywords,
+        live: content.livestream,
+        srcrel: content.sourcerelationship,
+        len: content.len,
+        lang: content.language,
+        embed: content.embeddable,
+        producer: content.producer.map(|producer| adcom::Producer {
+            id: producer.id,
+            name: producer.name,
+            domain: producer.domain,
+            cat: producer.cat,
+            cattax: producer.cattax.map(u32::from),
+            ext: producer.ext,
+        }),
+        network: content.network.map(|network| adcom::Network {
+            id: network.id,
+            name: network.name,
+            domain: network.domain,
+            ext: network.ext,
+        }),
+        channel: content.channel.map(|channel| adcom::Channel {
+            id: channel.id,
+            name: channel.name,
+            domain: channel.domain,
+            ext: channel.ext,
+        }),
+        data: content
+            .data
+            .map(|data| data.into_iter().map(data_from_v2_6).collect()),
+        ext: content.ext,
+    }
+}
+
+fn content_into_v2_6(content: adcom::Content, path: &str, u: &mut Unmapped) -> v2_6::Content {
+    let cattax = cattax_into_v2_6(content.cattax, path, u);
+    v2_6::Content {
+        id: content.id,
+        episode: content.episode,
+        title: content.title,
+        series: content.series,
+        season: content.season,
+        artist: content.artist,
+        genre: content.genre,
+        album: content.album,
+        isrc: content.isrc,
+        producer: content.producer.map(|producer| v2_6::Producer {
+            id: producer.id,
+            name: producer.name,
+            cat: producer.cat,
+            cattax: cattax_into_v2_6(producer.cattax, &join(path, "producer"), u),
+            domain: producer.domain,
+            ext: producer.ext,
+        }),
+        url: content.url,
+        cat: content.cat,
+        cattax,
+        prodq: content.prodq,
+        videoquality: None,
+        context: content.context,
+        contentrating: content.rating,
+        userrating: content.urating,
+        qagmediarating: content.mrating,
+        keywords: content.keywords,
+        livestream: content.live,
+        sourcerelationship: content.srcrel,
+        len: content.len,
+        language: content.lang,
+        embeddable: content.embed,
+        data: content
+            .data
+            .map(|data| data.into_iter().map(data_into_v2_6).collect()),
+        network: content.network.map(|network| v2_6::Network {
+            id: network.id,
+            name: network.name,
+            domain: network.domain,
+            ext: network.ext,
+        }),
+        channel: content.channel.map(|channel| v2_6::Channel {
+            id: channel.id,
+            name: channel.name,
+            domain: channel.domain,
+            ext: channel.ext,
+        }),
+        ext: content.ext,
+    }
+}
+
+fn device_from_v2_6(device: v2_6::Device, u: &mut Unmapped) -> adcom::Device {
+    for (field, value) in [
+        ("flashver", &device.flashver),
+        ("didsha1", &device.didsha1),
+        ("didmd5", &device.didmd5),
+        ("dpidsha1", &device.dpidsha1),
+        ("dpidmd5", &device.dpidmd5),
+        ("macsha1", &device.macsha1),
+        ("macmd5", &device.macmd5),
+    ] {
+        u.check("device", field, value);
+    }
+    let os = device.os.as_deref().and_then(|os| {
+        let code = os_code(os);
+        if code.is_none() {
+            u.push("device", "os");
+        }
+        code
+    });
+
+    adcom::Device {
+        r#type: device.devicetype,
+        ua: device.ua,
+        sua: device.sua,
+        ifa: device.ifa,
+        dnt: device.dnt,
+        lmt: device.lmt,
+        make: device.make,
+        model: device.model,
+        os,
+        osv: device.osv,
+        hwv: device.hwv,
+        h: device.h,
+        w: device.w,
+        ppi: device.ppi,
+        pxratio: device.pxratio,
+        js: device.js,
+        lang: device.language,
+        ip: device.ip,
+        ipv6: device.ipv6,
+        carrier: device.carrier,
+        mccmnc: device.mccmnc,
+        contype: device.connectiontype,
+        geofetch: device.geofetch,
+        geo: device.geo.map(|geo| geo_from_v2_6(geo, "device.geo", u)),
+        ext: device.ext,
+        ..Default::default()
+    }
+}
+
+fn device_into_v2_6(device: adcom::Device, u: &mut Unmapped) -> v2_6::Device {
+    u.check("context.device", "xff", &device.xff);
+    u.check("context.device", "iptr", &device.iptr);
+    u.check("context.device", "mccmncsim", &device.mccmncsim);
+    let os = device.os.and_then(|os| {
+        let name = os_name(os);
+        if name.is_none() {
+            u.push("context.device", "os");
+        }
+        name.map(str::to_string)
+    });
+
+    v2_6::Device {
+        ua: device.ua,
+        geo: device
+            .geo
+            .map(|geo| geo_into_v2_6(geo, "context.device.geo", u)),
+        dnt: device.dnt,
+        lmt: device.lmt,
+        ip: device.ip,
+        ipv6: device.ipv6,
+        devicetype: device.r#type,
+        make: device.make,
+        model: device.model,
+        os,
+        osv: device.osv,
+        hwv: device.hwv,
+        h: device.h,
+        w: device.w,
+        ppi: device.ppi,
+        pxratio: device.pxratio,
+        js: device.js,
+        geofetch: device.geofetch,
+        language: device.lang,
+        carrier: device.carrier,
+        mccmnc: device.mccmnc,
+        connectiontype: device.contype,
+        ifa: device.ifa,
+        sua: device.sua,
+        ext: device.ext,
+        ..Default::default()
+    }
+}
+
+fn geo_from_v2_6(geo: v2_6::Geo, path: &str, u: &mut Unmapped) -> adcom::Geo {
+    u.check(path, "regionfips104", &geo.regionfips104);
+    let country = geo.country.and_then(|country| {
+        let code = to_alpha2(&country);
+        if code.is_none() {
+            u.push(path, "country");
+        }
+        code.map(str::to_string)
+    });
+    let utcoffset = geo.utcoffset.and_then(|offset| i32::try_from(offset).ok());
+
+    adcom::Geo {
+        r#type: geo.r#type,
+        lat: geo.lat,
+        lon: geo.lon,
+        accur: geo.accuracy,
+        lastfix: geo.lastfix,
+        ipserv: geo.ipservice,
+        country,
+        region: geo.region,
+        metro: geo.metro,
+        city: geo.city,
+        zip: geo.zip,
+        utcoffset,
+        ext: geo.ext,
+    }
+}
+
+fn geo_into_v2_6(geo: adcom::Geo, path: &str, u: &mut Unmapped) -> v2_6::Geo {
+    let country = geo.country.and_then(|country| {
+        let code = to_alpha3(&country);
+        if code.is_none() {
+            u.push(path, "country");
+        }
+        code.map(str::to_string)
+    });
+    let utcoffset = geo.utcoffset.and_then(|offset| {
+        let offset = u32::try_from(offset).ok();
+        if offset.is_none() {
+            u.push(path, "utcoffset");
+        }
+        offset
+    });
+
+    v2_6::Geo {
+        lat: geo.lat,
+        lon: geo.lon,
+        r#type: geo.r#type,
+        accuracy: geo.accur,
+        lastfix: geo.lastfix,
+        ipservice: geo.ipserv,
+        country,
+        region: geo.region,
+        regionfips104: None,
+        metro: geo.metro,
+        city: geo.city,
+        zip: geo.zip,
+        utcoffset,
+        ext: geo.ext,
+    }
+}
+
+fn user_from_v2_6(user: v2_6::User, u: &mut Unmapped) -> adcom::User {
+    u.check("user", "sua", &user.sua);
+    adcom::User {
+        id: user.id,
+        buyeruid: user.buyeruid,
+        yob: user.yob,
+        gender: user.gender,
+        keywords: user.keywords,
+        consent: user.consent,
+        geo: user.geo.map(|geo| geo_from_v2_6(geo, "user.geo", u)),
+        data: user
+            .data
+            .map(|data| data.into_iter().map(data_from_v2_6).collect()),
+        eids: user.eids,
+        ext: user.ext,
+    }
+}
+
+fn user_into_v2_6(user: adcom::User, u: &mut Unmapped) -> v2_6::User {
+    v2_6::User {
+        id: user.id,
+        buyeruid: user.buyeruid,
+        yob: user.yob,
+        gender: user.gender,
+        keywords: user.keywords,
+        customdata: None,
+        geo: user
+            .geo
+            .map(|geo| geo_into_v2_6(geo, "context.user.geo", u)),
+        data: user
+            .data
+            .map(|data| data.into_iter().map(data_into_v2_6).collect()),
+        consent: user.consent,
+        eids: user.eids,
+        sua: None,
+        ext: user.ext,
+    }
+}
+
+fn data_from_v2_6(data: v2_6::Data) -> adcom::Data {
+    adcom::Data {
+        id: data.id,
+        name: data.name,
+        segment: data.segment.map(|segments| {
+            segments
+                .into_iter()
+                .map(|segment| adcom::Segment {
+                    id: segment.id,
+                    name: segment.name,
+                    value: segment.value,
+                    ext: segment.ext,
+                })
+                .collect()
+        }),
+        ext: data.ext,
+    }
+}
+
+fn data_into_v2_6(data: adcom::Data) -> v2_6::Data {
+    v2_6::Data {
+        id: data.id,
+        name: data.name,
+        segment: data.segment.map(|segments| {
+            segments
+                .into_iter()
+                .map(|segment| v2_6::Segment {
+                    id: segment.id,
+                    name: segment.name,
+                    value: segment.value,
+                    ext: segment.ext,
+                })
+                .collect()
+        }),
+        ext: data.ext,
+    }
+}
+
+/// AdCOM 1.0 has no `us_privacy` attribute, so it travels in `ext` as it did before 2.6
+fn regs_from_v2_6(mut regs: v2_6::Regs, u: &mut Unmapped) -> adcom::Regs {
+    if regs.us_privacy.is_some() && !lower(&mut regs.us_privacy, &mut regs.ext, "us_privacy") {
+        u.push("regs", "us_privacy");
+    }
+    adcom::Regs {
+        coppa: regs.coppa,
+        gdpr: regs.gdpr,
+        ext: regs.ext,
+    }
+}
+
+fn regs_into_v2_6(regs: adcom::Regs) -> v2_6::Regs {
+    let mut us_privacy = None;
+    let mut ext = regs.ext;
+    lift(&mut ext, "us_privacy", &mut us_privacy);
+    v2_6::Regs {
+        coppa: regs.coppa,
+        gdpr: regs.gdpr,
+        us_privacy,
+        ext,
+    }
+}
+
+fn response_from_v2_6<Ext>(response: v2_6::BidResponse<Ext>, u: &mut Unmapped) -> Response<Ext> {
+    let seatbid = response.seatbid.map(|seatbids| {
+        seatbids
+            .into_iter()
+            .enumerate()
+            .map(|(i, seatbid)| Seatbid {
+                seat: seatbid.seat,
+                package: seatbid.group,
+                bid: seatbid
+                    .bid
+                    .into_iter()
+                    .enumerate()
+                    .map(|(j, bid)| bid_from_v2_6(bid, &format!("seatbid[{i}].bid[{j}]"), u))
+                    .collect(),
+                ext: seatbid.ext,
+            })
+            .collect()
+    });
+
+    Response {
+        id: response.id,
+        bidid: response.bidid,
+        nbr: response.nbr,
+        cur: response.cur,
+        cdata: response.customdata,
+        seatbid,
+        ext: response.ext,
+    }
+}
+
+fn response_into_v2_6<Ext>(response: Response<Ext>, u: &mut Unmapped) -> v2_6::BidResponse<Ext> {
+    let seatbid = response.seatbid.map(|seatbids| {
+        seatbids
+            .into_iter()
+            .enumerate()
+            .map(|(i, seatbid)| v2_6::SeatBid {
+                bid: seatbid
+                    .bid
+                    .into_iter()
+                    .enumerate()
+                    .map(|(j, bid)| bid_into_v2_6(bid, &format!("seatbid[{i}].bid[{j}]"), u))
+                    .collect(),
+                seat: seatbid.seat,
+                group: seatbid.package,
+                ext: seatbid.ext,
+            })
+            .collect()
+    });
+
+    v2_6::BidResponse {
+        id: response.id,
+        seatbid,
+        bidid: response.bidid,
+        cur: response.cur,
+        customdata: response.cdata,
+        nbr: response.nbr,
+        ext: response.ext,
+    }
+}
+
+#[allow(deprecated)]
+fn bid_from_v2_6(bid: v2_6::Bid, path: &str, u: &mut Unmapped) -> Bid {
+    u.check(path, "slotinpod", &bid.slotinpod);
+    let apis = match (bid.apis, bid.api) {
+        (Some(apis), api) => {
+            u.check(path, "api", &api);
+            Some(apis)
+        }
+        (None, api) => api.map(|api| vec![api]),
+    };
+
+    let (display, video) = if bid.adm.as_deref().is_some_and(is_vast) {
+        u.check(path, "w", &bid.w);
+        u.check(path, "h", &bid.h);
+        u.check(path, "wratio", &bid.wratio);
+        u.check(path, "hratio", &bid.hratio);
+        let video = adcom::Video {
+            api: u.recode(path, "apis", apis),
+            ctype: u.recode(path, "protocol", bid.protocol),
+            dur: bid.dur,
+            adm: bid.adm,
+            ..Default::default()
+        };
+        (None, Some(video))
+    } else {
+        u.check(path, "dur", &bid.dur);
+        u.check(path, "protocol", &bid.protocol);
+        let display = adcom::Display {
+            api: u.recode(path, "apis", apis),
+            w: bid.w,
+            h: bid.h,
+            wratio: bid.wratio,
+            hratio: bid.hratio,
+            adm: bid.adm,
+            ..Default::default()
+        };
+        (
+            Some(display).filter(|display| *display != Default::default()),
+            None,
+        )
+    };
+
+    let ad = Ad {
+        id: bid.crid.unwrap_or_default(),
+        adomain: bid.adomain,
+        bundle: bid.bundle.map(|bundle| vec![bundle]),
+        iurl: bid.iurl,
+        cat: bid.cat,
+        cattax: bid.cattax.map(u32::from),
+        lang: bid.language,
+        attr: bid.attr,
+        mrating: bid.qagmediarating,
+        display,
+        video,
+        ..Default::default()
+    };
+
+    Bid {
+        id: Some(bid.id),
+        item: bid.impid,
+        price: bid.price,
+        deal: bid.dealid,
+        cid: bid.cid,
+        tactic: bid.tactic,
+        purl: bid.nurl,
+        burl: bid.burl,
+        lurl: bid.lurl,
+        exp: bid.exp,
+        mid: bid.adid,
+        r#macro: None,
+        media: (ad != Ad::default()).then_some(Media { ad: Some(ad) }),
+        ext: bid.ext,
+    }
+}
+
+fn bid_into_v2_6(bid: Bid, path: &str, u: &mut Unmapped) -> v2_6::Bid {
+    u.check(path, "macro", &bid.r#macro);
+    let mut converted = v2_6::Bid {
+        id: bid.id.unwrap_or_default(),
+        impid: bid.item,
+        price: bid.price,
+        nurl: bid.purl,
+        burl: bid.burl,
+        lurl: bid.lurl,
+        adid: bid.mid,
+        cid: bid.cid,
+        tactic: bid.tactic,
+        dealid: bid.deal,
+        exp: bid.exp,
+        ext: bid.ext,
+        ..Default::default()
+    };
+
+    let Some(ad) = bid.media.and_then(|media| media.ad) else {
+        return converted;
+    };
+    let path = join(path, "media.ad");
+    u.check(&path, "secure", &ad.secure);
+    u.check(&path, "init", &ad.init);
+    u.check(&path, "lastmod", &ad.lastmod);
+    u.check(&path, "audit", &ad.audit);
+    u.check(&path, "ext", &ad.ext);
+
+    let mut bundles = ad.bundle.unwrap_or_default().into_iter();
+    converted.bundle = bundles.next();
+    if bundles.next().is_some() {
+        u.push(&path, "bundle");
+    }
+    converted.crid = Some(ad.id).filter(|id| !id.is_empty());
+    converted.adomain = ad.adomain;
+    converted.iurl = ad.iurl;
+    converted.cat = ad.cat;
+    converted.cattax = cattax_into_v2_6(ad.cattax, &path, u);
+    converted.language = ad.lang;
+    converted.attr = ad.attr;
+    converted.qagmediarating = ad.mrating;
+
+    if let Some(display) = ad.display {
+        let path = join(&path, "display");
+        u.check(&path, "mime", &display.mime);
+        u.check(&path, "ctype", &display.ctype);
+        u.check(&path, "priv", &display.r#priv);
+        u.check(&path, "curl", &display.curl);
+        u.check(&path, "banner", &display.banner);
+        u.check(&path, "native", &display.native);
+        u.check(&path, "event", &display.event);
+        u.check(&path, "ext", &display.ext);
+        converted.adm = display.adm;
+        converted.apis = u.recode(&path, "api", display.api);
+        converted.w = display.w;
+        converted.h = display.h;
+        converted.wratio = display.wratio;
+        converted.hratio = display.hratio;
+    }
+    if let Some(video) = ad.video {
+        let path = join(&path, "video");
+        u.check(&path, "mime", &video.mime);
+        u.check(&path, "curl", &video.curl);
+        u.check(&path, "ext", &video.ext);
+        if converted.adm.is_some() {
+            u.check(&path, "adm", &video.adm);
+        } else {
+            converted.adm = video.adm;
+        }
+        converted.apis = u.recode(&path, "api", video.api);
+        converted.protocol = u.recode(&path, "ctype", video.ctype);
+        converted.dur = video.dur;
+    }
+    if let Some(audio) = ad.audio {
+        let path = join(&path, "audio");
+        u.check(&path, "mime", &audio.mime);
+        u.check(&path, "curl", &audio.curl);
+        u.check(&path, "ext", &audio.ext);
+        if converted.adm.is_some() {
+            u.check(&path, "adm", &audio.adm);
+        } else {
+            converted.adm = audio.adm;
+        }
+        converted.apis = audio.api;
+        converted.protocol = audio.ctype;
+        converted.dur = audio.dur;
+    }
+    converted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_request_round_trip() {
+        let original: v2_6::BidRequest = serde_json::from_value(json!({
+            "id": "req",
+            "imp": [{
+                "id": "1",
+                "banner": {"format": [{"w": 300, "h": 250}], "pos": 1, "api": [3, 5]},
+                "video": {"mimes": ["video/mp4"], "protocols": [2, 3], "plcmt": 1, "maxduration": 30},
+                "tagid": "top",
+                "instl": 1,
+                "bidfloor": 1.25,
+                "bidfloorcur": "EUR",
+                "pmp": {"private_auction": 1, "deals": [{"id": "d1", "bidfloor": 2.0, "at": 1}]}
+            }],
+            "site": {"domain": "example.com", "sectioncat": ["IAB1"], "publisher": {"id": "p"}},
+            "device": {"ua": "Mozilla/5.0", "os": "iOS", "devicetype": 4, "geo": {"country": "DEU"}},
+            "user": {"id": "u", "customdata": "c", "consent": "CO"},
+            "regs": {"gdpr": 1, "us_privacy": "1YNN"},
+            "source": {"tid": "t"},
+            "wseat": ["seat-1"],
+            "bcat": ["IAB25"],
+            "tmax": 120
+        }))
+        .unwrap();
+
+        let converted = Request::from_v2_6(original.clone());
+        assert!(converted.unmapped.is_empty());
+        let request = converted.value;
+        let context = request.context.as_ref().unwrap();
+        assert_eq!(request.wseat, Some(1));
+        assert_eq!(request.cdata.as_deref(), Some("c"));
+        assert_eq!(
+            context.site.as_ref().unwrap().sectcat.as_ref().unwrap()[0],
+            "IAB1"
+        );
+        let device = context.device.as_ref().unwrap();
+        assert_eq!(device.os, Some(13));
+        assert_eq!(device.geo.as_ref().unwrap().country.as_deref(), Some("DE"));
+        assert_eq!(
+            context.regs.as_ref().unwrap().ext,
+            Some(json!({"us_privacy": "1YNN"}))
+        );
+        let placement = request.item[0].spec.placement.as_ref().unwrap();
+        assert_eq!(
+            placement.display.as_ref().unwrap().instl,
+            Some(InterstitialFlag::Interstitial)
+        );
+        assert_eq!(
+            placement.video.as_ref().unwrap().ptype,
+            Some(VideoPlcmtSubtype::Instream)
+        );
+
+        let converted = request.into_v2_6();
+        assert!(converted.unmapped.is_empty());
+        assert_eq!(converted.value, original);
+    }
+
+    #[test]
+    fn test_operating_system_codes() {
+        for (name, code) in [
+            ("Android", 2),
+            ("iOS", 13),
+            ("Chrome OS", 8),
+            ("Windows", 28),
+        ] {
+            let device: v2_6::Device = serde_json::from_value(json!({"os": name})).unwrap();
+            let mut unmapped = Unmapped::default();
+            let converted = device_from_v2_6(device, &mut unmapped);
+            assert_eq!(converted.os, Some(code));
+            assert_eq!(
+                device_into_v2_6(converted, &mut unmapped).os.as_deref(),
+                Some(name)
+            );
+            assert!(unmapped.0.is_empty());
+        }
+        assert_eq!(os_name(3), Some("tvOS"));
+    }
+
+    #[test]
+    fn test_unmapped_fields() {
+        let request: v2_6::BidRequest = serde_json::from_value(json!({
+            "id": "req",
+            "imp": [{"id": "1", "banner": {"w": 300, "h": 250, "btype": [1]}, "native": {"request": "{\"assets\": []}"}}],
+            "device": {"os": "Plan 9", "didsha1": "x"},
+            "cacheid": "cache"
+        }))
+        .unwrap();
+        let converted = Request::from_v2_6(request);
+        assert_eq!(
+            converted.unmapped,
+            [
+                "cacheid",
+                "device.didsha1",
+                "device.os",
+                "imp[0].native",
+                "imp[0].banner.btype"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_response_round_trip() {
+        let original: v2_6::BidResponse = serde_json::from_value(json!({
+            "id": "req",
+            "cur": "USD",
+            "seatbid": [{"seat": "s", "bid": [
+                {"id": "b1", "impid": "1", "price": 1.5, "adm": "<div/>", "crid": "cr", "w": 300, "h": 250, "adomain": ["ford.com"]},
+                {"id": "b2", "impid": "1", "price": 2.0, "adm": "<VAST version=\"4.0\"/>", "dur": 15, "protocol": 3, "nurl": "https://win"}
+            ]}]
+        }))
+        .unwrap();
+
+        let converted = Response::from_v2_6(original.clone());
+        assert!(converted.unmapped.is_empty());
+        let seatbid = &converted.value.seatbid.as_ref().unwrap()[0];
+        let ad = seatbid.bid[1].media.as_ref().unwrap().ad.as_ref().unwrap();
+        assert_eq!(ad.video.as_ref().unwrap().dur, Some(15));
+        assert_eq!(seatbid.bid[1].purl.as_deref(), Some("https://win"));
+
+        let converted = converted.value.into_v2_6();
+        assert!(converted.unmapped.is_empty());
+        assert_eq!(converted.value, original);
+    }
+}
@@ -0,0 +1,38 @@
+use crate::core::v2_6::enums::*;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// This object constitutes a specific deal that was struck *a priori* between a seller and a buyer.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Deal<Ext = Value> {
+    /// A unique identifier for the deal.
+    pub id: String,
+
+    /// The number of instances of the item being offered under the deal.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub qty: Option<u32>,
+
+    /// Minimum deal price for this item expressed in CPM.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flr: Option<f64>,
+
+    /// Currency of the `flr` attribute specified using ISO-4217 alpha codes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flrcur: Option<String>,
+
+    /// Optional override of the overall auction type of the request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub at: Option<AuctionType>,
+
+    /// Allowed list of buyer seats allowed to bid on this deal.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wseat: Option<Vec<String>>,
+
+    /// Array of advertiser domains allowed to bid on this deal.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wadomain: Option<Vec<String>>,
+
+    /// Placeholder for exchange-specific extensions to OpenRTB.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ext: Option<Ext>,
+}
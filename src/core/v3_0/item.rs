@@ -0,0 +1,61 @@
+use super::*;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// This object represents a unit of goods being offered for sale either on the open market or in
+/// relation to a private marketplace deal.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Item<Ext = Value> {
+    /// A unique identifier for this item within the context of the offer.
+    pub id: String,
+
+    /// The number of instances of this item being offered.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub qty: Option<u32>,
+
+    /// If multiple items are offered in the same bid request, the sequence number allows for the
+    /// coordinated delivery.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seq: Option<u32>,
+
+    /// Minimum bid price for this item expressed in CPM.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flr: Option<f64>,
+
+    /// Currency of the `flr` attribute specified using ISO-4217 alpha codes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flrcur: Option<String>,
+
+    /// Advisory as to the number of seconds that may elapse between auction and fulfilment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exp: Option<u32>,
+
+    /// Timestamp when the item is estimated to be fulfilled in Unix format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dt: Option<u64>,
+
+    /// Item (i.e., Ad object) markup delivery method, where 0 = either, 1 = markup in bid, 2 = markup
+    /// by reference.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dlvy: Option<u32>,
+
+    /// An array of `Metric` objects.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metric: Option<Vec<Metric>>,
+
+    /// Array of `Deal` objects that convey special terms applicable to this item.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deal: Option<Vec<Deal>>,
+
+    /// Indicator of auction eligibility to seats named in `Deal` objects, where 0 = all bids are
+    /// accepted, 1 = bids are restricted to the deals specified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private: Option<u32>,
+
+    /// Layer-4 domain object structure that specifies the item being offered.
+    pub spec: Spec,
+
+    /// Placeholder for exchange-specific extensions to OpenRTB.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ext: Option<Ext>,
+}
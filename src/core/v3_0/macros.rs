@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// This object constitutes a buyer defined key/value pair used to inject dynamic values into media markup.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Macro<Ext = Value> {
+    /// Name of a buyer specific macro.
+    pub key: String,
+
+    /// Value to substitute for each instance of the macro found in markup.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+
+    /// Placeholder for exchange-specific extensions to OpenRTB.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ext: Option<Ext>,
+}
@@ -0,0 +1,10 @@
+use crate::adcom::v1_0::Ad;
+use serde::{Deserialize, Serialize};
+
+/// AdCOM media of a bid
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Media {
+    /// The ad to be presented if the bid is won.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ad: Option<Ad>,
+}
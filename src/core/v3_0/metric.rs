@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// This object is associated with an item as an array of metrics.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Metric<Ext = Value> {
+    /// Type of metric being presented using exchange curated string names.
+    pub r#type: String,
+
+    /// Number representing the value of the metric.
+    pub value: f64,
+
+    /// Source of the value using exchange curated string names.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vendor: Option<String>,
+
+    /// Placeholder for exchange-specific extensions to OpenRTB.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ext: Option<Ext>,
+}
@@ -0,0 +1,41 @@
+//! OpenRTB 3.0 Specification Objects
+//!
+//! This module contains the transport layer objects defined in the OpenRTB 3.0 specification.
+//! The domain objects they carry are the AdCOM 1.0 objects in [`crate::adcom::v1_0`].
+
+// Root object
+pub mod openrtb;
+
+// Bid Request objects
+pub mod deal;
+pub mod item;
+pub mod metric;
+pub mod request;
+pub mod source;
+pub mod spec;
+
+// Bid Response objects
+pub mod bid;
+pub mod macros;
+pub mod media;
+pub mod response;
+pub mod seatbid;
+
+// Conversions from and to OpenRTB 2.6
+pub mod bridge;
+mod countries;
+
+// Re-export all structs for easy access
+pub use bid::*;
+pub use bridge::*;
+pub use deal::*;
+pub use item::*;
+pub use macros::*;
+pub use media::*;
+pub use metric::*;
+pub use openrtb::*;
+pub use request::*;
+pub use response::*;
+pub use seatbid::*;
+pub use source::*;
+pub use spec::*;
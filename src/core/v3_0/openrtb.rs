@@ -0,0 +1,31 @@
+use super::*;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// The top-level object of every OpenRTB 3.0 payload
+///
+/// On the wire it is the value of the `openrtb` key of the JSON document. It carries either a
+/// request or a response together with the version of the domain specification in use.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Openrtb<Ext = Value> {
+    /// Version of the Layer-3 OpenRTB specification (e.g., "3.0").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ver: Option<String>,
+
+    /// Identifier of the Layer-4 domain model used to define items for sale, media associated
+    /// with bids, etc.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub domainspec: Option<String>,
+
+    /// Specification version of the Layer-4 domain model referenced in `domainspec`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub domainver: Option<String>,
+
+    /// Bid request container.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request: Option<Request<Ext>>,
+
+    /// Bid response container.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response: Option<Response<Ext>>,
+}
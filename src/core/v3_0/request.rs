@@ -0,0 +1,66 @@
+use super::*;
+use crate::adcom::v1_0::Context;
+use crate::core::v2_6::enums::*;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// The request object contains a globally unique bid request ID
+///
+/// At least one `Item` is required. Other attributes establish rules and restrictions that apply
+/// to all items being offered.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Request<Ext = Value> {
+    /// Unique ID of the bid request; provided by the exchange.
+    pub id: String,
+
+    /// Indicator of test mode in which auctions are not billable, where 0 = live mode, 1 = test mode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub test: Option<TestMode>,
+
+    /// Maximum time in milliseconds the exchange allows for bids to be received including Internet
+    /// latency to avoid timeout.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tmax: Option<u32>,
+
+    /// Auction type, where 1 = First Price, 2 = Second Price Plus.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub at: Option<AuctionType>,
+
+    /// Array of accepted currencies for bids on this bid request using ISO-4217 alpha codes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cur: Option<Vec<String>>,
+
+    /// Restriction list of buyer seats for bidding on this item.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seat: Option<Vec<String>>,
+
+    /// Flag that determines the restriction interpretation of the `seat` array, where 0 = block list, 1
+    /// = allowed list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wseat: Option<u32>,
+
+    /// Allows bidder to retrieve data set on its behalf in the exchange's cookie.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cdata: Option<String>,
+
+    /// A `Source` object that provides data about the inventory source and which entity makes the final
+    /// decision.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<Source>,
+
+    /// Array of `Item` objects representing the items being offered.
+    pub item: Vec<Item>,
+
+    /// Flag to indicate if the exchange can verify that the items offered represent all of the items
+    /// available in context, where 0 = no, 1 = yes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub package: Option<u32>,
+
+    /// Layer-4 domain object structure that provides context for the items being offered.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<Context>,
+
+    /// Placeholder for exchange-specific extensions to OpenRTB.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ext: Option<Ext>,
+}
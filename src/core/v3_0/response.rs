@@ -0,0 +1,34 @@
+use super::*;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// This object is the bid response object under the `Openrtb` root
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Response<Ext = Value> {
+    /// ID of the bid request to which this is a response.
+    pub id: String,
+
+    /// Bidder generated response ID to assist with logging/tracking.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bidid: Option<String>,
+
+    /// Reason for not bidding if applicable.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nbr: Option<u32>,
+
+    /// Bid currency using ISO-4217 alpha codes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cur: Option<String>,
+
+    /// Allows bidder to set data in the exchange's cookie.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cdata: Option<String>,
+
+    /// Array of `Seatbid` objects; 1+ required if a bid is to be made.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seatbid: Option<Vec<Seatbid>>,
+
+    /// Placeholder for exchange-specific extensions to OpenRTB.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ext: Option<Ext>,
+}
@@ -0,0 +1,24 @@
+use super::*;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// A bid response can contain multiple `Seatbid` objects, each on behalf of a different buyer seat.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Seatbid<Ext = Value> {
+    /// ID of the buyer seat on whose behalf this bid is made.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seat: Option<String>,
+
+    /// For offers with multiple items, this flag indicates if the bidder is willing to accept wins on a
+    /// subset of bids or requires the full group as a package, where 0 = individual wins accepted, 1 =
+    /// package win or loss only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub package: Option<u32>,
+
+    /// Array of 1+ `Bid` objects each related to an item.
+    pub bid: Vec<Bid>,
+
+    /// Placeholder for exchange-specific extensions to OpenRTB.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ext: Option<Ext>,
+}
@@ -0,0 +1,46 @@
+use crate::core::v2_6::SupplyChain;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// This object carries data about the source of the transaction including the unique ID of the
+/// transaction itself, source authentication information, and the chain of custody.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Source<Ext = Value> {
+    /// Transaction ID that must be common across all participants throughout the entire supply chain of
+    /// the transaction.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tid: Option<String>,
+
+    /// Timestamp when the request originated at the beginning of the supply chain in Unix format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<u64>,
+
+    /// Digital signature used to authenticate the origin of this request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ds: Option<String>,
+
+    /// An ordered list of identifiers that indicates the attributes used to create the digital
+    /// signature.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dsmap: Option<String>,
+
+    /// File name of the certificate used to create the digital signature.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cert: Option<String>,
+
+    /// The full digest string that was signed to produce the digital signature.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub digest: Option<String>,
+
+    /// Payment ID chain string containing embedded syntax described in the TAG Payment ID Protocol.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pchain: Option<String>,
+
+    /// The supply chain of the transaction.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schain: Option<SupplyChain>,
+
+    /// Placeholder for exchange-specific extensions to OpenRTB.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ext: Option<Ext>,
+}
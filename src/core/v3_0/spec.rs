@@ -0,0 +1,10 @@
+use crate::adcom::v1_0::Placement;
+use serde::{Deserialize, Serialize};
+
+/// AdCOM specification of an offered item
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Spec {
+    /// The placement being offered.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placement: Option<Placement>,
+}
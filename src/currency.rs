@@ -0,0 +1,521 @@
+//! ISO-4217 currencies and conversion between them
+//!
+//! OpenRTB and VAST carry currencies as free-form codes. [`Currency`] validates and
+//! normalizes them, and a [`RateTable`] converts amounts between currencies with the
+//! [`Rounding`] suited to floors and bid prices.
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+/// Errors raised when parsing currencies or converting between them
+#[derive(Debug, Clone, PartialEq)]
+pub enum CurrencyError {
+    /// The code is not an ISO-4217 alphabetic code
+    UnknownCurrency(String),
+    /// The rate table has no rate for the currency
+    MissingRate(Currency),
+    /// The rate table holds a rate that is not a positive finite number
+    InvalidRate(Currency, f64),
+    /// The bid currency is not among the currencies allowed by the request
+    NotAllowed(Currency),
+    /// The rate table could not be parsed
+    InvalidRateTable(String),
+}
+
+impl fmt::Display for CurrencyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CurrencyError::UnknownCurrency(code) => write!(f, "unknown currency code: {code}"),
+            CurrencyError::MissingRate(currency) => write!(f, "no exchange rate for {currency}"),
+            CurrencyError::InvalidRate(currency, rate) => {
+                write!(f, "invalid exchange rate for {currency}: {rate}")
+            }
+            CurrencyError::NotAllowed(currency) => {
+                write!(f, "currency {currency} is not allowed by the request")
+            }
+            CurrencyError::InvalidRateTable(message) => {
+                write!(f, "invalid rate table: {message}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CurrencyError {}
+
+/// An ISO-4217 currency
+///
+/// Parsing accepts codes in any case and surrounding whitespace, and serializes the
+/// canonical upper case code.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Currency([u8; 3]);
+
+impl Currency {
+    /// US Dollar, the OpenRTB default currency
+    pub const USD: Currency = Currency(*b"USD");
+    /// Euro
+    pub const EUR: Currency = Currency(*b"EUR");
+    /// Pound Sterling
+    pub const GBP: Currency = Currency(*b"GBP");
+    /// Yen
+    pub const JPY: Currency = Currency(*b"JPY");
+    /// Canadian Dollar
+    pub const CAD: Currency = Currency(*b"CAD");
+    /// Australian Dollar
+    pub const AUD: Currency = Currency(*b"AUD");
+    /// Swiss Franc
+    pub const CHF: Currency = Currency(*b"CHF");
+    /// Yuan Renminbi
+    pub const CNY: Currency = Currency(*b"CNY");
+    /// Indian Rupee
+    pub const INR: Currency = Currency(*b"INR");
+    /// Brazilian Real
+    pub const BRL: Currency = Currency(*b"BRL");
+
+    /// Parse an ISO-4217 alphabetic code
+    pub fn new(code: &str) -> Result<Self, CurrencyError> {
+        let normalized = code.trim().to_ascii_uppercase();
+        CURRENCIES
+            .binary_search_by(|(alpha, ..)| alpha.cmp(&normalized.as_str()))
+            .map(|index| {
+                let alpha = CURRENCIES[index].0.as_bytes();
+                Currency([alpha[0], alpha[1], alpha[2]])
+            })
+            .map_err(|_| CurrencyError::UnknownCurrency(code.to_string()))
+    }
+
+    fn entry(&self) -> &'static (&'static str, u16, Option<u32>, &'static str) {
+        let index = CURRENCIES
+            .binary_search_by(|(alpha, ..)| alpha.as_bytes().cmp(&self.0[..]))
+            .unwrap_or_default();
+        &CURRENCIES[index]
+    }
+
+    /// Alphabetic code
+    pub fn code(&self) -> &'static str {
+        self.entry().0
+    }
+
+    /// Numeric code
+    pub fn numeric(&self) -> u16 {
+        self.entry().1
+    }
+
+    /// Number of decimals of the minor unit, if the currency has one
+    pub fn minor_units(&self) -> Option<u32> {
+        self.entry().2
+    }
+
+    /// English name
+    pub fn name(&self) -> &'static str {
+        self.entry().3
+    }
+
+    /// Round `amount` up to the precision of `rounding`
+    ///
+    /// Used for floors, which must not drop below the converted amount.
+    pub fn round_up(&self, amount: f64, rounding: Rounding) -> f64 {
+        round(amount, rounding.decimals(*self), f64::ceil)
+    }
+
+    /// Round `amount` down to the precision of `rounding`
+    ///
+    /// Used for bid prices, which must not exceed the converted amount.
+    pub fn round_down(&self, amount: f64, rounding: Rounding) -> f64 {
+        round(amount, rounding.decimals(*self), f64::floor)
+    }
+}
+
+impl Default for Currency {
+    fn default() -> Self {
+        Currency::USD
+    }
+}
+
+impl fmt::Debug for Currency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Currency({})", self.code())
+    }
+}
+
+impl fmt::Display for Currency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+impl FromStr for Currency {
+    type Err = CurrencyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Currency::new(s)
+    }
+}
+
+impl Serialize for Currency {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.code())
+    }
+}
+
+impl<'de> Deserialize<'de> for Currency {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let code = String::deserialize(deserializer)?;
+        Currency::new(&code).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Precision amounts are rounded to after conversion
+///
+/// CPMs in currencies with few minor units, or converted from micro-currency amounts,
+/// can lose their value when rounded to the minor unit; `Micros` keeps six decimals as
+/// used by micro-currency price representations.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Rounding {
+    /// Keep the converted amount unrounded
+    Exact,
+    /// Round to the minor unit of the currency, two decimals when it has none
+    #[default]
+    MinorUnits,
+    /// Round to millionths of the currency unit
+    Micros,
+}
+
+impl Rounding {
+    fn decimals(self, currency: Currency) -> Option<u32> {
+        match self {
+            Rounding::Exact => None,
+            Rounding::MinorUnits => Some(currency.minor_units().unwrap_or(2)),
+            Rounding::Micros => Some(6),
+        }
+    }
+}
+
+/// Round to `decimals` places with `direction`, ignoring floating point noise
+fn round(amount: f64, decimals: Option<u32>, direction: fn(f64) -> f64) -> f64 {
+    let Some(decimals) = decimals else {
+        return amount;
+    };
+    let scale = 10f64.powi(decimals as i32);
+    let scaled = amount * scale;
+    let nearest = scaled.round();
+    if (scaled - nearest).abs() < 1e-6 {
+        nearest / scale
+    } else {
+        direction(scaled) / scale
+    }
+}
+
+/// Exchange rates against a base currency
+///
+/// Each rate is the amount of the currency worth one unit of the base currency. Tables
+/// deserialize from JSON such as `{"base": "USD", "rates": {"EUR": 0.92, "JPY": 151.4}}`,
+/// so they can be loaded from a local file.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RateTable {
+    /// Currency the rates are quoted against
+    pub base: Currency,
+
+    /// Amount of each currency worth one unit of the base currency
+    pub rates: HashMap<Currency, f64>,
+}
+
+impl RateTable {
+    /// Create an empty table quoted against `base`
+    pub fn new(base: Currency) -> Self {
+        RateTable {
+            base,
+            rates: HashMap::new(),
+        }
+    }
+
+    /// Add the rate of `currency` against the base currency
+    pub fn with_rate(mut self, currency: Currency, rate: f64) -> Self {
+        self.rates.insert(currency, rate);
+        self
+    }
+
+    /// Parse a table from JSON
+    pub fn from_json(json: &str) -> Result<Self, CurrencyError> {
+        serde_json::from_str(json).map_err(|e| CurrencyError::InvalidRateTable(e.to_string()))
+    }
+
+    /// Amount of `currency` worth one unit of the base currency
+    pub fn rate(&self, currency: Currency) -> Result<f64, CurrencyError> {
+        if currency == self.base {
+            return Ok(1.0);
+        }
+        match self.rates.get(&currency) {
+            Some(rate) if rate.is_finite() && *rate > 0.0 => Ok(*rate),
+            Some(rate) => Err(CurrencyError::InvalidRate(currency, *rate)),
+            None => Err(CurrencyError::MissingRate(currency)),
+        }
+    }
+
+    /// Convert `amount` from one currency to another without rounding
+    pub fn convert(&self, amount: f64, from: Currency, to: Currency) -> Result<f64, CurrencyError> {
+        if from == to {
+            return Ok(amount);
+        }
+        Ok(amount / self.rate(from)? * self.rate(to)?)
+    }
+}
+
+/// ISO-4217 currencies as alphabetic code, numeric code, minor unit decimals and name,
+/// sorted by alphabetic code
+const CURRENCIES: &[(&str, u16, Option<u32>, &str)] = &[
+    ("AED", 784, Some(2), "UAE Dirham"),
+    ("AFN", 971, Some(2), "Afghani"),
+    ("ALL", 8, Some(2), "Lek"),
+    ("AMD", 51, Some(2), "Armenian Dram"),
+    ("ANG", 532, Some(2), "Netherlands Antillean Guilder"),
+    ("AOA", 973, Some(2), "Kwanza"),
+    ("ARS", 32, Some(2), "Argentine Peso"),
+    ("AUD", 36, Some(2), "Australian Dollar"),
+    ("AWG", 533, Some(2), "Aruban Florin"),
+    ("AZN", 944, Some(2), "Azerbaijan Manat"),
+    ("BAM", 977, Some(2), "Convertible Mark"),
+    ("BBD", 52, Some(2), "Barbados Dollar"),
+    ("BDT", 50, Some(2), "Taka"),
+    ("BGN", 975, Some(2), "Bulgarian Lev"),
+    ("BHD", 48, Some(3), "Bahraini Dinar"),
+    ("BIF", 108, Some(0), "Burundi Franc"),
+    ("BMD", 60, Some(2), "Bermudian Dollar"),
+    ("BND", 96, Some(2), "Brunei Dollar"),
+    ("BOB", 68, Some(2), "Boliviano"),
+    ("BOV", 984, Some(2), "Mvdol"),
+    ("BRL", 986, Some(2), "Brazilian Real"),
+    ("BSD", 44, Some(2), "Bahamian Dollar"),
+    ("BTN", 64, Some(2), "Ngultrum"),
+    ("BWP", 72, Some(2), "Pula"),
+    ("BYN", 933, Some(2), "Belarusian Ruble"),
+    ("BZD", 84, Some(2), "Belize Dollar"),
+    ("CAD", 124, Some(2), "Canadian Dollar"),
+    ("CDF", 976, Some(2), "Congolese Franc"),
+    ("CHE", 947, Some(2), "WIR Euro"),
+    ("CHF", 756, Some(2), "Swiss Franc"),
+    ("CHW", 948, Some(2), "WIR Franc"),
+    ("CLF", 990, Some(4), "Unidad de Fomento"),
+    ("CLP", 152, Some(0), "Chilean Peso"),
+    ("CNY", 156, Some(2), "Yuan Renminbi"),
+    ("COP", 170, Some(2), "Colombian Peso"),
+    ("COU", 970, Some(2), "Unidad de Valor Real"),
+    ("CRC", 188, Some(2), "Costa Rican Colon"),
+    ("CUC", 931, Some(2), "Peso Convertible"),
+    ("CUP", 192, Some(2), "Cuban Peso"),
+    ("CVE", 132, Some(2), "Cabo Verde Escudo"),
+    ("CZK", 203, Some(2), "Czech Koruna"),
+    ("DJF", 262, Some(0), "Djibouti Franc"),
+    ("DKK", 208, Some(2), "Danish Krone"),
+    ("DOP", 214, Some(2), "Dominican Peso"),
+    ("DZD", 12, Some(2), "Algerian Dinar"),
+    ("EGP", 818, Some(2), "Egyptian Pound"),
+    ("ERN", 232, Some(2), "Nakfa"),
+    ("ETB", 230, Some(2), "Ethiopian Birr"),
+    ("EUR", 978, Some(2), "Euro"),
+    ("FJD", 242, Some(2), "Fiji Dollar"),
+    ("FKP", 238, Some(2), "Falkland Islands Pound"),
+    ("GBP", 826, Some(2), "Pound Sterling"),
+    ("GEL", 981, Some(2), "Lari"),
+    ("GHS", 936, Some(2), "Ghana Cedi"),
+    ("GIP", 292, Some(2), "Gibraltar Pound"),
+    ("GMD", 270, Some(2), "Dalasi"),
+    ("GNF", 324, Some(0), "Guinean Franc"),
+    ("GTQ", 320, Some(2), "Quetzal"),
+    ("GYD", 328, Some(2), "Guyana Dollar"),
+    ("HKD", 344, Some(2), "Hong Kong Dollar"),
+    ("HNL", 340, Some(2), "Lempira"),
+    ("HRK", 191, Some(2), "Kuna"),
+    ("HTG", 332, Some(2), "Gourde"),
+    ("HUF", 348, Some(2), "Forint"),
+    ("IDR", 360, Some(2), "Rupiah"),
+    ("ILS", 376, Some(2), "New Israeli Sheqel"),
+    ("INR", 356, Some(2), "Indian Rupee"),
+    ("IQD", 368, Some(3), "Iraqi Dinar"),
+    ("IRR", 364, Some(2), "Iranian Rial"),
+    ("ISK", 352, Some(0), "Iceland Krona"),
+    ("JMD", 388, Some(2), "Jamaican Dollar"),
+    ("JOD", 400, Some(3), "Jordanian Dinar"),
+    ("JPY", 392, Some(0), "Yen"),
+    ("KES", 404, Some(2), "Kenyan Shilling"),
+    ("KGS", 417, Some(2), "Som"),
+    ("KHR", 116, Some(2), "Riel"),
+    ("KMF", 174, Some(0), "Comorian Franc"),
+    ("KPW", 408, Some(2), "North Korean Won"),
+    ("KRW", 410, Some(0), "Won"),
+    ("KWD", 414, Some(3), "Kuwaiti Dinar"),
+    ("KYD", 136, Some(2), "Cayman Islands Dollar"),
+    ("KZT", 398, Some(2), "Tenge"),
+    ("LAK", 418, Some(2), "Lao Kip"),
+    ("LBP", 422, Some(2), "Lebanese Pound"),
+    ("LKR", 144, Some(2), "Sri Lanka Rupee"),
+    ("LRD", 430, Some(2), "Liberian Dollar"),
+    ("LSL", 426, Some(2), "Loti"),
+    ("LYD", 434, Some(3), "Libyan Dinar"),
+    ("MAD", 504, Some(2), "Moroccan Dirham"),
+    ("MDL", 498, Some(2), "Moldovan Leu"),
+    ("MGA", 969, Some(2), "Malagasy Ariary"),
+    ("MKD", 807, Some(2), "Denar"),
+    ("MMK", 104, Some(2), "Kyat"),
+    ("MNT", 496, Some(2), "Tugrik"),
+    ("MOP", 446, Some(2), "Pataca"),
+    ("MRU", 929, Some(2), "Ouguiya"),
+    ("MUR", 480, Some(2), "Mauritius Rupee"),
+    ("MVR", 462, Some(2), "Rufiyaa"),
+    ("MWK", 454, Some(2), "Malawi Kwacha"),
+    ("MXN", 484, Some(2), "Mexican Peso"),
+    ("MXV", 979, Some(2), "Mexican Unidad de Inversion (UDI)"),
+    ("MYR", 458, Some(2), "Malaysian Ringgit"),
+    ("MZN", 943, Some(2), "Mozambique Metical"),
+    ("NAD", 516, Some(2), "Namibia Dollar"),
+    ("NGN", 566, Some(2), "Naira"),
+    ("NIO", 558, Some(2), "Cordoba Oro"),
+    ("NOK", 578, Some(2), "Norwegian Krone"),
+    ("NPR", 524, Some(2), "Nepalese Rupee"),
+    ("NZD", 554, Some(2), "New Zealand Dollar"),
+    ("OMR", 512, Some(3), "Rial Omani"),
+    ("PAB", 590, Some(2), "Balboa"),
+    ("PEN", 604, Some(2), "Sol"),
+    ("PGK", 598, Some(2), "Kina"),
+    ("PHP", 608, Some(2), "Philippine Peso"),
+    ("PKR", 586, Some(2), "Pakistan Rupee"),
+    ("PLN", 985, Some(2), "Zloty"),
+    ("PYG", 600, Some(0), "Guarani"),
+    ("QAR", 634, Some(2), "Qatari Rial"),
+    ("RON", 946, Some(2), "Romanian Leu"),
+    ("RSD", 941, Some(2), "Serbian Dinar"),
+    ("RUB", 643, Some(2), "Russian Ruble"),
+    ("RWF", 646, Some(0), "Rwanda Franc"),
+    ("SAR", 682, Some(2), "Saudi Riyal"),
+    ("SBD", 90, Some(2), "Solomon Islands Dollar"),
+    ("SCR", 690, Some(2), "Seychelles Rupee"),
+    ("SDG", 938, Some(2), "Sudanese Pound"),
+    ("SEK", 752, Some(2), "Swedish Krona"),
+    ("SGD", 702, Some(2), "Singapore Dollar"),
+    ("SHP", 654, Some(2), "Saint Helena Pound"),
+    ("SLE", 925, Some(2), "Leone"),
+    ("SLL", 694, Some(2), "Leone"),
+    ("SOS", 706, Some(2), "Somali Shilling"),
+    ("SRD", 968, Some(2), "Surinam Dollar"),
+    ("SSP", 728, Some(2), "South Sudanese Pound"),
+    ("STN", 930, Some(2), "Dobra"),
+    ("SVC", 222, Some(2), "El Salvador Colon"),
+    ("SYP", 760, Some(2), "Syrian Pound"),
+    ("SZL", 748, Some(2), "Lilangeni"),
+    ("THB", 764, Some(2), "Baht"),
+    ("TJS", 972, Some(2), "Somoni"),
+    ("TMT", 934, Some(2), "Turkmenistan New Manat"),
+    ("TND", 788, Some(3), "Tunisian Dinar"),
+    ("TOP", 776, Some(2), "Pa’anga"),
+    ("TRY", 949, Some(2), "Turkish Lira"),
+    ("TTD", 780, Some(2), "Trinidad and Tobago Dollar"),
+    ("TWD", 901, Some(2), "New Taiwan Dollar"),
+    ("TZS", 834, Some(2), "Tanzanian Shilling"),
+    ("UAH", 980, Some(2), "Hryvnia"),
+    ("UGX", 800, Some(0), "Uganda Shilling"),
+    ("USD", 840, Some(2), "US Dollar"),
+    ("USN", 997, Some(2), "US Dollar (Next day)"),
+    (
+        "UYI",
+        940,
+        Some(0),
+        "Uruguay Peso en Unidades Indexadas (UI)",
+    ),
+    ("UYU", 858, Some(2), "Peso Uruguayo"),
+    ("UYW", 927, Some(4), "Unidad Previsional"),
+    ("UZS", 860, Some(2), "Uzbekistan Sum"),
+    ("VED", 926, Some(2), "Bolívar Soberano"),
+    ("VES", 928, Some(2), "Bolívar Soberano"),
+    ("VND", 704, Some(0), "Dong"),
+    ("VUV", 548, Some(0), "Vatu"),
+    ("WST", 882, Some(2), "Tala"),
+    ("XAF", 950, Some(0), "CFA Franc BEAC"),
+    ("XAG", 961, None, "Silver"),
+    ("XAU", 959, None, "Gold"),
+    (
+        "XBA",
+        955,
+        None,
+        "Bond Markets Unit European Composite Unit (EURCO)",
+    ),
+    (
+        "XBB",
+        956,
+        None,
+        "Bond Markets Unit European Monetary Unit (E.M.U.-6)",
+    ),
+    (
+        "XBC",
+        957,
+        None,
+        "Bond Markets Unit European Unit of Account 9 (E.U.A.-9)",
+    ),
+    (
+        "XBD",
+        958,
+        None,
+        "Bond Markets Unit European Unit of Account 17 (E.U.A.-17)",
+    ),
+    ("XCD", 951, Some(2), "East Caribbean Dollar"),
+    ("XDR", 960, None, "SDR (Special Drawing Right)"),
+    ("XOF", 952, Some(0), "CFA Franc BCEAO"),
+    ("XPD", 964, None, "Palladium"),
+    ("XPF", 953, Some(0), "CFP Franc"),
+    ("XPT", 962, None, "Platinum"),
+    ("XSU", 994, None, "Sucre"),
+    (
+        "XTS",
+        963,
+        None,
+        "Codes specifically reserved for testing purposes",
+    ),
+    ("XUA", 965, None, "ADB Unit of Account"),
+    (
+        "XXX",
+        999,
+        None,
+        "The codes assigned for transactions where no currency is involved",
+    ),
+    ("YER", 886, Some(2), "Yemeni Rial"),
+    ("ZAR", 710, Some(2), "Rand"),
+    ("ZMW", 967, Some(2), "Zambian Kwacha"),
+    ("ZWL", 932, Some(2), "Zimbabwe Dollar"),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_currency_conversion() {
+        assert_eq!(" eur ".parse::<Currency>().unwrap(), Currency::EUR);
+        assert_eq!(Currency::JPY.minor_units(), Some(0));
+        assert_eq!(Currency::new("KWD").unwrap().numeric(), 414);
+        assert!(Currency::new("ABC").is_err());
+        assert_eq!(serde_json::to_string(&Currency::GBP).unwrap(), "\"GBP\"");
+
+        let rates =
+            RateTable::from_json(r#"{"base": "USD", "rates": {"EUR": 0.8, "JPY": 150}}"#).unwrap();
+        assert_eq!(
+            rates.convert(2.0, Currency::USD, Currency::EUR).unwrap(),
+            1.6
+        );
+        let yen = rates.convert(1.5, Currency::EUR, Currency::JPY).unwrap();
+        assert_eq!(Currency::JPY.round_up(yen, Rounding::MinorUnits), 282.0);
+        assert_eq!(Currency::JPY.round_down(yen, Rounding::MinorUnits), 281.0);
+        assert_eq!(Currency::USD.round_up(0.0004, Rounding::MinorUnits), 0.01);
+        assert_eq!(Currency::USD.round_down(0.0004, Rounding::Micros), 0.0004);
+        assert_eq!(Currency::USD.round_up(1.1, Rounding::MinorUnits), 1.1);
+        assert_eq!(
+            rates.convert(1.0, Currency::GBP, Currency::USD),
+            Err(CurrencyError::MissingRate(Currency::GBP))
+        );
+    }
+}
@@ -1,7 +1,13 @@
+pub mod adcom;
+pub mod builder;
 pub mod core;
+pub mod currency;
 
 #[cfg(feature = "native")]
 pub mod native;
 
 #[cfg(feature = "vast")]
 pub mod vast;
+
+#[cfg(feature = "vmap")]
+pub mod vmap;
@@ -0,0 +1,287 @@
+//! Typed builders for the Native 1.2 objects
+//!
+//! `X::builder` takes the required fields of `X`; optional fields are set through the
+//! returned builder.
+
+use super::*;
+use crate::builder::builder;
+
+builder! {
+    NativeRequest<Ext> => NativeRequestBuilder {
+        required {
+            assets: Vec<AssetRequest>,
+        }
+        optional {
+            ver: String = request::default_ver(),
+            context: ContextType,
+            contextsubtype: ContextSubType,
+            plcmttype: PlacementType,
+            plcmtcnt: u32 = request::default_plcmtcnt(),
+            seq: u32 = request::default_seq(),
+            aurlsupport: u32 = request::default_aurlsupport(),
+            durlsupport: u32 = request::default_durlsupport(),
+            eventtrackers: Vec<EventTrackersRequest>,
+            privacy: u32 = request::default_privacy(),
+            ext: Ext,
+        }
+    }
+}
+
+builder! {
+    AssetRequest<Ext> => AssetRequestBuilder {
+        required {
+            id: u32,
+        }
+        optional {
+            required: u32 = request::default_required(),
+            title: TitleRequest,
+            img: ImageRequest,
+            video: VideoRequest,
+            data: DataRequest,
+            ext: Ext,
+        }
+    }
+}
+
+builder! {
+    TitleRequest<Ext> => TitleRequestBuilder {
+        required {
+            len: u32,
+        }
+        optional {
+            ext: Ext,
+        }
+    }
+}
+
+builder! {
+    ImageRequest<Ext> => ImageRequestBuilder {
+        required {}
+        optional {
+            img_type: ImageAssetType,
+            w: u32,
+            wmin: u32,
+            h: u32,
+            hmin: u32,
+            mimes: Vec<String>,
+            ext: Ext,
+        }
+    }
+}
+
+builder! {
+    VideoRequest<Ext> => VideoRequestBuilder {
+        required {
+            mimes: Vec<String>,
+            minduration: u32,
+            maxduration: u32,
+            protocols: Vec<u32>,
+        }
+        optional {
+            ext: Ext,
+        }
+    }
+}
+
+builder! {
+    DataRequest<Ext> => DataRequestBuilder {
+        required {
+            data_type: DataAssetType,
+        }
+        optional {
+            len: u32,
+            ext: Ext,
+        }
+    }
+}
+
+builder! {
+    EventTrackersRequest<Ext> => EventTrackersRequestBuilder {
+        required {
+            event: EventType,
+            methods: Vec<EventTrackingMethod>,
+        }
+        optional {
+            ext: Ext,
+        }
+    }
+}
+
+builder! {
+    NativeResponse<Ext> => NativeResponseBuilder {
+        required {
+            link: LinkResponse<Ext>,
+        }
+        optional {
+            ver: String = response::default_ver(),
+            assets: Vec<AssetResponse>,
+            assetsurl: String,
+            dcourl: String,
+            imptrackers: Vec<String>,
+            jstracker: String,
+            eventtrackers: Vec<EventTrackerResponse>,
+            privacy: String,
+            ext: Ext,
+        }
+    }
+}
+
+builder! {
+    AssetResponse<Ext> => AssetResponseBuilder {
+        required {}
+        optional {
+            id: u32,
+            required: u32 = response::default_required(),
+            title: TitleResponse,
+            img: ImageResponse,
+            video: VideoResponse,
+            data: DataResponse,
+            link: LinkResponse<Ext>,
+            ext: Ext,
+        }
+    }
+}
+
+builder! {
+    TitleResponse<Ext> => TitleResponseBuilder {
+        required {
+            text: String,
+        }
+        optional {
+            len: u32,
+            ext: Ext,
+        }
+    }
+}
+
+builder! {
+    ImageResponse<Ext> => ImageResponseBuilder {
+        required {
+            url: String,
+        }
+        optional {
+            img_type: ImageAssetType,
+            w: u32,
+            h: u32,
+            ext: Ext,
+        }
+    }
+}
+
+builder! {
+    DataResponse<Ext> => DataResponseBuilder {
+        required {
+            value: String,
+        }
+        optional {
+            data_type: DataAssetType,
+            len: u32,
+            ext: Ext,
+        }
+    }
+}
+
+builder! {
+    VideoResponse => VideoResponseBuilder {
+        required {
+            vasttag: String,
+        }
+        optional {}
+    }
+}
+
+builder! {
+    LinkResponse<Ext> => LinkResponseBuilder {
+        required {
+            url: String,
+        }
+        optional {
+            clicktrackers: Vec<String>,
+            fallback: String,
+            ext: Ext,
+        }
+    }
+}
+
+builder! {
+    EventTrackerResponse<CustomData, Ext> => EventTrackerResponseBuilder {
+        required {
+            event: EventType,
+            method: EventTrackingMethod,
+        }
+        optional {
+            url: String,
+            customdata: CustomData,
+            ext: Ext,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builders() {
+        let request: NativeRequest = NativeRequest::builder([
+            AssetRequest::builder(1)
+                .title(TitleRequest::builder(90).build())
+                .build(),
+            AssetRequest::builder(2)
+                .img(
+                    ImageRequest::builder()
+                        .img_type(ImageAssetType::Main)
+                        .wmin(300)
+                        .hmin(250)
+                        .build(),
+                )
+                .build(),
+            AssetRequest::builder(3)
+                .required(0)
+                .data(DataRequest::builder(DataAssetType::Sponsored).build())
+                .build(),
+        ])
+        .context(ContextType::Content)
+        .eventtrackers([EventTrackersRequest::builder(
+            EventType::Impression,
+            [EventTrackingMethod::Img],
+        )
+        .build()])
+        .build();
+
+        assert_eq!(request.ver.as_deref(), Some("1.2"));
+        assert_eq!(request.assets[0].required, Some(0));
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(
+            serde_json::from_value::<NativeRequest>(json).unwrap(),
+            request
+        );
+
+        let response: NativeResponse =
+            NativeResponse::builder(LinkResponse::builder("https://example.com/landing").build())
+                .assets([
+                    AssetResponse::builder()
+                        .id(1)
+                        .title(TitleResponse::builder("Title").build())
+                        .build(),
+                    AssetResponse::builder()
+                        .id(3)
+                        .data(DataResponse::builder("Sponsor").build())
+                        .build(),
+                ])
+                .eventtrackers([EventTrackerResponse::builder(
+                    EventType::Impression,
+                    EventTrackingMethod::Img,
+                )
+                .url("https://example.com/imp")
+                .build()])
+                .build();
+
+        let json = serde_json::to_value(&response).unwrap();
+        assert_eq!(json["link"]["url"], "https://example.com/landing");
+        assert_eq!(
+            serde_json::from_value::<NativeResponse>(json).unwrap(),
+            response
+        );
+    }
+}
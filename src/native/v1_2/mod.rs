@@ -4,11 +4,13 @@
 //! The specification defines a sub-protocol of OpenRTB to allow for the delivery of native
 //! advertising formats.
 
+pub mod builder;
 pub mod enums;
 pub mod request;
 pub mod response;
 
 // Re-export all types for easy access
+pub use builder::*;
 pub use enums::*;
 pub use request::*;
 pub use response::*;
@@ -233,31 +233,31 @@ pub struct EventTrackersRequest<Ext = Value> {
 }
 
 // Default functions
-fn default_ver() -> Option<String> {
+pub(super) fn default_ver() -> Option<String> {
     Some("1.2".to_string())
 }
 
-fn default_plcmtcnt() -> Option<u32> {
+pub(super) fn default_plcmtcnt() -> Option<u32> {
     Some(1)
 }
 
-fn default_seq() -> Option<u32> {
+pub(super) fn default_seq() -> Option<u32> {
     Some(0)
 }
 
-fn default_aurlsupport() -> Option<u32> {
+pub(super) fn default_aurlsupport() -> Option<u32> {
     Some(0)
 }
 
-fn default_durlsupport() -> Option<u32> {
+pub(super) fn default_durlsupport() -> Option<u32> {
     Some(0)
 }
 
-fn default_privacy() -> Option<u32> {
+pub(super) fn default_privacy() -> Option<u32> {
     Some(0)
 }
 
-fn default_required() -> Option<u32> {
+pub(super) fn default_required() -> Option<u32> {
     Some(0)
 }
 
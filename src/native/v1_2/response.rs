@@ -248,10 +248,10 @@ pub struct EventTrackerResponse<CustomData = Value, Ext = Value> {
 }
 
 // Default functions
-fn default_ver() -> Option<String> {
+pub(super) fn default_ver() -> Option<String> {
     Some("1.2".to_string())
 }
 
-fn default_required() -> Option<u32> {
+pub(super) fn default_required() -> Option<u32> {
     Some(0)
 }
@@ -23,6 +23,10 @@ pub struct Ad {
     #[serde(rename = "@conditionalAd", skip_serializing_if = "Option::is_none")]
     pub conditional_ad: Option<bool>,
 
+    /// Type of media carried by the ad; video when absent
+    #[serde(rename = "@adType", skip_serializing_if = "Option::is_none")]
+    pub ad_type: Option<AdType>,
+
     /// InLine ad content
     #[serde(rename = "InLine", skip_serializing_if = "Option::is_none")]
     pub inline: Option<InLine>,
@@ -39,6 +43,7 @@ impl Ad {
             id,
             sequence: None,
             conditional_ad: None,
+            ad_type: None,
             inline: Some(inline),
             wrapper: None,
         }
@@ -50,6 +55,7 @@ impl Ad {
             id,
             sequence: None,
             conditional_ad: None,
+            ad_type: None,
             inline: None,
             wrapper: Some(wrapper),
         }
@@ -64,5 +70,10 @@ impl Ad {
     pub fn is_wrapper(&self) -> bool {
         self.wrapper.is_some()
     }
+
+    /// Check if this is an audio-only ad
+    pub fn is_audio(&self) -> bool {
+        self.ad_type == Some(AdType::Audio)
+    }
 }
 
@@ -0,0 +1,199 @@
+//! Audio ads and their matching against OpenRTB audio impressions
+//!
+//! VAST 4.1+ carries audio through `adType="audio"` and audio-only media files, which
+//! have no pixel dimensions.
+
+use super::*;
+use crate::core::v2_6::Audio;
+
+fn is_audio_mime(mime: &str) -> bool {
+    mime.trim()
+        .get(..6)
+        .is_some_and(|prefix| prefix.eq_ignore_ascii_case("audio/"))
+}
+
+impl MediaFile {
+    /// Whether the MIME type of the file is an audio type
+    pub fn is_audio(&self) -> bool {
+        is_audio_mime(&self.mime_type.0)
+    }
+}
+
+impl Ad {
+    /// Check the media files of the ad against its `adType`
+    ///
+    /// Video renditions must declare `width` and `height`, audio renditions need not.
+    /// An audio ad may only carry audio renditions.
+    pub fn validate(&self) -> Result<(), VastError> {
+        let creatives = self
+            .inline
+            .iter()
+            .flat_map(|inline| &inline.creatives.creative)
+            .chain(
+                self.wrapper
+                    .iter()
+                    .filter_map(|wrapper| wrapper.creatives.as_ref())
+                    .flat_map(|creatives| &creatives.creative),
+            );
+
+        for media_file in creatives
+            .filter_map(|creative| creative.linear.as_ref())
+            .flat_map(|linear| &linear.media_files.media_file)
+        {
+            if media_file.is_audio() {
+                continue;
+            }
+            if self.is_audio() {
+                return Err(VastError::InvalidAttribute(
+                    "type".to_string(),
+                    media_file.mime_type.0.clone(),
+                ));
+            }
+            if media_file.width.is_none() {
+                return Err(VastError::MissingAttribute(
+                    "MediaFile".to_string(),
+                    "width".to_string(),
+                ));
+            }
+            if media_file.height.is_none() {
+                return Err(VastError::MissingAttribute(
+                    "MediaFile".to_string(),
+                    "height".to_string(),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Find the first linear creative that can fill an OpenRTB audio impression
+    ///
+    /// Only ads with `adType="audio"` or `adType="hybrid"` match, as an absent `adType`
+    /// means video. The creative duration, including its milliseconds, must lie within the
+    /// impression's `minduration` and `maxduration`, and at least one media file must be
+    /// accepted by [`PlayerProfile::from_audio`], which picks the audio rendition of a
+    /// hybrid ad.
+    pub fn select_audio(&self, audio: &Audio) -> Option<AudioMatch<'_>> {
+        if !matches!(self.ad_type, Some(AdType::Audio | AdType::Hybrid)) {
+            return None;
+        }
+        let profile = PlayerProfile::from_audio(audio);
+
+        self.inline
+            .iter()
+            .flat_map(|inline| &inline.creatives.creative)
+            .find_map(|creative| {
+                let linear = creative.linear.as_ref()?;
+                let duration = linear.duration.to_std().ok()?;
+                let seconds = |limit: u32| std::time::Duration::from_secs(u64::from(limit));
+                if audio.minduration.is_some_and(|min| duration < seconds(min))
+                    || audio.maxduration.is_some_and(|max| duration > seconds(max))
+                {
+                    return None;
+                }
+                let selection = linear.media_files.select(&profile);
+                selection.best()?;
+                Some(AudioMatch {
+                    creative,
+                    duration: u32::try_from(duration.as_secs()).ok()?,
+                    selection,
+                })
+            })
+    }
+}
+
+impl Vast {
+    /// Check the media files of every ad against its `adType`
+    pub fn validate(&self) -> Result<(), VastError> {
+        self.ads.iter().try_for_each(Ad::validate)
+    }
+}
+
+/// A linear creative matching an audio impression
+#[derive(Debug, Clone, PartialEq)]
+pub struct AudioMatch<'a> {
+    /// The matching creative
+    pub creative: &'a Creative,
+
+    /// Duration of the creative in whole seconds
+    pub duration: u32,
+
+    /// Ranked media files of the creative
+    pub selection: MediaSelection<'a>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const VAST: &str = r#"<VAST version="4.3"><Ad id="1" adType="audio"><InLine><AdSystem>Sys</AdSystem><AdTitle>Radio</AdTitle><Impression>https://example.com/imp</Impression><Creatives><Creative><Linear><Duration>00:00:30</Duration><MediaFiles><MediaFile delivery="progressive" type="audio/ogg" bitrate="96">https://example.com/ad.ogg</MediaFile><MediaFile delivery="progressive" type="audio/mpeg" bitrate="128">https://example.com/ad.mp3</MediaFile></MediaFiles></Linear></Creative></Creatives></InLine></Ad></VAST>"#;
+
+    #[test]
+    fn test_audio_ad() {
+        let mut vast: Vast = VAST.parse().unwrap();
+        assert!(vast.ads[0].is_audio());
+        assert!(vast.validate().is_ok());
+
+        let audio: Audio = serde_json::from_value(json!({
+            "mimes": ["audio/mpeg"],
+            "maxduration": 30
+        }))
+        .unwrap();
+        let selected = vast.ads[0].select_audio(&audio).unwrap();
+        assert_eq!(selected.duration, 30);
+        assert_eq!(
+            selected.selection.best().unwrap().uri.as_str(),
+            "https://example.com/ad.mp3"
+        );
+
+        let short: Audio = serde_json::from_value(json!({
+            "mimes": ["audio/mpeg"],
+            "maxduration": 15
+        }))
+        .unwrap();
+        assert!(vast.ads[0].select_audio(&short).is_none());
+
+        let set_duration = |vast: &mut Vast, value: &str| {
+            let creative = &mut vast.ads[0].inline.as_mut().unwrap().creatives.creative[0];
+            creative.linear.as_mut().unwrap().duration = Duration::new(value.to_string());
+        };
+        set_duration(&mut vast, "00:00:30.500");
+        assert!(vast.ads[0].select_audio(&audio).is_none());
+        set_duration(&mut vast, "00:00:30");
+
+        vast.ads[0].ad_type = None;
+        assert!(vast.ads[0].select_audio(&audio).is_none());
+        vast.ads[0].ad_type = Some(AdType::Audio);
+
+        let mut hybrid = vast.clone();
+        hybrid.ads[0].ad_type = Some(AdType::Hybrid);
+        let linear = hybrid.ads[0].inline.as_mut().unwrap().creatives.creative[0]
+            .linear
+            .as_mut()
+            .unwrap();
+        let mut video = linear.media_files.media_file[1].clone();
+        video.mime_type = MimeType("video/mp4".to_string());
+        video.width = Some(640);
+        video.height = Some(360);
+        linear.media_files.media_file.insert(0, video);
+        assert!(hybrid.validate().is_ok());
+        let selected = hybrid.ads[0].select_audio(&audio).unwrap();
+        assert_eq!(selected.selection.best().unwrap().mime_type.0, "audio/mpeg");
+
+        let linear = vast.ads[0].inline.as_mut().unwrap().creatives.creative[0]
+            .linear
+            .as_mut()
+            .unwrap();
+        linear.media_files.media_file[0].mime_type = MimeType("video/mp4".to_string());
+        assert!(matches!(
+            vast.validate(),
+            Err(VastError::InvalidAttribute(_, _))
+        ));
+
+        vast.ads[0].ad_type = None;
+        assert!(matches!(
+            vast.validate(),
+            Err(VastError::MissingAttribute(_, _))
+        ));
+    }
+}
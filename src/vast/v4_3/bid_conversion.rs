@@ -0,0 +1,261 @@
+//! Conversion of winning OpenRTB bids into VAST documents
+
+use super::*;
+use crate::core::v2_6::Bid;
+use quick_xml::escape::escape;
+
+/// Exchange-side settings used when wrapping a bid into a VAST document
+#[derive(Debug, Clone, PartialEq)]
+pub struct BidVastOptions {
+    /// Name of the exchange, used for the `AdSystem` of generated wrappers; defaults to
+    /// the name of this crate, as VAST requires a non-empty `AdSystem`
+    pub ad_system: String,
+
+    /// Exchange impression trackers injected into the ad
+    pub impressions: Vec<Uri>,
+
+    /// Exchange error trackers injected into the ad
+    pub errors: Vec<Uri>,
+
+    /// Currency of the bid price using ISO-4217 alpha codes
+    pub currency: String,
+
+    /// Registry reported in the `UniversalAdId` built from the bid's `crid`
+    pub universal_ad_id_registry: String,
+}
+
+impl Default for BidVastOptions {
+    fn default() -> Self {
+        BidVastOptions {
+            ad_system: env!("CARGO_PKG_NAME").to_string(),
+            impressions: Vec::new(),
+            errors: Vec::new(),
+            currency: "USD".to_string(),
+            universal_ad_id_registry: "unknown".to_string(),
+        }
+    }
+}
+
+impl Vast {
+    /// Build a VAST document for a winning bid
+    ///
+    /// When `adm` contains VAST markup its first ad is embedded and enriched. When
+    /// `adm` is a URL, or is absent and `nurl` is set, a `Wrapper` pointing at that
+    /// URL is generated. In all cases the exchange trackers and the bid's `burl` are
+    /// added as impressions, `Pricing` is set from the bid price, and the bid's `crid`
+    /// and first `adomain` are carried onto the ad. Wrappers have no `Advertiser`
+    /// element, so the advertiser domain is added as an `Advertiser` extension, and the
+    /// `crid` of a wrapper without creatives goes into a `CreativeId` extension.
+    pub fn from_bid(bid: &Bid, options: &BidVastOptions) -> Result<Vast, VastError> {
+        let adm = bid.adm.as_deref().map(str::trim).filter(|s| !s.is_empty());
+        let mut ad = match adm {
+            Some(markup) if markup.starts_with('<') => {
+                let embedded: Vast = markup.parse()?;
+                embedded
+                    .ads
+                    .into_iter()
+                    .next()
+                    .ok_or_else(|| VastError::MissingElement("Ad".to_string()))?
+            }
+            Some(tag) => wrapper_ad(bid, options, tag),
+            None => {
+                let nurl = bid
+                    .nurl
+                    .as_deref()
+                    .ok_or_else(|| VastError::MissingElement("adm".to_string()))?;
+                wrapper_ad(bid, options, nurl)
+            }
+        };
+
+        let pricing = Pricing {
+            model: PricingModel::CPM,
+            currency: options.currency.clone(),
+            value: bid.price,
+        };
+        let mut impressions: Vec<Uri> = options.impressions.clone();
+        impressions.extend(bid.burl.iter().map(|burl| Uri(burl.clone())));
+        let errors = options.errors.iter().cloned();
+
+        if let Some(inline) = ad.inline.as_mut() {
+            inline.impressions.extend(
+                impressions
+                    .into_iter()
+                    .map(|uri| Impression { id: None, uri }),
+            );
+            inline.errors.extend(errors.map(|uri| ErrorElement { uri }));
+            inline.pricing = Some(pricing);
+
+            if inline.advertiser.is_none()
+                && let Some(domain) = bid.adomain.iter().flatten().next()
+            {
+                inline.advertiser = Some(Advertiser {
+                    id: None,
+                    name: domain.clone(),
+                });
+            }
+            identify_creatives(&mut inline.creatives.creative, bid, options);
+        } else if let Some(wrapper) = ad.wrapper.as_mut() {
+            wrapper.impressions.extend(
+                impressions
+                    .into_iter()
+                    .map(|uri| WrapperImpression { id: None, uri }),
+            );
+            wrapper
+                .errors
+                .extend(errors.map(|uri| WrapperError { uri }));
+            wrapper.pricing = Some(pricing);
+
+            if let Some(domain) = bid.adomain.iter().flatten().next() {
+                add_extension(wrapper, "Advertiser", domain);
+            }
+            match wrapper.creatives.as_mut() {
+                Some(creatives) if !creatives.creative.is_empty() => {
+                    identify_creatives(&mut creatives.creative, bid, options)
+                }
+                _ => {
+                    if let Some(crid) = &bid.crid {
+                        add_extension(wrapper, "CreativeId", crid);
+                    }
+                }
+            }
+        }
+
+        let mut vast = Vast::new();
+        vast.add_ad(ad);
+        Ok(vast)
+    }
+}
+
+/// Add an extension of the given type holding `value` unless the wrapper has one
+fn add_extension(wrapper: &mut Wrapper, extension_type: &str, value: &str) {
+    let extensions = wrapper.extensions.get_or_insert_with(Default::default);
+    if extensions.find(extension_type).is_none() {
+        extensions.extension.push(Extension {
+            extension_type: Some(extension_type.to_string()),
+            content: escape(value).into_owned(),
+        });
+    }
+}
+
+/// Set the `AdID` and `UniversalAdId` of creatives lacking them from the bid's `crid`
+fn identify_creatives(creatives: &mut [Creative], bid: &Bid, options: &BidVastOptions) {
+    let Some(crid) = &bid.crid else {
+        return;
+    };
+    for creative in creatives {
+        creative.ad_id.get_or_insert_with(|| crid.clone());
+        creative.universal_ad_id.get_or_insert_with(|| {
+            UniversalAdId::new(options.universal_ad_id_registry.clone(), crid.clone())
+        });
+    }
+}
+
+fn wrapper_ad(bid: &Bid, options: &BidVastOptions, tag: &str) -> Ad {
+    let wrapper = Wrapper {
+        ad_system: WrapperAdSystem {
+            version: None,
+            name: options.ad_system.clone(),
+        },
+        vast_ad_tag_uri: VASTAdTagURI {
+            uri: Uri(tag.to_string()),
+        },
+        ..Default::default()
+    };
+    Ad::new_wrapper(bid.adid.clone().unwrap_or_else(|| bid.id.clone()), wrapper)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const INLINE: &str = r#"<VAST version="4.3"><Ad id="1"><InLine><AdSystem>Sys</AdSystem><AdTitle>Title</AdTitle><Impression>https://example.com/imp</Impression><Creatives><Creative><Linear><Duration>00:00:15</Duration><MediaFiles><MediaFile delivery="progressive" type="video/mp4">https://example.com/ad.mp4</MediaFile></MediaFiles></Linear></Creative></Creatives></InLine></Ad></VAST>"#;
+
+    fn bid(adm: Option<&str>, nurl: Option<&str>) -> Bid {
+        serde_json::from_value(json!({
+            "id": "bid",
+            "impid": "1",
+            "price": 2.5,
+            "adm": adm,
+            "nurl": nurl,
+            "burl": "https://exchange.example.com/bill",
+            "adomain": ["advertiser.com"],
+            "crid": "creative-1"
+        }))
+        .unwrap()
+    }
+
+    fn options() -> BidVastOptions {
+        BidVastOptions {
+            impressions: vec![Uri("https://exchange.example.com/imp".to_string())],
+            errors: vec![Uri("https://exchange.example.com/err".to_string())],
+            ..Default::default()
+        }
+    }
+
+    fn extension<'a>(wrapper: &'a Wrapper, extension_type: &str) -> Option<&'a str> {
+        let extension = wrapper.extensions.as_ref()?.find(extension_type)?;
+        Some(extension.content.as_str())
+    }
+
+    fn check_wrapper(vast: &Vast, tag: &str) {
+        let wrapper = vast.ads[0].wrapper.as_ref().unwrap();
+        assert_eq!(wrapper.ad_system.name, "openrtb");
+        assert_eq!(wrapper.vast_ad_tag_uri.uri.0, tag);
+        assert_eq!(wrapper.impressions.len(), 2);
+        assert_eq!(wrapper.errors.len(), 1);
+        assert_eq!(wrapper.pricing.as_ref().unwrap().value, 2.5);
+        assert_eq!(extension(wrapper, "Advertiser"), Some("advertiser.com"));
+        assert_eq!(extension(wrapper, "CreativeId"), Some("creative-1"));
+        assert!(wrapper.creatives.is_none());
+    }
+
+    #[test]
+    fn test_inline_adm() {
+        let vast = Vast::from_bid(&bid(Some(INLINE), None), &options()).unwrap();
+        let inline = vast.ads[0].inline.as_ref().unwrap();
+        assert_eq!(inline.impressions.len(), 3);
+        assert_eq!(inline.errors.len(), 1);
+        assert_eq!(inline.pricing.as_ref().unwrap().currency, "USD");
+        assert_eq!(inline.advertiser.as_ref().unwrap().name, "advertiser.com");
+        let creative = &inline.creatives.creative[0];
+        assert_eq!(creative.ad_id.as_deref(), Some("creative-1"));
+        assert!(creative.universal_ad_id.is_some());
+    }
+
+    #[test]
+    fn test_wrapper_from_url_adm_and_nurl() {
+        let tag = "https://dsp.example.com/vast?id=1";
+        let vast = Vast::from_bid(&bid(Some(tag), None), &options()).unwrap();
+        check_wrapper(&vast, tag);
+
+        let nurl = "https://dsp.example.com/win";
+        let vast = Vast::from_bid(&bid(None, Some(nurl)), &options()).unwrap();
+        check_wrapper(&vast, nurl);
+        let xml = vast.to_string();
+        assert!(xml.contains("<AdSystem>openrtb</AdSystem>"));
+    }
+
+    #[test]
+    fn test_wrapper_adm_with_creatives() {
+        let adm = r#"<VAST version="4.3"><Ad id="1"><Wrapper><AdSystem>Sys</AdSystem><VASTAdTagURI>https://dsp.example.com/vast</VASTAdTagURI><Creatives><Creative><Linear><Duration>00:00:15</Duration><MediaFiles></MediaFiles><TrackingEvents><Tracking event="start">https://dsp.example.com/start</Tracking></TrackingEvents></Linear></Creative></Creatives></Wrapper></Ad></VAST>"#;
+        let vast = Vast::from_bid(&bid(Some(adm), None), &options()).unwrap();
+        let wrapper = vast.ads[0].wrapper.as_ref().unwrap();
+        let creative = &wrapper.creatives.as_ref().unwrap().creative[0];
+        assert_eq!(creative.ad_id.as_deref(), Some("creative-1"));
+        assert_eq!(extension(wrapper, "CreativeId"), None);
+    }
+
+    #[test]
+    fn test_from_bid_errors() {
+        assert!(matches!(
+            Vast::from_bid(&bid(None, None), &options()),
+            Err(VastError::MissingElement(element)) if element == "adm"
+        ));
+        assert!(Vast::from_bid(&bid(Some("<VAST version=\"4.3\">"), None), &options()).is_err());
+        assert!(matches!(
+            Vast::from_bid(&bid(Some(r#"<VAST version="4.3"/>"#), None), &options()),
+            Err(VastError::MissingElement(element)) if element == "Ad"
+        ));
+    }
+}
@@ -0,0 +1,994 @@
+//! Typed builders for the VAST 4.3 elements
+//!
+//! `X::builder` takes the required attributes and child elements of `X`; optional ones are
+//! set through the returned builder.
+
+use super::*;
+use crate::builder::{IntoField, builder};
+
+impl IntoField<Uri> for &str {
+    fn into_field(self) -> Uri {
+        Uri(self.to_string())
+    }
+}
+
+impl IntoField<MimeType> for &str {
+    fn into_field(self) -> MimeType {
+        MimeType(self.to_string())
+    }
+}
+
+builder! {
+    Ad => AdBuilder {
+        required {
+            id: String,
+        }
+        optional {
+            sequence: u32,
+            conditional_ad: bool,
+            ad_type: AdType,
+            inline: InLine,
+            wrapper: Wrapper,
+        }
+    }
+}
+
+builder! {
+    AdParameters => AdParametersBuilder {
+        required {
+            data: String,
+        }
+        optional {
+            xml_encoded: bool,
+        }
+    }
+}
+
+builder! {
+    Category => CategoryBuilder {
+        required {
+            authority: String,
+            code: String,
+        }
+        optional {}
+    }
+}
+
+builder! {
+    Duration => DurationBuilder {
+        required {
+            value: String,
+        }
+        optional {}
+    }
+}
+
+builder! {
+    CompanionAds => CompanionAdsBuilder {
+        required {}
+        optional {
+            required: CompanionAdsRequired,
+        }
+        defaulted {
+            companion: Vec<CompanionAd>,
+        }
+    }
+}
+
+builder! {
+    CompanionAd => CompanionAdBuilder {
+        required {
+            width: u32,
+            height: u32,
+        }
+        optional {
+            id: String,
+            asset_width: u32,
+            asset_height: u32,
+            expanded_width: u32,
+            expanded_height: u32,
+            api_framework: ApiFramework,
+            rendering_mode: String,
+            pxratio: String,
+            static_resource: CompanionStaticResource,
+            iframe_resource: CompanionIFrameResource,
+            html_resource: CompanionHTMLResource,
+            alt_text: AltText,
+            click_through: CompanionClickThrough,
+            tracking_events: TrackingEvents,
+            ad_parameters: AdParameters,
+        }
+        defaulted {
+            click_tracking: Vec<CompanionClickTracking>,
+        }
+    }
+}
+
+builder! {
+    CompanionStaticResource => CompanionStaticResourceBuilder {
+        required {
+            creative_type: String,
+            uri: Uri,
+        }
+        optional {}
+    }
+}
+
+builder! {
+    CompanionIFrameResource => CompanionIFrameResourceBuilder {
+        required {
+            uri: Uri,
+        }
+        optional {}
+    }
+}
+
+builder! {
+    CompanionHTMLResource => CompanionHTMLResourceBuilder {
+        required {
+            html: String,
+        }
+        optional {}
+    }
+}
+
+builder! {
+    CompanionClickThrough => CompanionClickThroughBuilder {
+        required {
+            uri: Uri,
+        }
+        optional {}
+    }
+}
+
+builder! {
+    CompanionClickTracking => CompanionClickTrackingBuilder {
+        required {
+            uri: Uri,
+        }
+        optional {
+            id: String,
+        }
+    }
+}
+
+builder! {
+    AltText => AltTextBuilder {
+        required {
+            value: String,
+        }
+        optional {}
+    }
+}
+
+builder! {
+    Creatives => CreativesBuilder {
+        required {}
+        optional {}
+        defaulted {
+            creative: Vec<Creative>,
+        }
+    }
+}
+
+builder! {
+    Creative => CreativeBuilder {
+        required {}
+        optional {
+            id: String,
+            sequence: u32,
+            api_framework: ApiFramework,
+            ad_id: String,
+            universal_ad_id: UniversalAdId,
+            creative_extensions: CreativeExtensions,
+            linear: Linear,
+            non_linear_ads: NonLinearAds,
+            companion_ads: CompanionAds,
+        }
+    }
+}
+
+builder! {
+    Extensions => ExtensionsBuilder {
+        required {}
+        optional {}
+        defaulted {
+            extension: Vec<Extension>,
+        }
+    }
+}
+
+builder! {
+    Extension => ExtensionBuilder {
+        required {}
+        optional {
+            extension_type: String,
+        }
+        defaulted {
+            content: String,
+        }
+    }
+}
+
+builder! {
+    CreativeExtensions => CreativeExtensionsBuilder {
+        required {}
+        optional {}
+        defaulted {
+            creative_extension: Vec<CreativeExtension>,
+        }
+    }
+}
+
+builder! {
+    CreativeExtension => CreativeExtensionBuilder {
+        required {}
+        optional {
+            extension_type: String,
+        }
+        defaulted {
+            content: String,
+        }
+    }
+}
+
+builder! {
+    Icons => IconsBuilder {
+        required {}
+        optional {}
+        defaulted {
+            icon: Vec<Icon>,
+        }
+    }
+}
+
+builder! {
+    Icon => IconBuilder {
+        required {
+            width: u32,
+            height: u32,
+            x_position: IconPosition,
+            y_position: IconPosition,
+        }
+        optional {
+            program: String,
+            duration: TimeOffset,
+            offset: TimeOffset,
+            api_framework: ApiFramework,
+            pxratio: String,
+            static_resource: IconStaticResource,
+            iframe_resource: IconIFrameResource,
+            html_resource: IconHTMLResource,
+            icon_clicks: IconClicks,
+        }
+        defaulted {
+            icon_view_tracking: Vec<IconViewTracking>,
+        }
+    }
+}
+
+builder! {
+    IconStaticResource => IconStaticResourceBuilder {
+        required {
+            creative_type: String,
+            uri: Uri,
+        }
+        optional {}
+    }
+}
+
+builder! {
+    IconIFrameResource => IconIFrameResourceBuilder {
+        required {
+            uri: Uri,
+        }
+        optional {}
+    }
+}
+
+builder! {
+    IconHTMLResource => IconHTMLResourceBuilder {
+        required {
+            html: String,
+        }
+        optional {}
+    }
+}
+
+builder! {
+    IconClicks => IconClicksBuilder {
+        required {}
+        optional {
+            click_through: IconClickThrough,
+            fallback_images: IconClickFallbackImages,
+        }
+        defaulted {
+            click_tracking: Vec<IconClickTracking>,
+        }
+    }
+}
+
+builder! {
+    IconClickThrough => IconClickThroughBuilder {
+        required {
+            uri: Uri,
+        }
+        optional {}
+    }
+}
+
+builder! {
+    IconClickTracking => IconClickTrackingBuilder {
+        required {
+            uri: Uri,
+        }
+        optional {
+            id: String,
+        }
+    }
+}
+
+builder! {
+    IconClickFallbackImages => IconClickFallbackImagesBuilder {
+        required {}
+        optional {}
+        defaulted {
+            fallback_image: Vec<IconClickFallbackImage>,
+        }
+    }
+}
+
+builder! {
+    IconClickFallbackImage => IconClickFallbackImageBuilder {
+        required {}
+        optional {
+            width: u32,
+            height: u32,
+            static_resource: IconStaticResource,
+        }
+    }
+}
+
+builder! {
+    IconViewTracking => IconViewTrackingBuilder {
+        required {
+            uri: Uri,
+        }
+        optional {}
+    }
+}
+
+builder! {
+    InLine => InLineBuilder {
+        required {
+            ad_system: AdSystem,
+            creatives: Creatives,
+        }
+        optional {
+            ad_title: AdTitle,
+            advertiser: Advertiser,
+            description: Description,
+            survey: Survey,
+            viewable_impression: ViewableImpression,
+            ad_verifications: AdVerifications,
+            extensions: Extensions,
+            pricing: Pricing,
+        }
+        defaulted {
+            impressions: Vec<Impression>,
+            categories: Vec<Category>,
+            errors: Vec<ErrorElement>,
+        }
+    }
+}
+
+builder! {
+    AdSystem => AdSystemBuilder {
+        required {
+            name: String,
+        }
+        optional {
+            version: String,
+        }
+    }
+}
+
+builder! {
+    Advertiser => AdvertiserBuilder {
+        required {
+            name: String,
+        }
+        optional {
+            id: String,
+        }
+    }
+}
+
+builder! {
+    Impression => ImpressionBuilder {
+        required {
+            uri: Uri,
+        }
+        optional {
+            id: String,
+        }
+    }
+}
+
+builder! {
+    AdTitle => AdTitleBuilder {
+        required {
+            value: String,
+        }
+        optional {}
+    }
+}
+
+builder! {
+    Description => DescriptionBuilder {
+        required {
+            value: String,
+        }
+        optional {}
+    }
+}
+
+builder! {
+    Survey => SurveyBuilder {
+        required {
+            value: String,
+        }
+        optional {}
+    }
+}
+
+builder! {
+    ErrorElement => ErrorElementBuilder {
+        required {
+            uri: Uri,
+        }
+        optional {}
+    }
+}
+
+builder! {
+    Linear => LinearBuilder {
+        required {
+            duration: Duration,
+            media_files: MediaFiles,
+        }
+        optional {
+            skip_offset: SkipOffset,
+            ad_parameters: AdParameters,
+            video_clicks: VideoClicks,
+            tracking_events: TrackingEvents,
+            icons: Icons,
+        }
+    }
+}
+
+builder! {
+    VideoClicks => VideoClicksBuilder {
+        required {}
+        optional {
+            click_through: ClickThrough,
+        }
+        defaulted {
+            click_tracking: Vec<ClickTracking>,
+            custom_clicks: Vec<CustomClick>,
+        }
+    }
+}
+
+builder! {
+    ClickThrough => ClickThroughBuilder {
+        required {
+            uri: Uri,
+        }
+        optional {
+            id: String,
+        }
+    }
+}
+
+builder! {
+    ClickTracking => ClickTrackingBuilder {
+        required {
+            uri: Uri,
+        }
+        optional {
+            id: String,
+        }
+    }
+}
+
+builder! {
+    CustomClick => CustomClickBuilder {
+        required {
+            uri: Uri,
+        }
+        optional {
+            id: String,
+        }
+    }
+}
+
+builder! {
+    MediaFiles => MediaFilesBuilder {
+        required {}
+        optional {
+            mezzanine: Mezzanine,
+            closed_caption_files: ClosedCaptionFiles,
+        }
+        defaulted {
+            media_file: Vec<MediaFile>,
+            interactive_creative_files: Vec<InteractiveCreativeFile>,
+        }
+    }
+}
+
+builder! {
+    MediaFile => MediaFileBuilder {
+        required {
+            delivery: DeliveryType,
+            mime_type: MimeType,
+            uri: Uri,
+        }
+        optional {
+            id: String,
+            width: u32,
+            height: u32,
+            codec: String,
+            bitrate: u32,
+            min_bitrate: u32,
+            max_bitrate: u32,
+            scalable: bool,
+            maintain_aspect_ratio: bool,
+            api_framework: String,
+            file_size: u64,
+            media_rating: String,
+        }
+    }
+}
+
+builder! {
+    Mezzanine => MezzanineBuilder {
+        required {
+            delivery: DeliveryType,
+            width: u32,
+            height: u32,
+            mime_type: MimeType,
+            uri: Uri,
+        }
+        optional {
+            id: String,
+            codec: String,
+            bitrate: u32,
+            min_bitrate: u32,
+            max_bitrate: u32,
+            file_size: u64,
+        }
+    }
+}
+
+builder! {
+    InteractiveCreativeFile => InteractiveCreativeFileBuilder {
+        required {
+            uri: Uri,
+        }
+        optional {
+            mime_type: MimeType,
+            api_framework: String,
+            variable_duration: bool,
+        }
+    }
+}
+
+builder! {
+    ClosedCaptionFiles => ClosedCaptionFilesBuilder {
+        required {}
+        optional {}
+        defaulted {
+            closed_caption_file: Vec<ClosedCaptionFile>,
+        }
+    }
+}
+
+builder! {
+    ClosedCaptionFile => ClosedCaptionFileBuilder {
+        required {
+            uri: Uri,
+        }
+        optional {
+            mime_type: MimeType,
+            language: String,
+        }
+    }
+}
+
+builder! {
+    NonLinearAds => NonLinearAdsBuilder {
+        required {}
+        optional {
+            tracking_events: TrackingEvents,
+        }
+        defaulted {
+            non_linear: Vec<NonLinear>,
+        }
+    }
+}
+
+builder! {
+    NonLinear => NonLinearBuilder {
+        required {
+            width: u32,
+            height: u32,
+        }
+        optional {
+            id: String,
+            expanded_width: u32,
+            expanded_height: u32,
+            scalable: bool,
+            maintain_aspect_ratio: bool,
+            min_suggested_duration: Duration,
+            api_framework: ApiFramework,
+            static_resource: StaticResource,
+            iframe_resource: IFrameResource,
+            html_resource: HTMLResource,
+            click_through: NonLinearClickThrough,
+            ad_parameters: AdParameters,
+        }
+        defaulted {
+            click_tracking: Vec<NonLinearClickTracking>,
+        }
+    }
+}
+
+builder! {
+    StaticResource => StaticResourceBuilder {
+        required {
+            creative_type: String,
+            uri: Uri,
+        }
+        optional {}
+    }
+}
+
+builder! {
+    IFrameResource => IFrameResourceBuilder {
+        required {
+            uri: Uri,
+        }
+        optional {}
+    }
+}
+
+builder! {
+    HTMLResource => HTMLResourceBuilder {
+        required {
+            html: String,
+        }
+        optional {}
+    }
+}
+
+builder! {
+    NonLinearClickThrough => NonLinearClickThroughBuilder {
+        required {
+            uri: Uri,
+        }
+        optional {}
+    }
+}
+
+builder! {
+    NonLinearClickTracking => NonLinearClickTrackingBuilder {
+        required {
+            uri: Uri,
+        }
+        optional {
+            id: String,
+        }
+    }
+}
+
+builder! {
+    Pricing => PricingBuilder {
+        required {
+            model: PricingModel,
+            currency: String,
+            value: f64,
+        }
+        optional {}
+    }
+}
+
+builder! {
+    TrackingEvents => TrackingEventsBuilder {
+        required {}
+        optional {}
+        defaulted {
+            tracking: Vec<Tracking>,
+        }
+    }
+}
+
+builder! {
+    Tracking => TrackingBuilder {
+        required {
+            event: TrackingEvent,
+            uri: Uri,
+        }
+        optional {
+            offset: TimeOffset,
+        }
+    }
+}
+
+builder! {
+    UniversalAdId => UniversalAdIdBuilder {
+        required {
+            id_registry: String,
+            value: String,
+        }
+        optional {
+            id_value: String,
+        }
+    }
+}
+
+builder! {
+    Vast => VastBuilder {
+        required {
+            version: String,
+        }
+        optional {
+            id: String,
+            sequence: u32,
+            conditional_ad: bool,
+        }
+        defaulted {
+            ads: Vec<Ad>,
+            errors: Vec<Error>,
+        }
+    }
+}
+
+builder! {
+    Error => ErrorBuilder {
+        required {
+            uri: Uri,
+        }
+        optional {}
+    }
+}
+
+builder! {
+    AdVerifications => AdVerificationsBuilder {
+        required {}
+        optional {}
+        defaulted {
+            verification: Vec<Verification>,
+        }
+    }
+}
+
+builder! {
+    Verification => VerificationBuilder {
+        required {
+            vendor: String,
+        }
+        optional {
+            tracking_events: VerificationTrackingEvents,
+            verification_parameters: VerificationParameters,
+        }
+        defaulted {
+            javascript_resources: Vec<JavaScriptResource>,
+            executable_resources: Vec<ExecutableResource>,
+        }
+    }
+}
+
+builder! {
+    JavaScriptResource => JavaScriptResourceBuilder {
+        required {
+            api_framework: ApiFramework,
+            uri: Uri,
+        }
+        optional {
+            browser_optional: bool,
+        }
+    }
+}
+
+builder! {
+    ExecutableResource => ExecutableResourceBuilder {
+        required {
+            api_framework: ApiFramework,
+            uri: Uri,
+        }
+        optional {
+            mime_type: String,
+        }
+    }
+}
+
+builder! {
+    VerificationTrackingEvents => VerificationTrackingEventsBuilder {
+        required {}
+        optional {}
+        defaulted {
+            tracking: Vec<VerificationTracking>,
+        }
+    }
+}
+
+builder! {
+    VerificationTracking => VerificationTrackingBuilder {
+        required {
+            event: VerificationEvent,
+            uri: Uri,
+        }
+        optional {}
+    }
+}
+
+builder! {
+    VerificationParameters => VerificationParametersBuilder {
+        required {
+            parameters: String,
+        }
+        optional {}
+    }
+}
+
+builder! {
+    ViewableImpression => ViewableImpressionBuilder {
+        required {}
+        optional {
+            id: String,
+        }
+        defaulted {
+            viewable: Vec<Viewable>,
+            not_viewable: Vec<NotViewable>,
+            view_undetermined: Vec<ViewUndetermined>,
+        }
+    }
+}
+
+builder! {
+    Viewable => ViewableBuilder {
+        required {
+            uri: Uri,
+        }
+        optional {}
+    }
+}
+
+builder! {
+    NotViewable => NotViewableBuilder {
+        required {
+            uri: Uri,
+        }
+        optional {}
+    }
+}
+
+builder! {
+    ViewUndetermined => ViewUndeterminedBuilder {
+        required {
+            uri: Uri,
+        }
+        optional {}
+    }
+}
+
+builder! {
+    Wrapper => WrapperBuilder {
+        required {
+            ad_system: WrapperAdSystem,
+            vast_ad_tag_uri: VASTAdTagURI,
+        }
+        optional {
+            follow_additional_wrappers: bool,
+            allow_multiple_ads: bool,
+            fallback_on_no_ad: bool,
+            viewable_impression: ViewableImpression,
+            ad_verifications: AdVerifications,
+            extensions: Extensions,
+            pricing: Pricing,
+            creatives: Creatives,
+        }
+        defaulted {
+            impressions: Vec<WrapperImpression>,
+            errors: Vec<WrapperError>,
+            blocked_ad_categories: Vec<BlockedAdCategories>,
+        }
+    }
+}
+
+builder! {
+    WrapperAdSystem => WrapperAdSystemBuilder {
+        required {
+            name: String,
+        }
+        optional {
+            version: String,
+        }
+    }
+}
+
+builder! {
+    VASTAdTagURI => VASTAdTagURIBuilder {
+        required {
+            uri: Uri,
+        }
+        optional {}
+    }
+}
+
+builder! {
+    WrapperImpression => WrapperImpressionBuilder {
+        required {
+            uri: Uri,
+        }
+        optional {
+            id: String,
+        }
+    }
+}
+
+builder! {
+    BlockedAdCategories => BlockedAdCategoriesBuilder {
+        required {
+            categories: String,
+        }
+        optional {
+            authority: String,
+        }
+    }
+}
+
+builder! {
+    WrapperError => WrapperErrorBuilder {
+        required {
+            uri: Uri,
+        }
+        optional {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_inline_ad() {
+        let media_file = MediaFile::builder(
+            DeliveryType::Progressive,
+            "video/mp4",
+            "https://cdn.example.com/ad.mp4",
+        )
+        .width(1920)
+        .height(1080)
+        .build();
+        let linear = Linear::builder(
+            Duration::builder("00:00:15").build(),
+            MediaFiles::builder().media_file([media_file]).build(),
+        )
+        .build();
+        let inline = InLine::builder(
+            AdSystem::builder("ExampleAds").build(),
+            Creatives::builder()
+                .creative([Creative::builder().linear(linear).build()])
+                .build(),
+        )
+        .impressions([Impression::builder("https://track.example.com/imp").build()])
+        .build();
+        let vast = Vast::builder("4.3")
+            .ads([Ad::builder("ad-1").inline(inline).build()])
+            .build();
+
+        assert_eq!(vast.to_string().parse::<Vast>().unwrap(), vast);
+    }
+}
@@ -4,7 +4,7 @@ use super::*;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
-/// Duration in HH:MM:SS format as specified in VAST
+/// Duration in HH:MM:SS or HH:MM:SS.mmm format as specified in VAST
 #[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Duration {
     #[serde(rename = "$value")]
@@ -17,24 +17,24 @@ impl Duration {
         Duration { value: s }
     }
 
-    /// Parse duration into total seconds
+    /// Parse duration into total seconds, truncating any milliseconds
     pub fn to_seconds(&self) -> Result<u32, VastError> {
-        let parts: Vec<&str> = self.value.split(':').collect();
-        if parts.len() != 3 {
-            return Err(VastError::InvalidDuration(self.value.clone()));
-        }
+        let duration = self.to_std()?;
+        u32::try_from(duration.as_secs())
+            .map_err(|_| VastError::InvalidDuration(self.value.clone()))
+    }
 
-        let hours = parts[0]
-            .parse::<u32>()
-            .map_err(|_| VastError::InvalidDuration(self.value.clone()))?;
-        let minutes = parts[1]
-            .parse::<u32>()
-            .map_err(|_| VastError::InvalidDuration(self.value.clone()))?;
-        let seconds = parts[2]
-            .parse::<u32>()
-            .map_err(|_| VastError::InvalidDuration(self.value.clone()))?;
+    /// Parse duration (`HH:MM:SS` or `HH:MM:SS.mmm`) into a `std::time::Duration`
+    pub fn to_std(&self) -> Result<std::time::Duration, VastError> {
+        parse_clock(&self.value).ok_or_else(|| VastError::InvalidDuration(self.value.clone()))
+    }
+}
 
-        Ok(hours * 3600 + minutes * 60 + seconds)
+impl From<std::time::Duration> for Duration {
+    fn from(d: std::time::Duration) -> Self {
+        Duration {
+            value: format_clock(d),
+        }
     }
 }
 
@@ -176,4 +176,3 @@ impl std::str::FromStr for ApiFramework {
         Ok(ApiFramework(s.to_string()))
     }
 }
-
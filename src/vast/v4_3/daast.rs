@@ -0,0 +1,181 @@
+//! DAAST 1.0 (Digital Audio Ad Serving Template) documents mapped into the VAST 4.3 model
+//!
+//! DAAST follows the VAST 3.0 structure with audio-specific element names. Those
+//! elements are renamed to their VAST counterparts and every ad is marked as
+//! `adType="audio"`. Extension content is left untouched.
+
+use super::*;
+use quick_xml::Reader;
+use quick_xml::Writer;
+use quick_xml::events::{BytesEnd, BytesStart, Event};
+
+/// DAAST element names and the VAST 4.3 elements they map to
+const RENAMED_ELEMENTS: &[(&[u8], &str)] = &[
+    (b"DAAST", "VAST"),
+    (b"DAASTAdTagURI", "VASTAdTagURI"),
+    (b"AdInteractions", "VideoClicks"),
+    (b"InteractionThrough", "ClickThrough"),
+    (b"InteractionTracking", "ClickTracking"),
+    (b"CustomInteraction", "CustomClick"),
+];
+
+fn renamed(name: &[u8]) -> Option<&'static str> {
+    RENAMED_ELEMENTS
+        .iter()
+        .find(|(daast, _)| *daast == name)
+        .map(|(_, vast)| *vast)
+}
+
+fn is_opaque(name: &[u8]) -> bool {
+    matches!(name, b"Extension" | b"CreativeExtension")
+}
+
+fn rename_root(start: &mut BytesStart) -> Result<(), VastError> {
+    if start.local_name().as_ref() != b"DAAST" {
+        return Err(VastError::MissingElement("DAAST".to_string()));
+    }
+    start.set_name(b"VAST");
+    Ok(())
+}
+
+/// Rewrite DAAST element names to their VAST counterparts
+fn rename_elements(xml: &str) -> Result<String, VastError> {
+    let mut reader = Reader::from_str(xml);
+    let mut writer = Writer::new(Vec::new());
+    let mut root = false;
+    let mut opaque = 0;
+
+    loop {
+        let event = reader.read_event().map_err(quick_xml::DeError::from)?;
+        let event = match event {
+            Event::Eof => break,
+            Event::Start(start) if opaque > 0 => {
+                opaque += 1;
+                Event::Start(start)
+            }
+            Event::End(end) if opaque > 0 => {
+                opaque -= 1;
+                Event::End(end)
+            }
+            Event::Start(mut start) if !root => {
+                rename_root(&mut start)?;
+                root = true;
+                Event::Start(start)
+            }
+            Event::Empty(mut start) if !root => {
+                rename_root(&mut start)?;
+                root = true;
+                Event::Empty(start)
+            }
+            Event::Start(mut start) => {
+                if is_opaque(start.local_name().as_ref()) {
+                    opaque = 1;
+                } else if let Some(name) = renamed(start.name().as_ref()) {
+                    start.set_name(name.as_bytes());
+                }
+                Event::Start(start)
+            }
+            Event::Empty(mut start) if opaque == 0 => {
+                if let Some(name) = renamed(start.name().as_ref()) {
+                    start.set_name(name.as_bytes());
+                }
+                Event::Empty(start)
+            }
+            Event::End(end) => match renamed(end.name().as_ref()) {
+                Some(name) => Event::End(BytesEnd::new(name)),
+                None => Event::End(end),
+            },
+            event => event,
+        };
+        writer
+            .write_event(event)
+            .map_err(quick_xml::Error::from)
+            .map_err(quick_xml::DeError::from)?;
+    }
+
+    if !root {
+        return Err(VastError::MissingElement("DAAST".to_string()));
+    }
+    String::from_utf8(writer.into_inner())
+        .map_err(|e| VastError::XmlParse(quick_xml::DeError::Custom(e.to_string())))
+}
+
+impl Vast {
+    /// Parse a DAAST 1.0 document into a VAST 4.3 document of audio ads
+    pub fn from_daast(xml: &str) -> Result<Vast, VastError> {
+        let xml = rename_elements(xml)?;
+        let mut vast: Vast = xml.parse()?;
+        vast.version = "4.3".to_string();
+        for ad in &mut vast.ads {
+            ad.ad_type = Some(AdType::Audio);
+        }
+        Ok(vast)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAAST: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<DAAST version="1.0">
+  <Ad id="a1">
+    <InLine>
+      <AdSystem>Radio Sys</AdSystem>
+      <AdTitle>Spot</AdTitle>
+      <Impression><![CDATA[https://example.com/imp]]></Impression>
+      <Creatives>
+        <Creative>
+          <Linear>
+            <Duration>00:00:30</Duration>
+            <MediaFiles>
+              <MediaFile delivery="progressive" type="audio/mpeg" bitrate="128"><![CDATA[https://example.com/spot.mp3]]></MediaFile>
+            </MediaFiles>
+            <AdInteractions>
+              <InteractionThrough>https://example.com/landing</InteractionThrough>
+              <InteractionTracking>https://example.com/click</InteractionTracking>
+            </AdInteractions>
+          </Linear>
+        </Creative>
+      </Creatives>
+      <Extensions><Extension type="station"><AdInteractions/></Extension></Extensions>
+    </InLine>
+  </Ad>
+  <Ad id="a2">
+    <Wrapper>
+      <AdSystem>Radio Sys</AdSystem>
+      <DAASTAdTagURI>https://example.com/daast</DAASTAdTagURI>
+    </Wrapper>
+  </Ad>
+</DAAST>"#;
+
+    #[test]
+    fn test_from_daast() {
+        let vast = Vast::from_daast(DAAST).unwrap();
+        assert_eq!(vast.version, "4.3");
+        assert!(vast.ads.iter().all(Ad::is_audio));
+        assert!(vast.validate().is_ok());
+
+        let inline = vast.ads[0].inline.as_ref().unwrap();
+        let linear = inline.creatives.creative[0].linear.as_ref().unwrap();
+        let clicks = linear.video_clicks.as_ref().unwrap();
+        assert_eq!(
+            clicks.click_through.as_ref().unwrap().uri.as_str(),
+            "https://example.com/landing"
+        );
+        assert_eq!(clicks.click_tracking.len(), 1);
+        let extension = &inline.extensions.as_ref().unwrap().extension[0];
+        assert_eq!(extension.content, "<AdInteractions/>");
+
+        let wrapper = vast.ads[1].wrapper.as_ref().unwrap();
+        assert_eq!(
+            wrapper.vast_ad_tag_uri.uri.as_str(),
+            "https://example.com/daast"
+        );
+
+        assert!(matches!(
+            Vast::from_daast(r#"<VAST version="4.3"/>"#),
+            Err(VastError::MissingElement(_))
+        ));
+    }
+}
@@ -0,0 +1,46 @@
+//! Ad types as defined in VAST 4.3
+
+use super::*;
+use serde::{Deserialize, Serialize};
+
+/// Type of media carried by an ad (`adType` attribute)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AdType {
+    /// Video ad
+    #[serde(rename = "video")]
+    Video,
+    /// Audio-only ad
+    #[serde(rename = "audio")]
+    Audio,
+    /// Ad that can be played as video or audio
+    #[serde(rename = "hybrid")]
+    Hybrid,
+}
+
+impl std::fmt::Display for AdType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AdType::Video => write!(f, "video"),
+            AdType::Audio => write!(f, "audio"),
+            AdType::Hybrid => write!(f, "hybrid"),
+        }
+    }
+}
+
+impl std::str::FromStr for AdType {
+    type Err = VastError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "video" => AdType::Video,
+            "audio" => AdType::Audio,
+            "hybrid" => AdType::Hybrid,
+            _ => {
+                return Err(VastError::InvalidAttribute(
+                    "adType".to_string(),
+                    s.to_string(),
+                ));
+            }
+        })
+    }
+}
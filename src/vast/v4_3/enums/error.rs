@@ -9,6 +9,10 @@ pub enum VastError {
     #[error("XML parsing error: {0}")]
     XmlParse(#[from] quick_xml::DeError),
 
+    /// XML serialization error
+    #[error("XML serialization error: {0}")]
+    XmlSerialize(#[from] quick_xml::SeError),
+
     /// Invalid VAST version
     #[error("Invalid VAST version: expected 4.3, got {0}")]
     InvalidVersion(String),
@@ -17,6 +21,10 @@ pub enum VastError {
     #[error("Required element '{0}' is missing")]
     MissingElement(String),
 
+    /// Required attribute is missing
+    #[error("Required attribute '{1}' is missing on '{0}'")]
+    MissingAttribute(String, String),
+
     /// Invalid attribute value
     #[error("Invalid attribute value for '{0}': {1}")]
     InvalidAttribute(String, String),
@@ -29,6 +37,10 @@ pub enum VastError {
     #[error("Invalid duration format: {0}")]
     InvalidDuration(String),
 
+    /// Time offset parsing error
+    #[error("Invalid time offset: {0}")]
+    InvalidTimeOffset(String),
+
     /// Invalid tracking event
     #[error("Invalid tracking event: {0}")]
     InvalidTrackingEvent(String),
@@ -48,4 +60,8 @@ pub enum VastError {
     /// Invalid pricing model
     #[error("Invalid pricing model: {0}")]
     InvalidPricingModel(String),
+
+    /// Extension type that is not registered or does not match the registered type
+    #[error("Invalid extension: {0}")]
+    InvalidExtension(String),
 }
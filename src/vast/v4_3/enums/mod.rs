@@ -2,9 +2,13 @@
 //!
 //! This module contains shared enumerated types defined in the VAST 4.3 specification.
 
+pub mod ad_type;
 pub mod error;
 pub mod tracking_event;
+pub mod verification_event;
 
 // Re-export for easy access
+pub use ad_type::*;
 pub use error::*;
 pub use tracking_event::*;
+pub use verification_event::*;
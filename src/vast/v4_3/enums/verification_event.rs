@@ -0,0 +1,78 @@
+//! Verification tracking events and `[REASON]` codes as defined in VAST 4.3
+
+use super::*;
+use serde::{Deserialize, Serialize};
+
+/// Types of verification tracking events as defined in VAST 4.3
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VerificationEvent {
+    /// The verification script was not executed
+    #[serde(rename = "verificationNotExecuted")]
+    VerificationNotExecuted,
+}
+
+impl std::fmt::Display for VerificationEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            VerificationEvent::VerificationNotExecuted => "verificationNotExecuted",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+impl std::str::FromStr for VerificationEvent {
+    type Err = VastError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "verificationNotExecuted" => VerificationEvent::VerificationNotExecuted,
+            _ => return Err(VastError::InvalidTrackingEvent(s.to_string())),
+        })
+    }
+}
+
+/// Reason substituted for the `[REASON]` macro of a `verificationNotExecuted` tracker
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VerificationNotExecutedReason {
+    /// The publisher does not allow the verification vendor
+    ResourceRejected,
+    /// The API framework or version of the resource is not supported
+    NotSupported,
+    /// An error occurred while loading the resource
+    LoadError,
+}
+
+impl VerificationNotExecutedReason {
+    /// The numeric code used for the `[REASON]` macro
+    pub fn code(&self) -> u32 {
+        match self {
+            VerificationNotExecutedReason::ResourceRejected => 1,
+            VerificationNotExecutedReason::NotSupported => 2,
+            VerificationNotExecutedReason::LoadError => 3,
+        }
+    }
+}
+
+impl std::fmt::Display for VerificationNotExecutedReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+impl TryFrom<u32> for VerificationNotExecutedReason {
+    type Error = VastError;
+
+    fn try_from(code: u32) -> Result<Self, Self::Error> {
+        Ok(match code {
+            1 => VerificationNotExecutedReason::ResourceRejected,
+            2 => VerificationNotExecutedReason::NotSupported,
+            3 => VerificationNotExecutedReason::LoadError,
+            _ => {
+                return Err(VastError::InvalidAttribute(
+                    "REASON".to_string(),
+                    code.to_string(),
+                ));
+            }
+        })
+    }
+}
@@ -1,6 +1,20 @@
 //! Extension elements for custom VAST extensions
+//!
+//! The content of `Extension` and `CreativeExtension` elements is kept as the raw inner
+//! XML of the element, so extensions this crate knows nothing about survive a parse and
+//! serialize cycle unchanged. Callers can register serde types for the extension `type`
+//! values they understand with an [`ExtensionRegistry`] to work with typed content.
 
+use super::VastError;
+use quick_xml::Reader;
+use quick_xml::escape::{escape, unescape};
+use quick_xml::events::Event;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
+use std::any::Any;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt;
 
 /// Container for ad-level extensions
 #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
@@ -11,6 +25,15 @@ pub struct Extensions {
     pub extension: Vec<Extension>,
 }
 
+impl Extensions {
+    /// The first extension with the given `type`
+    pub fn find(&self, extension_type: &str) -> Option<&Extension> {
+        self.extension
+            .iter()
+            .find(|e| e.extension_type.as_deref() == Some(extension_type))
+    }
+}
+
 /// A single extension element
 #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 #[serde(rename = "Extension")]
@@ -19,8 +42,8 @@ pub struct Extension {
     #[serde(rename = "@type", skip_serializing_if = "Option::is_none")]
     pub extension_type: Option<String>,
 
-    /// The extension content (can contain any valid XML)
-    #[serde(rename = "$value")]
+    /// The raw inner XML of the extension
+    #[serde(rename = "$value", default)]
     pub content: String,
 }
 
@@ -37,6 +60,15 @@ pub struct CreativeExtensions {
     pub creative_extension: Vec<CreativeExtension>,
 }
 
+impl CreativeExtensions {
+    /// The first creative extension with the given `type`
+    pub fn find(&self, extension_type: &str) -> Option<&CreativeExtension> {
+        self.creative_extension
+            .iter()
+            .find(|e| e.extension_type.as_deref() == Some(extension_type))
+    }
+}
+
 /// A single creative extension element
 #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 #[serde(rename = "CreativeExtension")]
@@ -45,7 +77,326 @@ pub struct CreativeExtension {
     #[serde(rename = "@type", skip_serializing_if = "Option::is_none")]
     pub extension_type: Option<String>,
 
-    /// The extension content (can contain any valid XML)
-    #[serde(rename = "$value")]
+    /// The raw inner XML of the extension
+    #[serde(rename = "$value", default)]
     pub content: String,
 }
+
+/// Common access to `Extension` and `CreativeExtension` elements
+pub trait ExtensionElement: Sized {
+    /// The `type` attribute of the element
+    fn extension_type(&self) -> Option<&str>;
+
+    /// The raw inner XML of the element
+    fn content(&self) -> &str;
+
+    /// Build an element from a type and raw inner XML
+    fn from_parts(extension_type: Option<String>, content: String) -> Self;
+
+    /// Decode the content as a single XML element of type `T`
+    fn decode<T: DeserializeOwned>(&self) -> Result<T, VastError> {
+        Ok(quick_xml::de::from_str(self.content())?)
+    }
+
+    /// Build an element whose content is `value` serialized as XML
+    fn encode<T: Serialize>(
+        extension_type: impl Into<String>,
+        value: &T,
+    ) -> Result<Self, VastError> {
+        let content = quick_xml::se::to_string(value)?;
+        Ok(Self::from_parts(Some(extension_type.into()), content))
+    }
+}
+
+impl ExtensionElement for Extension {
+    fn extension_type(&self) -> Option<&str> {
+        self.extension_type.as_deref()
+    }
+
+    fn content(&self) -> &str {
+        &self.content
+    }
+
+    fn from_parts(extension_type: Option<String>, content: String) -> Self {
+        Extension {
+            extension_type,
+            content,
+        }
+    }
+}
+
+impl ExtensionElement for CreativeExtension {
+    fn extension_type(&self) -> Option<&str> {
+        self.extension_type.as_deref()
+    }
+
+    fn content(&self) -> &str {
+        &self.content
+    }
+
+    fn from_parts(extension_type: Option<String>, content: String) -> Self {
+        CreativeExtension {
+            extension_type,
+            content,
+        }
+    }
+}
+
+/// Content of an extension decoded into the type registered for its `type`
+pub struct TypedExtension {
+    /// The extension `type` attribute
+    pub extension_type: String,
+
+    value: Box<dyn Any + Send + Sync>,
+}
+
+impl TypedExtension {
+    /// Wrap a value for encoding with an [`ExtensionRegistry`]
+    pub fn new<T: Any + Send + Sync>(extension_type: impl Into<String>, value: T) -> Self {
+        TypedExtension {
+            extension_type: extension_type.into(),
+            value: Box::new(value),
+        }
+    }
+
+    /// Borrow the decoded value if it is a `T`
+    pub fn downcast_ref<T: Any>(&self) -> Option<&T> {
+        self.value.downcast_ref()
+    }
+
+    /// Mutably borrow the decoded value if it is a `T`
+    pub fn downcast_mut<T: Any>(&mut self) -> Option<&mut T> {
+        self.value.downcast_mut()
+    }
+
+    /// Take the decoded value if it is a `T`
+    pub fn downcast<T: Any>(self) -> Result<T, Self> {
+        match self.value.downcast::<T>() {
+            Ok(value) => Ok(*value),
+            Err(value) => Err(TypedExtension {
+                extension_type: self.extension_type,
+                value,
+            }),
+        }
+    }
+}
+
+impl fmt::Debug for TypedExtension {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TypedExtension")
+            .field("extension_type", &self.extension_type)
+            .finish_non_exhaustive()
+    }
+}
+
+type DecodeFn = fn(&str) -> Result<Box<dyn Any + Send + Sync>, VastError>;
+type EncodeFn = fn(&(dyn Any + Send + Sync)) -> Result<String, VastError>;
+
+#[derive(Clone, Copy)]
+struct Registration {
+    decode: DecodeFn,
+    encode: EncodeFn,
+}
+
+fn decode_as<T>(content: &str) -> Result<Box<dyn Any + Send + Sync>, VastError>
+where
+    T: DeserializeOwned + Send + Sync + 'static,
+{
+    let value: T = quick_xml::de::from_str(content)?;
+    Ok(Box::new(value))
+}
+
+fn encode_as<T>(value: &(dyn Any + Send + Sync)) -> Result<String, VastError>
+where
+    T: Serialize + 'static,
+{
+    let value = value.downcast_ref::<T>().ok_or_else(|| {
+        VastError::InvalidExtension("value type does not match registration".to_string())
+    })?;
+    Ok(quick_xml::se::to_string(value)?)
+}
+
+/// Maps extension `type` attributes to the serde types that model their content
+///
+/// The content of a registered extension is expected to be a single XML element that
+/// deserializes into the registered type. Extensions without a registration are left
+/// as raw XML.
+#[derive(Clone, Default)]
+pub struct ExtensionRegistry {
+    types: HashMap<String, Registration>,
+}
+
+impl ExtensionRegistry {
+    /// Create an empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register `T` as the content model of extensions with the given `type`
+    pub fn register<T>(&mut self, extension_type: impl Into<String>) -> &mut Self
+    where
+        T: Serialize + DeserializeOwned + Send + Sync + 'static,
+    {
+        self.types.insert(
+            extension_type.into(),
+            Registration {
+                decode: decode_as::<T>,
+                encode: encode_as::<T>,
+            },
+        );
+        self
+    }
+
+    /// Whether a type is registered for the given extension `type`
+    pub fn is_registered(&self, extension_type: &str) -> bool {
+        self.types.contains_key(extension_type)
+    }
+
+    /// Decode the content of an extension element
+    ///
+    /// Returns `Ok(None)` when the element has no `type` or its type is not registered.
+    pub fn decode<E: ExtensionElement>(
+        &self,
+        element: &E,
+    ) -> Result<Option<TypedExtension>, VastError> {
+        let Some(extension_type) = element.extension_type() else {
+            return Ok(None);
+        };
+        let Some(registration) = self.types.get(extension_type) else {
+            return Ok(None);
+        };
+        Ok(Some(TypedExtension {
+            extension_type: extension_type.to_string(),
+            value: (registration.decode)(element.content())?,
+        }))
+    }
+
+    /// Encode a typed extension back into an extension element
+    pub fn encode<E: ExtensionElement>(&self, extension: &TypedExtension) -> Result<E, VastError> {
+        let registration = self
+            .types
+            .get(&extension.extension_type)
+            .ok_or_else(|| VastError::InvalidExtension(extension.extension_type.clone()))?;
+        let content = (registration.encode)(extension.value.as_ref())?;
+        Ok(E::from_parts(
+            Some(extension.extension_type.clone()),
+            content,
+        ))
+    }
+}
+
+impl fmt::Debug for ExtensionRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.types.keys()).finish()
+    }
+}
+
+fn is_extension_element(local_name: &[u8]) -> bool {
+    local_name == b"Extension" || local_name == b"CreativeExtension"
+}
+
+/// Replace the inner XML of every extension element in a document
+///
+/// Only the outermost extension elements are visited; nested ones are part of the
+/// content handed to `map`.
+fn map_extension_content(
+    xml: &str,
+    map: impl Fn(&str) -> Result<Cow<'_, str>, quick_xml::Error>,
+) -> Result<Cow<'_, str>, quick_xml::Error> {
+    let mut reader = Reader::from_str(xml);
+    let mut output = String::new();
+    let mut copied = 0;
+
+    loop {
+        match reader.read_event()? {
+            Event::Start(start) if is_extension_element(start.local_name().as_ref()) => {
+                let span = reader.read_to_end(start.name())?;
+                let (begin, end) = (span.start as usize, span.end as usize);
+                output.push_str(&xml[copied..begin]);
+                output.push_str(&map(&xml[begin..end])?);
+                copied = end;
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+
+    if copied == 0 {
+        return Ok(Cow::Borrowed(xml));
+    }
+    output.push_str(&xml[copied..]);
+    Ok(Cow::Owned(output))
+}
+
+/// Escape the inner XML of extension elements so that it deserializes into `content`
+pub(crate) fn capture_extension_content(xml: &str) -> Result<Cow<'_, str>, quick_xml::Error> {
+    map_extension_content(xml, |raw| Ok(escape(raw)))
+}
+
+/// Write the `content` of extension elements back as raw XML after serialization
+pub(crate) fn restore_extension_content(xml: &str) -> Result<Cow<'_, str>, quick_xml::Error> {
+    map_extension_content(xml, |text| Ok(unescape(text)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    #[serde(rename = "Waterfall")]
+    struct Waterfall {
+        #[serde(rename = "@index")]
+        index: u32,
+        #[serde(rename = "Partner", default)]
+        partners: Vec<String>,
+    }
+
+    const VAST: &str = r#"<VAST version="4.3"><Ad id="1"><InLine><AdSystem>Sys</AdSystem><AdTitle>Title</AdTitle><Impression><![CDATA[https://example.com/imp]]></Impression><Creatives><Creative><Linear><Duration>00:00:15</Duration><MediaFiles><MediaFile delivery="progressive" type="video/mp4" width="640" height="360"><![CDATA[https://example.com/ad.mp4]]></MediaFile></MediaFiles></Linear></Creative></Creatives><Extensions><Extension type="waterfall"><Waterfall index="2"><Partner>a</Partner><Partner>b</Partner></Waterfall></Extension><Extension type="unknown">
+  <Custom xmlns:x="urn:x" x:flag="1">a &amp; b<![CDATA[<raw>]]></Custom>
+</Extension></Extensions></InLine></Ad></VAST>"#;
+
+    #[test]
+    fn test_registered_and_unknown_extensions() {
+        let vast: Vast = VAST.parse().unwrap();
+        let extensions = vast.ads[0]
+            .inline
+            .as_ref()
+            .unwrap()
+            .extensions
+            .as_ref()
+            .unwrap();
+
+        let mut registry = ExtensionRegistry::new();
+        registry.register::<Waterfall>("waterfall");
+
+        let typed = registry
+            .decode(extensions.find("waterfall").unwrap())
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            typed.downcast_ref::<Waterfall>(),
+            Some(&Waterfall {
+                index: 2,
+                partners: vec!["a".to_string(), "b".to_string()],
+            })
+        );
+        let encoded: Extension = registry.encode(&typed).unwrap();
+        assert_eq!(&encoded, extensions.find("waterfall").unwrap());
+
+        let unknown = extensions.find("unknown").unwrap();
+        assert!(registry.decode(unknown).unwrap().is_none());
+        assert_eq!(
+            unknown.content,
+            "\n  <Custom xmlns:x=\"urn:x\" x:flag=\"1\">a &amp; b<![CDATA[<raw>]]></Custom>\n"
+        );
+
+        let xml = vast.to_string();
+        assert!(xml.contains(&format!(
+            "<Extension type=\"unknown\">{}</Extension>",
+            unknown.content
+        )));
+        let reparsed: Vast = xml.parse().unwrap();
+        assert_eq!(reparsed, vast);
+    }
+}
@@ -90,11 +90,11 @@ pub struct Icon {
 
     /// Duration for which the icon is displayed
     #[serde(rename = "@duration", skip_serializing_if = "Option::is_none")]
-    pub duration: Option<Duration>,
+    pub duration: Option<TimeOffset>,
 
     /// Offset time to display the icon
     #[serde(rename = "@offset", skip_serializing_if = "Option::is_none")]
-    pub offset: Option<Duration>,
+    pub offset: Option<TimeOffset>,
 
     /// API framework if applicable
     #[serde(rename = "@apiFramework", skip_serializing_if = "Option::is_none")]
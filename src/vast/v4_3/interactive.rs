@@ -0,0 +1,300 @@
+//! Interactive creative classification (SIMID and VPAID)
+//!
+//! Linear creatives can carry interactive files either as `InteractiveCreativeFile`
+//! elements (SIMID) or as `MediaFile` elements with an `apiFramework` of `VPAID`.
+
+use super::*;
+use crate::core::v2_6::enums::ApiFramework as CoreApiFramework;
+
+/// Kind of interactive creative
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InteractiveCreativeType {
+    /// Secure Interactive Media Interface Definition
+    Simid,
+    /// VPAID creative implemented in JavaScript
+    VpaidJavaScript,
+    /// VPAID creative implemented in Flash
+    VpaidFlash,
+}
+
+impl InteractiveCreativeType {
+    /// Classify a file from its `apiFramework`, MIME type and URI
+    ///
+    /// Returns `None` for files that are not SIMID or VPAID.
+    pub fn classify(
+        api_framework: Option<&str>,
+        mime_type: Option<&str>,
+        uri: &Uri,
+    ) -> Option<Self> {
+        let api = api_framework?.trim();
+        if api.eq_ignore_ascii_case("SIMID") {
+            return Some(InteractiveCreativeType::Simid);
+        }
+        if !api.eq_ignore_ascii_case("VPAID") {
+            return None;
+        }
+
+        let mime = mime_type.unwrap_or_default().trim();
+        let path = uri
+            .as_str()
+            .trim()
+            .split(['?', '#'])
+            .next()
+            .unwrap_or_default();
+        if mime.eq_ignore_ascii_case("application/x-shockwave-flash")
+            || path.to_ascii_lowercase().ends_with(".swf")
+        {
+            Some(InteractiveCreativeType::VpaidFlash)
+        } else {
+            Some(InteractiveCreativeType::VpaidJavaScript)
+        }
+    }
+
+    /// Whether the creative uses VPAID
+    pub fn is_vpaid(&self) -> bool {
+        matches!(
+            self,
+            InteractiveCreativeType::VpaidJavaScript | InteractiveCreativeType::VpaidFlash
+        )
+    }
+
+    /// OpenRTB 2.6 API frameworks under which the creative can run
+    pub fn api_frameworks(&self) -> &'static [CoreApiFramework] {
+        match self {
+            InteractiveCreativeType::Simid => {
+                &[CoreApiFramework::Simid1, CoreApiFramework::Simid11]
+            }
+            InteractiveCreativeType::VpaidJavaScript => &[CoreApiFramework::Vpaid2],
+            InteractiveCreativeType::VpaidFlash => {
+                &[CoreApiFramework::Vpaid1, CoreApiFramework::Vpaid2]
+            }
+        }
+    }
+
+    /// Whether a player declaring the given API frameworks can run the creative
+    pub fn is_supported_by(&self, api: &[CoreApiFramework]) -> bool {
+        self.api_frameworks().iter().any(|a| api.contains(a))
+    }
+}
+
+/// The element an interactive creative was found in
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InteractiveSource<'a> {
+    /// A `MediaFile` with an `apiFramework`
+    MediaFile(&'a MediaFile),
+    /// An `InteractiveCreativeFile`
+    InteractiveCreativeFile(&'a InteractiveCreativeFile),
+}
+
+impl InteractiveSource<'_> {
+    /// URI of the interactive file
+    pub fn uri(&self) -> &Uri {
+        match self {
+            InteractiveSource::MediaFile(f) => &f.uri,
+            InteractiveSource::InteractiveCreativeFile(f) => &f.uri,
+        }
+    }
+}
+
+/// An interactive file of a linear creative
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InteractiveCreative<'a> {
+    /// Kind of interactive creative
+    pub kind: InteractiveCreativeType,
+
+    /// The element holding the file
+    pub source: InteractiveSource<'a>,
+}
+
+/// Interactive files of a `MediaFiles` container
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct InteractiveClassification<'a> {
+    /// Interactive files in document order, media files first
+    pub creatives: Vec<InteractiveCreative<'a>>,
+
+    /// Whether at least one `MediaFile` can be played without an interactive framework
+    pub has_fallback: bool,
+}
+
+impl InteractiveClassification<'_> {
+    /// Whether any interactive file was found
+    pub fn is_interactive(&self) -> bool {
+        !self.creatives.is_empty()
+    }
+
+    /// OpenRTB 2.6 API frameworks required by the interactive files, without duplicates
+    pub fn api_frameworks(&self) -> Vec<CoreApiFramework> {
+        let mut api = Vec::new();
+        for creative in &self.creatives {
+            for framework in creative.kind.api_frameworks() {
+                if !api.contains(framework) {
+                    api.push(*framework);
+                }
+            }
+        }
+        api
+    }
+
+    /// Whether a player declaring the given API frameworks can render the creative,
+    /// either through a supported interactive file or a non-interactive fallback
+    pub fn is_playable_with(&self, api: &[CoreApiFramework]) -> bool {
+        self.has_fallback || self.creatives.iter().any(|c| c.kind.is_supported_by(api))
+    }
+}
+
+impl MediaFiles {
+    /// Classify the interactive files of the container
+    pub fn classify_interactive(&self) -> InteractiveClassification<'_> {
+        let mut classification = InteractiveClassification::default();
+
+        for media_file in &self.media_file {
+            match InteractiveCreativeType::classify(
+                media_file.api_framework.as_deref(),
+                Some(&media_file.mime_type.0),
+                &media_file.uri,
+            ) {
+                Some(kind) => classification.creatives.push(InteractiveCreative {
+                    kind,
+                    source: InteractiveSource::MediaFile(media_file),
+                }),
+                None => classification.has_fallback = true,
+            }
+        }
+
+        for file in &self.interactive_creative_files {
+            if let Some(kind) = InteractiveCreativeType::classify(
+                file.api_framework.as_deref(),
+                file.mime_type.as_ref().map(|m| m.0.as_str()),
+                &file.uri,
+            ) {
+                classification.creatives.push(InteractiveCreative {
+                    kind,
+                    source: InteractiveSource::InteractiveCreativeFile(file),
+                });
+            }
+        }
+
+        classification
+    }
+
+    /// Remove VPAID media files and interactive creative files
+    ///
+    /// Returns the number of files removed.
+    pub fn strip_vpaid(&mut self) -> usize {
+        let before = self.media_file.len() + self.interactive_creative_files.len();
+        self.media_file.retain(|f| {
+            !InteractiveCreativeType::classify(
+                f.api_framework.as_deref(),
+                Some(&f.mime_type.0),
+                &f.uri,
+            )
+            .is_some_and(|kind| kind.is_vpaid())
+        });
+        self.interactive_creative_files.retain(|f| {
+            !InteractiveCreativeType::classify(
+                f.api_framework.as_deref(),
+                f.mime_type.as_ref().map(|m| m.0.as_str()),
+                &f.uri,
+            )
+            .is_some_and(|kind| kind.is_vpaid())
+        });
+        before - self.media_file.len() - self.interactive_creative_files.len()
+    }
+}
+
+impl Vast {
+    /// Remove VPAID files from every linear creative for players that cannot run VPAID
+    ///
+    /// Linear creatives left without any media file are removed from their ad. Returns
+    /// the number of files removed.
+    pub fn strip_vpaid(&mut self) -> usize {
+        let mut removed = 0;
+        for inline in self.ads.iter_mut().filter_map(|ad| ad.inline.as_mut()) {
+            inline.creatives.creative.retain_mut(|creative| {
+                let Some(linear) = creative.linear.as_mut() else {
+                    return true;
+                };
+                let stripped = linear.media_files.strip_vpaid();
+                removed += stripped;
+                stripped == 0 || !linear.media_files.media_file.is_empty()
+            });
+        }
+        removed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn media_file(mime: &str, api: Option<&str>, uri: &str) -> MediaFile {
+        MediaFile {
+            id: None,
+            delivery: DeliveryType::Progressive,
+            mime_type: MimeType(mime.to_string()),
+            width: None,
+            height: None,
+            codec: None,
+            bitrate: None,
+            min_bitrate: None,
+            max_bitrate: None,
+            scalable: None,
+            maintain_aspect_ratio: None,
+            api_framework: api.map(str::to_string),
+            file_size: None,
+            media_rating: None,
+            uri: Uri(uri.to_string()),
+        }
+    }
+
+    #[test]
+    fn test_classify_and_strip() {
+        let mut media_files = MediaFiles {
+            media_file: vec![
+                media_file(
+                    "application/javascript",
+                    Some("VPAID"),
+                    "https://a/vpaid.js",
+                ),
+                media_file(
+                    "application/x-shockwave-flash",
+                    Some("VPAID"),
+                    "https://a/ad.swf",
+                ),
+                media_file("video/mp4", None, "https://a/ad.mp4"),
+            ],
+            interactive_creative_files: vec![InteractiveCreativeFile {
+                mime_type: Some(MimeType("text/html".to_string())),
+                api_framework: Some("SIMID".to_string()),
+                variable_duration: Some(true),
+                uri: Uri("https://a/simid.html".to_string()),
+            }],
+            ..Default::default()
+        };
+
+        let classification = media_files.classify_interactive();
+        let kinds: Vec<_> = classification.creatives.iter().map(|c| c.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                InteractiveCreativeType::VpaidJavaScript,
+                InteractiveCreativeType::VpaidFlash,
+                InteractiveCreativeType::Simid,
+            ]
+        );
+        assert!(classification.has_fallback);
+        assert_eq!(
+            classification.api_frameworks(),
+            vec![
+                CoreApiFramework::Vpaid2,
+                CoreApiFramework::Vpaid1,
+                CoreApiFramework::Simid1,
+                CoreApiFramework::Simid11,
+            ]
+        );
+
+        assert_eq!(media_files.strip_vpaid(), 2);
+        assert_eq!(media_files.media_file.len(), 1);
+        assert_eq!(media_files.interactive_creative_files.len(), 1);
+    }
+}
@@ -2,40 +2,9 @@
 
 use super::*;
 use serde::{Deserialize, Serialize};
-use std::fmt;
-
-/// Skip offset can be percentage or time
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(untagged)]
-pub enum SkipOffset {
-    /// Time offset in HH:MM:SS format
-    Time(Duration),
-    /// Percentage offset (e.g., "25%")
-    Percentage(String),
-}
 
-impl fmt::Display for SkipOffset {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            SkipOffset::Time(d) => write!(f, "{}", d),
-            SkipOffset::Percentage(p) => write!(f, "{}", p),
-        }
-    }
-}
-
-impl std::str::FromStr for SkipOffset {
-    type Err = std::convert::Infallible;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if s.ends_with('%') {
-            Ok(SkipOffset::Percentage(s.to_string()))
-        } else {
-            Ok(SkipOffset::Time(Duration {
-                value: s.to_string(),
-            }))
-        }
-    }
-}
+/// Skip offset can be a time (`HH:MM:SS[.mmm]`) or a percentage of the creative duration
+pub type SkipOffset = TimeOffset;
 
 /// Linear creative that plays linearly with the video content.
 ///
@@ -48,7 +17,7 @@ pub struct Linear {
     #[serde(rename = "@skipoffset", skip_serializing_if = "Option::is_none")]
     pub skip_offset: Option<SkipOffset>,
 
-    /// Duration of the creative in HH:MM:SS format
+    /// Duration of the creative in HH:MM:SS or HH:MM:SS.mmm format
     #[serde(rename = "Duration")]
     pub duration: Duration,
 
@@ -73,6 +42,16 @@ pub struct Linear {
     pub icons: Option<Icons>,
 }
 
+impl Linear {
+    /// Time after which the player may offer a skip control, if the creative is skippable
+    pub fn skip_after(&self) -> Result<Option<std::time::Duration>, VastError> {
+        match &self.skip_offset {
+            Some(offset) => Ok(Some(offset.resolve(self.duration.to_std()?))),
+            None => Ok(None),
+        }
+    }
+}
+
 /// Container for video click tracking elements
 #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 #[serde(rename = "VideoClicks")]
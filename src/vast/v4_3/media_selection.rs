@@ -0,0 +1,495 @@
+//! Media file rendition selection for a player profile
+//!
+//! Ranks the renditions of a `MediaFiles` container against the capabilities of a
+//! player so that the same input always yields the same choice.
+
+use super::*;
+use crate::core::v2_6::enums::{ApiFramework as CoreApiFramework, ContentDeliveryMethod};
+use std::cmp::Reverse;
+
+/// Capabilities of the player that will render the creative
+///
+/// Empty lists and `None` values are treated as "no restriction".
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PlayerProfile {
+    /// Supported MIME types (e.g., "video/mp4")
+    pub mime_types: Vec<String>,
+
+    /// Maximum rendition width in pixels
+    pub max_width: Option<u32>,
+
+    /// Maximum rendition height in pixels
+    pub max_height: Option<u32>,
+
+    /// Preferred bitrate in kbps; renditions closest to it rank first
+    pub target_bitrate: Option<u32>,
+
+    /// Minimum acceptable bitrate in kbps
+    pub min_bitrate: Option<u32>,
+
+    /// Maximum acceptable bitrate in kbps
+    pub max_bitrate: Option<u32>,
+
+    /// Supported delivery methods
+    pub delivery: Vec<DeliveryType>,
+
+    /// Supported `apiFramework` values (e.g., "VPAID", "SIMID"); files without
+    /// an `apiFramework` are always accepted, and an empty list accepts any framework
+    pub api_frameworks: Vec<String>,
+
+    /// Whether a mezzanine file should be surfaced for server-side ad insertion
+    pub ssai: bool,
+}
+
+impl PlayerProfile {
+    /// Derive a profile from the `Video` object of an OpenRTB 2.6 impression
+    pub fn from_video(video: &crate::core::v2_6::Video) -> Self {
+        let delivery = delivery_types(video.delivery.as_deref());
+
+        let api_frameworks = video
+            .api
+            .iter()
+            .flatten()
+            .map(|api| match api {
+                CoreApiFramework::Vpaid1 | CoreApiFramework::Vpaid2 => "VPAID",
+                CoreApiFramework::Mraid1 | CoreApiFramework::Mraid2 | CoreApiFramework::Mraid3 => {
+                    "MRAID"
+                }
+                CoreApiFramework::Ormma => "ORMMA",
+                CoreApiFramework::Omid1 => "OMID",
+                CoreApiFramework::Simid1 | CoreApiFramework::Simid11 => "SIMID",
+            })
+            .fold(Vec::new(), |mut acc, api| {
+                if !acc.iter().any(|a| a == api) {
+                    acc.push(api.to_string());
+                }
+                acc
+            });
+
+        PlayerProfile {
+            mime_types: video.mimes.clone(),
+            max_width: video.w,
+            max_height: video.h,
+            target_bitrate: Self::bitrate_midpoint(video.minbitrate, video.maxbitrate),
+            min_bitrate: video.minbitrate,
+            max_bitrate: video.maxbitrate,
+            delivery,
+            api_frameworks,
+            ssai: false,
+        }
+    }
+
+    /// Derive a profile from the `Audio` object of an OpenRTB 2.6 impression
+    ///
+    /// Audio renditions carry no dimensions, so no width or height limit is set. The
+    /// `api` list of `Audio` is untyped and not mapped, so no API framework restriction
+    /// is set.
+    pub fn from_audio(audio: &crate::core::v2_6::Audio) -> Self {
+        PlayerProfile {
+            mime_types: audio.mimes.clone(),
+            max_width: None,
+            max_height: None,
+            target_bitrate: Self::bitrate_midpoint(audio.minbitrate, audio.maxbitrate),
+            min_bitrate: audio.minbitrate,
+            max_bitrate: audio.maxbitrate,
+            delivery: delivery_types(audio.delivery.as_deref()),
+            api_frameworks: Vec::new(),
+            ssai: false,
+        }
+    }
+
+    /// Middle of a bitrate range, or its only bound when one is missing
+    fn bitrate_midpoint(min: Option<u32>, max: Option<u32>) -> Option<u32> {
+        match (min, max) {
+            (Some(min), Some(max)) => Some(min + (max.saturating_sub(min)) / 2),
+            (min, max) => max.or(min),
+        }
+    }
+
+    fn accepts_mime(&self, mime: &str) -> bool {
+        let mime = mime.split(';').next().unwrap_or_default().trim();
+        self.mime_types.is_empty()
+            || self
+                .mime_types
+                .iter()
+                .any(|m| m.trim().eq_ignore_ascii_case(mime))
+    }
+
+    fn accepts_api(&self, api: &str) -> bool {
+        self.api_frameworks.is_empty()
+            || self
+                .api_frameworks
+                .iter()
+                .any(|a| a.eq_ignore_ascii_case(api.trim()))
+    }
+}
+
+fn delivery_types(delivery: Option<&[ContentDeliveryMethod]>) -> Vec<DeliveryType> {
+    delivery
+        .into_iter()
+        .flatten()
+        .map(|d| match d {
+            ContentDeliveryMethod::Streaming => DeliveryType::Streaming,
+            ContentDeliveryMethod::Progressive | ContentDeliveryMethod::Download => {
+                DeliveryType::Progressive
+            }
+        })
+        .fold(Vec::new(), |mut acc, d| {
+            if !acc.contains(&d) {
+                acc.push(d);
+            }
+            acc
+        })
+}
+
+/// Reason a media file was not selected
+#[derive(Debug, Clone, PartialEq)]
+pub enum RejectionReason {
+    /// The MIME type is not supported by the player
+    UnsupportedMimeType(String),
+    /// The rendition is wider than the player allows
+    WidthExceeded { width: u32, max: u32 },
+    /// The rendition is taller than the player allows
+    HeightExceeded { height: u32, max: u32 },
+    /// The rendition bitrate is below the acceptable range
+    BitrateTooLow { bitrate: u32, min: u32 },
+    /// The rendition bitrate is above the acceptable range
+    BitrateTooHigh { bitrate: u32, max: u32 },
+    /// The delivery method is not supported by the player
+    UnsupportedDelivery(DeliveryType),
+    /// The file requires an API framework the player cannot run
+    UnsupportedApiFramework(String),
+}
+
+impl std::fmt::Display for RejectionReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RejectionReason::UnsupportedMimeType(m) => write!(f, "unsupported MIME type {}", m),
+            RejectionReason::WidthExceeded { width, max } => {
+                write!(f, "width {} exceeds maximum {}", width, max)
+            }
+            RejectionReason::HeightExceeded { height, max } => {
+                write!(f, "height {} exceeds maximum {}", height, max)
+            }
+            RejectionReason::BitrateTooLow { bitrate, min } => {
+                write!(f, "bitrate {} below minimum {}", bitrate, min)
+            }
+            RejectionReason::BitrateTooHigh { bitrate, max } => {
+                write!(f, "bitrate {} above maximum {}", bitrate, max)
+            }
+            RejectionReason::UnsupportedDelivery(d) => write!(f, "unsupported delivery {}", d),
+            RejectionReason::UnsupportedApiFramework(a) => {
+                write!(f, "unsupported API framework {}", a)
+            }
+        }
+    }
+}
+
+/// A media file together with the outcome of evaluating it against a profile
+#[derive(Debug, Clone, PartialEq)]
+pub struct MediaFileCandidate<'a> {
+    /// The evaluated media file
+    pub media_file: &'a MediaFile,
+
+    /// Position of the media file within `MediaFiles`
+    pub index: usize,
+
+    /// Why the file was rejected; empty when the file is acceptable
+    pub rejections: Vec<RejectionReason>,
+}
+
+impl MediaFileCandidate<'_> {
+    /// Whether the media file can be played by the profile
+    pub fn is_accepted(&self) -> bool {
+        self.rejections.is_empty()
+    }
+}
+
+/// Result of ranking the renditions of a `MediaFiles` container
+#[derive(Debug, Clone, PartialEq)]
+pub struct MediaSelection<'a> {
+    /// All media files, accepted ones first in ranked order, followed by rejected
+    /// ones in document order
+    pub candidates: Vec<MediaFileCandidate<'a>>,
+
+    /// The mezzanine file, when the profile requests SSAI and its MIME type is supported
+    pub mezzanine: Option<&'a Mezzanine>,
+
+    /// Interactive creative files whose API framework is supported by the profile
+    pub interactive_creative_files: Vec<&'a InteractiveCreativeFile>,
+}
+
+impl<'a> MediaSelection<'a> {
+    /// The highest ranked acceptable media file
+    pub fn best(&self) -> Option<&'a MediaFile> {
+        self.candidates
+            .first()
+            .filter(|c| c.is_accepted())
+            .map(|c| c.media_file)
+    }
+
+    /// All acceptable media files in ranked order
+    pub fn accepted(&self) -> impl Iterator<Item = &'a MediaFile> + '_ {
+        self.candidates
+            .iter()
+            .take_while(|c| c.is_accepted())
+            .map(|c| c.media_file)
+    }
+
+    /// All rejected media files with the reasons they were rejected
+    pub fn rejected(&self) -> impl Iterator<Item = &MediaFileCandidate<'a>> + '_ {
+        self.candidates.iter().filter(|c| !c.is_accepted())
+    }
+}
+
+fn effective_bitrate(media_file: &MediaFile) -> Option<u32> {
+    media_file
+        .bitrate
+        .or_else(|| PlayerProfile::bitrate_midpoint(media_file.min_bitrate, media_file.max_bitrate))
+}
+
+fn evaluate(profile: &PlayerProfile, media_file: &MediaFile) -> Vec<RejectionReason> {
+    let mut rejections = Vec::new();
+
+    if !profile.accepts_mime(&media_file.mime_type.0) {
+        rejections.push(RejectionReason::UnsupportedMimeType(
+            media_file.mime_type.0.clone(),
+        ));
+    }
+    if let (Some(width), Some(max)) = (media_file.width, profile.max_width)
+        && width > max
+    {
+        rejections.push(RejectionReason::WidthExceeded { width, max });
+    }
+    if let (Some(height), Some(max)) = (media_file.height, profile.max_height)
+        && height > max
+    {
+        rejections.push(RejectionReason::HeightExceeded { height, max });
+    }
+
+    // A rendition advertising a bitrate range is acceptable if the range overlaps
+    let low = media_file.bitrate.or(media_file.min_bitrate);
+    let high = media_file.bitrate.or(media_file.max_bitrate);
+    if let (Some(bitrate), Some(min)) = (high, profile.min_bitrate)
+        && bitrate < min
+    {
+        rejections.push(RejectionReason::BitrateTooLow { bitrate, min });
+    }
+    if let (Some(bitrate), Some(max)) = (low, profile.max_bitrate)
+        && bitrate > max
+    {
+        rejections.push(RejectionReason::BitrateTooHigh { bitrate, max });
+    }
+
+    if !profile.delivery.is_empty() && !profile.delivery.contains(&media_file.delivery) {
+        rejections.push(RejectionReason::UnsupportedDelivery(media_file.delivery));
+    }
+    if let Some(api) = &media_file.api_framework
+        && !profile.accepts_api(api)
+    {
+        rejections.push(RejectionReason::UnsupportedApiFramework(api.clone()));
+    }
+
+    rejections
+}
+
+impl MediaFiles {
+    /// Rank the media files against a player profile
+    ///
+    /// Acceptable renditions are ordered by closeness to the target bitrate, then by
+    /// largest resolution, then by highest bitrate, and finally by document order.
+    pub fn select(&self, profile: &PlayerProfile) -> MediaSelection<'_> {
+        let (mut accepted, rejected): (Vec<_>, Vec<_>) = self
+            .media_file
+            .iter()
+            .enumerate()
+            .map(|(index, media_file)| MediaFileCandidate {
+                media_file,
+                index,
+                rejections: evaluate(profile, media_file),
+            })
+            .partition(|c| c.is_accepted());
+
+        accepted.sort_by_key(|c| {
+            let bitrate = effective_bitrate(c.media_file);
+            let distance = match (bitrate, profile.target_bitrate) {
+                (Some(b), Some(target)) => b.abs_diff(target),
+                (None, Some(_)) => u32::MAX,
+                (_, None) => 0,
+            };
+            let area = u64::from(c.media_file.width.unwrap_or(0))
+                * u64::from(c.media_file.height.unwrap_or(0));
+            (
+                distance,
+                Reverse(area),
+                Reverse(bitrate.unwrap_or(0)),
+                c.index,
+            )
+        });
+
+        let mezzanine = self
+            .mezzanine
+            .as_ref()
+            .filter(|m| profile.ssai && profile.accepts_mime(&m.mime_type.0));
+
+        let interactive_creative_files = self
+            .interactive_creative_files
+            .iter()
+            .filter(|f| {
+                f.api_framework
+                    .as_deref()
+                    .is_some_and(|api| profile.accepts_api(api))
+            })
+            .collect();
+
+        accepted.extend(rejected);
+        MediaSelection {
+            candidates: accepted,
+            mezzanine,
+            interactive_creative_files,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn media_file(mime: &str, width: u32, height: u32, bitrate: u32) -> MediaFile {
+        MediaFile {
+            id: None,
+            delivery: DeliveryType::Progressive,
+            mime_type: MimeType(mime.to_string()),
+            width: Some(width),
+            height: Some(height),
+            codec: None,
+            bitrate: Some(bitrate),
+            min_bitrate: None,
+            max_bitrate: None,
+            scalable: None,
+            maintain_aspect_ratio: None,
+            api_framework: None,
+            file_size: None,
+            media_rating: None,
+            uri: Uri(format!("https://a/{width}x{height}_{bitrate}")),
+        }
+    }
+
+    #[test]
+    fn test_ranking_and_tie_break() {
+        let media_files = MediaFiles {
+            media_file: vec![
+                media_file("video/mp4", 640, 360, 800),
+                media_file("video/mp4", 1280, 720, 1200),
+                media_file("video/mp4", 1920, 1080, 1200),
+                media_file("video/mp4", 1920, 1080, 1200),
+                media_file("video/mp4", 854, 480, 1000),
+            ],
+            ..Default::default()
+        };
+        let profile = PlayerProfile {
+            target_bitrate: Some(1100),
+            ..Default::default()
+        };
+
+        // 1000 and 1200 are equally close to the target: the largest area wins, then
+        // the highest bitrate, then document order
+        let order: Vec<_> = media_files
+            .select(&profile)
+            .candidates
+            .iter()
+            .map(|c| c.index)
+            .collect();
+        assert_eq!(order, vec![2, 3, 1, 4, 0]);
+
+        // Without a target bitrate the largest rendition ranks first
+        let selection = media_files.select(&PlayerProfile::default());
+        assert_eq!(selection.best(), Some(&media_files.media_file[2]));
+        assert_eq!(selection.accepted().count(), 5);
+    }
+
+    #[test]
+    fn test_rejection_reasons() {
+        let mut streaming = media_file("video/mp4", 640, 360, 500);
+        streaming.delivery = DeliveryType::Streaming;
+        let mut vpaid = media_file("video/mp4", 640, 360, 500);
+        vpaid.api_framework = Some("VPAID".to_string());
+        let media_files = MediaFiles {
+            media_file: vec![
+                media_file("video/webm", 640, 360, 500),
+                media_file("video/mp4", 1920, 360, 500),
+                media_file("video/mp4", 640, 1080, 500),
+                media_file("video/mp4", 640, 360, 100),
+                media_file("video/mp4", 640, 360, 5000),
+                streaming,
+                vpaid,
+                media_file("video/mp4", 640, 360, 500),
+            ],
+            ..Default::default()
+        };
+        let profile = PlayerProfile {
+            mime_types: vec!["video/mp4".to_string()],
+            max_width: Some(1280),
+            max_height: Some(720),
+            min_bitrate: Some(300),
+            max_bitrate: Some(2000),
+            delivery: vec![DeliveryType::Progressive],
+            api_frameworks: vec!["SIMID".to_string()],
+            ..Default::default()
+        };
+
+        let selection = media_files.select(&profile);
+        assert_eq!(selection.best(), Some(&media_files.media_file[7]));
+        let rejections: Vec<_> = selection.rejected().map(|c| c.rejections.clone()).collect();
+        assert_eq!(
+            rejections,
+            vec![
+                vec![RejectionReason::UnsupportedMimeType(
+                    "video/webm".to_string()
+                )],
+                vec![RejectionReason::WidthExceeded {
+                    width: 1920,
+                    max: 1280
+                }],
+                vec![RejectionReason::HeightExceeded {
+                    height: 1080,
+                    max: 720
+                }],
+                vec![RejectionReason::BitrateTooLow {
+                    bitrate: 100,
+                    min: 300
+                }],
+                vec![RejectionReason::BitrateTooHigh {
+                    bitrate: 5000,
+                    max: 2000
+                }],
+                vec![RejectionReason::UnsupportedDelivery(
+                    DeliveryType::Streaming
+                )],
+                vec![RejectionReason::UnsupportedApiFramework(
+                    "VPAID".to_string()
+                )],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_empty_profile_accepts_any_api_framework() {
+        let mut vpaid = media_file("application/javascript", 640, 360, 500);
+        vpaid.api_framework = Some("VPAID".to_string());
+        let media_files = MediaFiles {
+            media_file: vec![vpaid],
+            interactive_creative_files: vec![InteractiveCreativeFile {
+                mime_type: Some(MimeType("text/html".to_string())),
+                api_framework: Some("SIMID".to_string()),
+                variable_duration: None,
+                uri: Uri("https://a/simid.html".to_string()),
+            }],
+            ..Default::default()
+        };
+
+        let selection = media_files.select(&PlayerProfile::default());
+        assert_eq!(selection.best(), Some(&media_files.media_file[0]));
+        assert_eq!(selection.interactive_creative_files.len(), 1);
+    }
+}
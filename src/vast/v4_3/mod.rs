@@ -6,28 +6,44 @@
 // Core modules
 pub mod ad;
 pub mod ad_parameters;
+pub mod audio;
+pub mod bid_conversion;
+pub mod builder;
 pub mod category;
 pub mod common;
 pub mod companion;
 pub mod creative;
+pub mod daast;
 pub mod enums;
 pub mod extensions;
 pub mod icons;
 pub mod inline;
+pub mod interactive;
 pub mod linear;
 pub mod media_file;
+pub mod media_selection;
 pub mod non_linear;
+pub mod playback;
+pub mod pod;
 pub mod pricing;
+pub mod round_trip;
+pub mod secure;
+pub mod stream;
+pub mod time_offset;
 pub mod tracking;
 pub mod universal_ad_id;
 pub mod vast;
 pub mod verification;
 pub mod viewable_impression;
+pub mod visitor;
 pub mod wrapper;
 
 // Re-export main types
 pub use ad::*;
 pub use ad_parameters::*;
+pub use audio::*;
+pub use bid_conversion::*;
+pub use builder::*;
 pub use category::*;
 pub use common::*;
 pub use companion::*;
@@ -36,13 +52,22 @@ pub use enums::*;
 pub use extensions::*;
 pub use icons::*;
 pub use inline::*;
+pub use interactive::*;
 pub use linear::*;
 pub use media_file::*;
+pub use media_selection::*;
 pub use non_linear::*;
+pub use playback::*;
+pub use pod::*;
 pub use pricing::*;
+pub use round_trip::*;
+pub use secure::*;
+pub use stream::*;
+pub use time_offset::*;
 pub use tracking::*;
 pub use universal_ad_id::*;
 pub use vast::*;
 pub use verification::*;
 pub use viewable_impression::*;
+pub use visitor::*;
 pub use wrapper::*;
@@ -0,0 +1,443 @@
+//! Playback tracking state machine for linear creatives
+//!
+//! Turns player events into the tracking URIs that must be fired for a resolved
+//! InLine ad, without requiring a real player.
+
+use super::*;
+use std::time::Duration as StdDuration;
+
+/// Events reported by a video player during linear ad playback
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PlayerEvent {
+    /// The creative has been loaded and is ready to play
+    Load,
+    /// Playback of the first frame has begun
+    Start,
+    /// The playhead has moved to the given position
+    TimeUpdate(StdDuration),
+    /// The viewer paused playback
+    Pause,
+    /// The viewer resumed playback
+    Resume,
+    /// The viewer muted the player
+    Mute,
+    /// The viewer unmuted the player
+    Unmute,
+    /// The viewer skipped the ad
+    Skip,
+    /// Playback reached the end of the creative
+    Complete,
+    /// Playback failed with the given VAST error code
+    Error(u32),
+    /// The viewability measurement for the impression was concluded
+    Viewability(ViewabilityResult),
+}
+
+/// Outcome of a viewability measurement
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ViewabilityResult {
+    /// The impression met the viewability criteria
+    Viewable,
+    /// The impression did not meet the viewability criteria
+    NotViewable,
+    /// Viewability could not be determined
+    Undetermined,
+}
+
+/// Playback states tracked by [`PlaybackTracker`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlaybackState {
+    /// No event has been received yet
+    Idle,
+    /// The creative is loaded but not yet playing
+    Loaded,
+    /// The creative is playing
+    Playing,
+    /// Playback is paused
+    Paused,
+    /// Playback ended through completion, skip or error
+    Finished,
+}
+
+#[derive(Debug, Clone)]
+struct IconView {
+    offset: StdDuration,
+    uris: Vec<Uri>,
+    fired: bool,
+}
+
+/// State machine that maps player events to the tracking URIs to fire
+///
+/// Impressions, `creativeView`, `start`, quartiles, `progress`, `complete`, `skip`,
+/// icon views and viewability trackers fire at most once. `pause`/`resume` and
+/// `mute`/`unmute` fire on every transition between the two states.
+#[derive(Debug, Clone)]
+pub struct PlaybackTracker {
+    duration: StdDuration,
+    state: PlaybackState,
+    muted: bool,
+    position: StdDuration,
+    impressions: Vec<Uri>,
+    impression_fired: bool,
+    errors: Vec<Uri>,
+    tracking: Vec<Tracking>,
+    fired: Vec<bool>,
+    icons: Vec<IconView>,
+    viewable_impression: Option<ViewableImpression>,
+    viewability_fired: bool,
+}
+
+impl PlaybackTracker {
+    /// Create a tracker for the first linear creative of an InLine ad
+    pub fn new(inline: &InLine) -> Result<Self, VastError> {
+        let linear = inline
+            .creatives
+            .creative
+            .iter()
+            .find_map(|c| c.linear.as_ref())
+            .ok_or_else(|| VastError::MissingElement("Linear".to_string()))?;
+        let duration = linear.duration.to_std()?;
+
+        let tracking = linear
+            .tracking_events
+            .as_ref()
+            .map(|t| t.tracking.clone())
+            .unwrap_or_default();
+
+        let icons = linear
+            .icons
+            .iter()
+            .flat_map(|icons| icons.icon.iter())
+            .filter(|icon| !icon.icon_view_tracking.is_empty())
+            .map(|icon| IconView {
+                offset: icon.offset.map(|o| o.resolve(duration)).unwrap_or_default(),
+                uris: icon
+                    .icon_view_tracking
+                    .iter()
+                    .map(|t| t.uri.clone())
+                    .collect(),
+                fired: false,
+            })
+            .collect();
+
+        Ok(PlaybackTracker {
+            duration,
+            state: PlaybackState::Idle,
+            muted: false,
+            position: StdDuration::ZERO,
+            impressions: inline.impressions.iter().map(|i| i.uri.clone()).collect(),
+            impression_fired: false,
+            errors: inline.errors.iter().map(|e| e.uri.clone()).collect(),
+            fired: vec![false; tracking.len()],
+            tracking,
+            icons,
+            viewable_impression: inline.viewable_impression.clone(),
+            viewability_fired: false,
+        })
+    }
+
+    /// Current playback state
+    pub fn state(&self) -> PlaybackState {
+        self.state
+    }
+
+    /// Duration of the tracked creative
+    pub fn duration(&self) -> StdDuration {
+        self.duration
+    }
+
+    /// Apply a player event and return the URIs that must be fired for it
+    pub fn handle(&mut self, event: PlayerEvent) -> Vec<Uri> {
+        let mut out = Vec::new();
+
+        match event {
+            // Viewability is measured independently of playback and may be reported
+            // after the ad finished.
+            PlayerEvent::Viewability(result) => self.fire_viewability(result, &mut out),
+            _ if self.state == PlaybackState::Finished => {}
+            PlayerEvent::Load => {
+                if self.state == PlaybackState::Idle {
+                    self.state = PlaybackState::Loaded;
+                }
+            }
+            PlayerEvent::Start => {
+                if matches!(self.state, PlaybackState::Idle | PlaybackState::Loaded) {
+                    self.state = PlaybackState::Playing;
+                    if !self.impression_fired {
+                        self.impression_fired = true;
+                        out.extend(self.impressions.iter().cloned());
+                    }
+                    self.fire_once(&TrackingEvent::CreativeView, &mut out);
+                    self.fire_once(&TrackingEvent::Start, &mut out);
+                    self.advance(StdDuration::ZERO, &mut out);
+                }
+            }
+            PlayerEvent::TimeUpdate(position) => {
+                if matches!(self.state, PlaybackState::Playing | PlaybackState::Paused) {
+                    self.advance(position, &mut out);
+                }
+            }
+            PlayerEvent::Pause => {
+                if self.state == PlaybackState::Playing {
+                    self.state = PlaybackState::Paused;
+                    self.fire_repeatable(&TrackingEvent::Pause, &mut out);
+                }
+            }
+            PlayerEvent::Resume => {
+                if self.state == PlaybackState::Paused {
+                    self.state = PlaybackState::Playing;
+                    self.fire_repeatable(&TrackingEvent::Resume, &mut out);
+                }
+            }
+            PlayerEvent::Mute => {
+                if !self.muted {
+                    self.muted = true;
+                    self.fire_repeatable(&TrackingEvent::Mute, &mut out);
+                }
+            }
+            PlayerEvent::Unmute => {
+                if self.muted {
+                    self.muted = false;
+                    self.fire_repeatable(&TrackingEvent::Unmute, &mut out);
+                }
+            }
+            PlayerEvent::Skip => {
+                if matches!(self.state, PlaybackState::Playing | PlaybackState::Paused) {
+                    self.state = PlaybackState::Finished;
+                    self.fire_once(&TrackingEvent::Skip, &mut out);
+                }
+            }
+            PlayerEvent::Complete => {
+                if matches!(self.state, PlaybackState::Playing | PlaybackState::Paused) {
+                    self.advance(self.duration, &mut out);
+                    self.state = PlaybackState::Finished;
+                    self.fire_once(&TrackingEvent::Complete, &mut out);
+                }
+            }
+            PlayerEvent::Error(code) => {
+                self.state = PlaybackState::Finished;
+                let code = code.to_string();
+                out.extend(
+                    self.errors
+                        .iter()
+                        .map(|uri| Uri(uri.0.replace("[ERRORCODE]", &code))),
+                );
+            }
+        }
+
+        out
+    }
+
+    /// Fire every time-based tracker whose trigger time has been reached
+    fn advance(&mut self, position: StdDuration, out: &mut Vec<Uri>) {
+        self.position = self.position.max(position.min(self.duration));
+        let position = self.position;
+        let duration = self.duration;
+
+        for (event, percent) in [
+            (TrackingEvent::FirstQuartile, 25.0),
+            (TrackingEvent::Midpoint, 50.0),
+            (TrackingEvent::ThirdQuartile, 75.0),
+        ] {
+            if !duration.is_zero() && position >= TimeOffset::Percentage(percent).resolve(duration)
+            {
+                self.fire_once(&event, out);
+            }
+        }
+
+        for (tracking, fired) in self.tracking.iter().zip(self.fired.iter_mut()) {
+            if *fired || tracking.event != TrackingEvent::Progress {
+                continue;
+            }
+            if let Some(offset) = tracking.offset
+                && offset.resolve(duration) <= position
+            {
+                *fired = true;
+                out.push(tracking.uri.clone());
+            }
+        }
+
+        for icon in self.icons.iter_mut().filter(|i| !i.fired) {
+            if icon.offset <= position {
+                icon.fired = true;
+                out.extend(icon.uris.iter().cloned());
+            }
+        }
+    }
+
+    fn fire_once(&mut self, event: &TrackingEvent, out: &mut Vec<Uri>) {
+        for (tracking, fired) in self.tracking.iter().zip(self.fired.iter_mut()) {
+            if !*fired && tracking.event == *event {
+                *fired = true;
+                out.push(tracking.uri.clone());
+            }
+        }
+    }
+
+    fn fire_repeatable(&self, event: &TrackingEvent, out: &mut Vec<Uri>) {
+        out.extend(
+            self.tracking
+                .iter()
+                .filter(|t| t.event == *event)
+                .map(|t| t.uri.clone()),
+        );
+    }
+
+    fn fire_viewability(&mut self, result: ViewabilityResult, out: &mut Vec<Uri>) {
+        if self.viewability_fired {
+            return;
+        }
+        let Some(viewable_impression) = &self.viewable_impression else {
+            return;
+        };
+        self.viewability_fired = true;
+        match result {
+            ViewabilityResult::Viewable => {
+                out.extend(viewable_impression.viewable.iter().map(|v| v.uri.clone()))
+            }
+            ViewabilityResult::NotViewable => out.extend(
+                viewable_impression
+                    .not_viewable
+                    .iter()
+                    .map(|v| v.uri.clone()),
+            ),
+            ViewabilityResult::Undetermined => out.extend(
+                viewable_impression
+                    .view_undetermined
+                    .iter()
+                    .map(|v| v.uri.clone()),
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn uri(s: &str) -> Uri {
+        Uri(s.to_string())
+    }
+
+    fn inline() -> InLine {
+        let mut tracking = TrackingEvents::new();
+        tracking.add_tracking(TrackingEvent::CreativeView, uri("cv"));
+        tracking.add_tracking(TrackingEvent::Start, uri("start"));
+        tracking.add_tracking(TrackingEvent::FirstQuartile, uri("q1"));
+        tracking.add_tracking(TrackingEvent::Midpoint, uri("mid"));
+        tracking.add_tracking(TrackingEvent::ThirdQuartile, uri("q3"));
+        tracking.add_tracking(TrackingEvent::Complete, uri("complete"));
+        tracking.add_tracking(TrackingEvent::Pause, uri("pause"));
+        tracking.add_tracking(TrackingEvent::Resume, uri("resume"));
+        tracking.add_tracking_with_offset(
+            TrackingEvent::Progress,
+            uri("p5"),
+            TimeOffset::Time(StdDuration::from_secs(5)),
+        );
+
+        let linear = Linear {
+            duration: Duration::new("00:00:20".to_string()),
+            tracking_events: Some(tracking),
+            ..Default::default()
+        };
+
+        InLine {
+            impressions: vec![Impression {
+                id: None,
+                uri: uri("imp"),
+            }],
+            errors: vec![ErrorElement {
+                uri: uri("err?code=[ERRORCODE]"),
+            }],
+            viewable_impression: Some(ViewableImpression {
+                viewable: vec![Viewable {
+                    uri: uri("viewable"),
+                }],
+                ..Default::default()
+            }),
+            creatives: Creatives {
+                creative: vec![Creative::new_linear(linear)],
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_full_playback_fires_each_tracker_once() {
+        let mut tracker = PlaybackTracker::new(&inline()).unwrap();
+        assert!(tracker.handle(PlayerEvent::Load).is_empty());
+        assert_eq!(
+            tracker.handle(PlayerEvent::Start),
+            vec![uri("imp"), uri("cv"), uri("start")]
+        );
+        assert_eq!(
+            tracker.handle(PlayerEvent::TimeUpdate(StdDuration::from_secs(6))),
+            vec![uri("q1"), uri("p5")]
+        );
+        assert!(
+            tracker
+                .handle(PlayerEvent::TimeUpdate(StdDuration::from_secs(6)))
+                .is_empty()
+        );
+        assert_eq!(tracker.handle(PlayerEvent::Pause), vec![uri("pause")]);
+        assert!(tracker.handle(PlayerEvent::Pause).is_empty());
+        assert_eq!(tracker.handle(PlayerEvent::Resume), vec![uri("resume")]);
+        assert_eq!(
+            tracker.handle(PlayerEvent::Complete),
+            vec![uri("mid"), uri("q3"), uri("complete")]
+        );
+        assert_eq!(tracker.state(), PlaybackState::Finished);
+        assert!(tracker.handle(PlayerEvent::Start).is_empty());
+        assert_eq!(
+            tracker.handle(PlayerEvent::Viewability(ViewabilityResult::Viewable)),
+            vec![uri("viewable")]
+        );
+        assert!(
+            tracker
+                .handle(PlayerEvent::Viewability(ViewabilityResult::Viewable))
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_error_substitutes_error_code() {
+        let mut tracker = PlaybackTracker::new(&inline()).unwrap();
+        tracker.handle(PlayerEvent::Start);
+        assert_eq!(
+            tracker.handle(PlayerEvent::Error(405)),
+            vec![uri("err?code=405")]
+        );
+        assert!(tracker.handle(PlayerEvent::Complete).is_empty());
+    }
+
+    #[test]
+    fn test_percentage_icon_offset_resolves_against_duration() {
+        let mut inline = inline();
+        let linear = inline.creatives.creative[0].linear.as_mut().unwrap();
+        linear.icons = Some(Icons {
+            icon: vec![Icon {
+                program: Some("AdChoices".to_string()),
+                width: 20,
+                height: 20,
+                x_position: IconPosition::Right,
+                y_position: IconPosition::Top,
+                duration: None,
+                offset: Some(TimeOffset::Percentage(50.0)),
+                api_framework: None,
+                pxratio: None,
+                static_resource: None,
+                iframe_resource: None,
+                html_resource: None,
+                icon_clicks: None,
+                icon_view_tracking: vec![IconViewTracking { uri: uri("icon") }],
+            }],
+        });
+
+        let mut tracker = PlaybackTracker::new(&inline).unwrap();
+        assert!(!tracker.handle(PlayerEvent::Start).contains(&uri("icon")));
+        let fired = tracker.handle(PlayerEvent::TimeUpdate(StdDuration::from_secs(6)));
+        assert!(!fired.contains(&uri("icon")));
+        let fired = tracker.handle(PlayerEvent::TimeUpdate(StdDuration::from_secs(10)));
+        assert!(fired.contains(&uri("icon")));
+    }
+}
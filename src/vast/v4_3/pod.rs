@@ -0,0 +1,374 @@
+//! Ad pod composition and sequencing
+//!
+//! An ad pod is the set of ads in a VAST response that carry a `sequence` attribute and
+//! are played back-to-back. Ads without a sequence form the buffet of standalone ads.
+
+use super::*;
+use crate::core::v2_6::enums::SlotPositionInPod;
+use crate::core::v2_6::{Bid, BidResponse, Video};
+use std::time::Duration as StdDuration;
+
+impl Ad {
+    /// Duration of the first linear creative of an InLine ad
+    ///
+    /// Returns `Ok(None)` for wrappers and ads without a linear creative.
+    pub fn linear_duration(&self) -> Result<Option<StdDuration>, VastError> {
+        self.inline
+            .iter()
+            .flat_map(|inline| inline.creatives.creative.iter())
+            .find_map(|c| c.linear.as_ref())
+            .map(|linear| linear.duration.to_std())
+            .transpose()
+    }
+}
+
+impl Vast {
+    /// Ads that belong to the pod, ordered by their `sequence` attribute
+    pub fn pod(&self) -> Vec<&Ad> {
+        let mut pod: Vec<&Ad> = self.ads.iter().filter(|ad| ad.sequence.is_some()).collect();
+        pod.sort_by_key(|ad| ad.sequence);
+        pod
+    }
+
+    /// Standalone ads of the buffet that are not part of the pod
+    pub fn standalone_ads(&self) -> Vec<&Ad> {
+        self.ads.iter().filter(|ad| ad.sequence.is_none()).collect()
+    }
+
+    /// Total linear duration of the pod
+    ///
+    /// Pod ads whose duration is unknown (e.g. wrappers) are not counted.
+    pub fn pod_duration(&self) -> Result<StdDuration, VastError> {
+        let mut total = StdDuration::ZERO;
+        for ad in self.pod() {
+            total += ad.linear_duration()?.unwrap_or_default();
+        }
+        Ok(total)
+    }
+}
+
+/// Limits that an assembled pod must respect
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PodConstraints {
+    /// Maximum total duration of the pod
+    pub max_duration: Option<StdDuration>,
+
+    /// Maximum number of ads in the pod
+    pub max_ads: Option<usize>,
+
+    /// Maximum duration of each slot, in playback order; when set, the pod has
+    /// exactly this many slots
+    pub slot_durations: Vec<StdDuration>,
+}
+
+impl PodConstraints {
+    /// Derive constraints from the pod fields of an OpenRTB 2.6 `Video` object
+    pub fn from_video(video: &Video) -> Self {
+        PodConstraints {
+            max_duration: video.poddur.map(|d| StdDuration::from_secs(u64::from(d))),
+            max_ads: video.maxseq.map(|n| n as usize),
+            slot_durations: Vec::new(),
+        }
+    }
+}
+
+/// An ad offered for inclusion in a pod
+#[derive(Debug, Clone, PartialEq)]
+pub struct PodCandidate {
+    /// The ad, inline or wrapper
+    pub ad: Ad,
+
+    /// Duration of the ad; required for wrappers, derived from the linear creative otherwise
+    pub duration: Option<StdDuration>,
+
+    /// Position in the pod the ad is eligible for
+    pub position: SlotPositionInPod,
+}
+
+impl PodCandidate {
+    /// Create a candidate that may play in any position
+    pub fn new(ad: Ad) -> Self {
+        PodCandidate {
+            ad,
+            duration: None,
+            position: SlotPositionInPod::Any,
+        }
+    }
+
+    /// Create a candidate from a bid whose `adm` holds a VAST document
+    ///
+    /// The first ad of the document is used. `dur` and `slotinpod` of the bid are carried over.
+    pub fn from_bid(bid: &Bid) -> Result<Self, VastError> {
+        let adm = bid
+            .adm
+            .as_deref()
+            .ok_or_else(|| VastError::MissingElement("adm".to_string()))?;
+        let vast: Vast = adm.parse()?;
+        let ad = vast
+            .ads
+            .into_iter()
+            .next()
+            .ok_or_else(|| VastError::MissingElement("Ad".to_string()))?;
+
+        Ok(PodCandidate {
+            ad,
+            duration: bid.dur.map(|d| StdDuration::from_secs(u64::from(d))),
+            position: bid.slotinpod.unwrap_or(SlotPositionInPod::Any),
+        })
+    }
+
+    fn resolved_duration(&self) -> Result<Option<StdDuration>, VastError> {
+        match self.duration {
+            Some(d) => Ok(Some(d)),
+            None => self.ad.linear_duration(),
+        }
+    }
+}
+
+/// Reason a candidate was left out of the pod
+#[derive(Debug, Clone, PartialEq)]
+pub enum PodRejection {
+    /// The duration of the ad could not be determined
+    UnknownDuration,
+    /// The ad duration could not be parsed
+    InvalidDuration(String),
+    /// Adding the ad would exceed the maximum pod duration
+    PodDurationExceeded,
+    /// The pod already holds the maximum number of ads
+    PodFull,
+    /// No free slot matches the ad's position and duration
+    NoMatchingSlot,
+}
+
+/// Result of assembling a pod from candidates
+#[derive(Debug, Clone, PartialEq)]
+pub struct PodComposition {
+    /// Ads of the pod in playback order, with `sequence` assigned from 1
+    ///
+    /// When the constraints give per-slot durations, `sequence` is the number of the
+    /// slot the ad was placed in, so unfilled slots leave gaps.
+    pub ads: Vec<Ad>,
+
+    /// Total linear duration of the pod
+    pub duration: StdDuration,
+
+    /// Candidates that were not included, with the reason
+    pub rejected: Vec<(PodCandidate, PodRejection)>,
+}
+
+impl PodComposition {
+    /// Assemble a pod from candidates given in priority order
+    ///
+    /// Candidates are placed greedily: each one takes the first free slot that matches
+    /// its position requirement and duration, as long as the pod limits allow it.
+    pub fn compose(candidates: Vec<PodCandidate>, constraints: &PodConstraints) -> Self {
+        let slot_count = if constraints.slot_durations.is_empty() {
+            constraints.max_ads.unwrap_or(candidates.len())
+        } else {
+            constraints
+                .max_ads
+                .map_or(constraints.slot_durations.len(), |n| {
+                    n.min(constraints.slot_durations.len())
+                })
+        };
+        let slot_cap = |index: usize| {
+            constraints
+                .slot_durations
+                .get(index)
+                .copied()
+                .unwrap_or(StdDuration::MAX)
+        };
+
+        let mut slots: Vec<Option<(PodCandidate, StdDuration)>> = vec![None; slot_count];
+        let mut total = StdDuration::ZERO;
+        let mut placed = 0;
+        let mut rejected = Vec::new();
+
+        for candidate in candidates {
+            let duration = match candidate.resolved_duration() {
+                Ok(Some(d)) => d,
+                Ok(None) => {
+                    rejected.push((candidate, PodRejection::UnknownDuration));
+                    continue;
+                }
+                Err(e) => {
+                    rejected.push((candidate, PodRejection::InvalidDuration(e.to_string())));
+                    continue;
+                }
+            };
+            if placed >= slot_count {
+                rejected.push((candidate, PodRejection::PodFull));
+                continue;
+            }
+            if constraints
+                .max_duration
+                .is_some_and(|max| total + duration > max)
+            {
+                rejected.push((candidate, PodRejection::PodDurationExceeded));
+                continue;
+            }
+
+            // Slots are filled from the middle for "any" ads so that the ends remain
+            // available for ads that require them.
+            let last = slot_count - 1;
+            let order: Vec<usize> = match candidate.position {
+                SlotPositionInPod::First => vec![0],
+                SlotPositionInPod::Last => vec![last],
+                SlotPositionInPod::FirstOrLast => vec![0, last],
+                SlotPositionInPod::Any => (1..last).chain([0, last]).collect(),
+            };
+            let slot = order
+                .into_iter()
+                .find(|&i| slots[i].is_none() && duration <= slot_cap(i));
+
+            match slot {
+                Some(i) => {
+                    total += duration;
+                    placed += 1;
+                    slots[i] = Some((candidate, duration));
+                }
+                None => rejected.push((candidate, PodRejection::NoMatchingSlot)),
+            }
+        }
+
+        // With per-slot durations an ad keeps the number of the slot it was checked
+        // against; otherwise the filled slots are numbered contiguously.
+        let numbered = !constraints.slot_durations.is_empty();
+        let ads = slots
+            .into_iter()
+            .enumerate()
+            .filter_map(|(i, slot)| Some((i, slot?.0)))
+            .enumerate()
+            .map(|(n, (i, candidate))| {
+                let mut ad = candidate.ad;
+                ad.sequence = Some(if numbered { i } else { n } as u32 + 1);
+                ad
+            })
+            .collect();
+
+        PodComposition {
+            ads,
+            duration: total,
+            rejected,
+        }
+    }
+
+    /// Assemble a pod from all bids of a bid response, highest price first
+    ///
+    /// Bids whose `adm` cannot be parsed as VAST are skipped.
+    pub fn from_bid_response(response: &BidResponse, constraints: &PodConstraints) -> Self {
+        let mut bids: Vec<&Bid> = response
+            .seatbid
+            .iter()
+            .flatten()
+            .flat_map(|seat| seat.bid.iter())
+            .collect();
+        bids.sort_by(|a, b| b.price.total_cmp(&a.price));
+
+        let candidates = bids
+            .into_iter()
+            .filter_map(|bid| PodCandidate::from_bid(bid).ok())
+            .collect();
+        Self::compose(candidates, constraints)
+    }
+
+    /// Build a VAST document holding the pod followed by the given standalone ads
+    pub fn into_vast(self, standalone: Vec<Ad>) -> Vast {
+        let mut vast = Vast::new();
+        vast.ads = self.ads;
+        vast.ads.extend(standalone.into_iter().map(|mut ad| {
+            ad.sequence = None;
+            ad
+        }));
+        vast
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidate(id: &str, secs: u64, position: SlotPositionInPod) -> PodCandidate {
+        PodCandidate {
+            ad: Ad::new_wrapper(id.to_string(), Wrapper::default()),
+            duration: Some(StdDuration::from_secs(secs)),
+            position,
+        }
+    }
+
+    #[test]
+    fn test_compose_respects_positions_and_duration() {
+        let constraints = PodConstraints {
+            max_duration: Some(StdDuration::from_secs(60)),
+            max_ads: Some(3),
+            slot_durations: Vec::new(),
+        };
+        let pod = PodComposition::compose(
+            vec![
+                candidate("a", 15, SlotPositionInPod::Any),
+                candidate("last", 15, SlotPositionInPod::Last),
+                candidate("b", 35, SlotPositionInPod::Any),
+                candidate("first", 15, SlotPositionInPod::First),
+                candidate("c", 15, SlotPositionInPod::Any),
+            ],
+            &constraints,
+        );
+
+        let ids: Vec<_> = pod.ads.iter().map(|ad| ad.id.as_str()).collect();
+        assert_eq!(ids, vec!["first", "a", "last"]);
+        assert_eq!(
+            pod.ads.iter().map(|ad| ad.sequence).collect::<Vec<_>>(),
+            vec![Some(1), Some(2), Some(3)]
+        );
+        assert_eq!(pod.duration, StdDuration::from_secs(45));
+        assert_eq!(pod.rejected.len(), 2);
+        assert_eq!(pod.rejected[0].1, PodRejection::PodDurationExceeded);
+        assert_eq!(pod.rejected[1].1, PodRejection::PodFull);
+
+        let vast = pod.into_vast(vec![Ad::new_wrapper(
+            "buffet".to_string(),
+            Wrapper::default(),
+        )]);
+        assert_eq!(vast.pod().len(), 3);
+        assert_eq!(vast.standalone_ads()[0].id, "buffet");
+    }
+
+    #[test]
+    fn test_compose_with_slot_durations() {
+        let constraints = PodConstraints {
+            slot_durations: vec![StdDuration::from_secs(15), StdDuration::from_secs(30)],
+            ..Default::default()
+        };
+        let pod = PodComposition::compose(
+            vec![
+                candidate("long", 30, SlotPositionInPod::First),
+                candidate("short", 15, SlotPositionInPod::Any),
+                candidate("long2", 30, SlotPositionInPod::Any),
+            ],
+            &constraints,
+        );
+
+        let ids: Vec<_> = pod.ads.iter().map(|ad| ad.id.as_str()).collect();
+        assert_eq!(ids, vec!["short", "long2"]);
+        assert_eq!(pod.rejected[0].1, PodRejection::NoMatchingSlot);
+    }
+
+    #[test]
+    fn test_slot_durations_keep_slot_sequence() {
+        let constraints = PodConstraints {
+            slot_durations: vec![
+                StdDuration::from_secs(15),
+                StdDuration::from_secs(30),
+                StdDuration::from_secs(15),
+            ],
+            ..Default::default()
+        };
+        let pod = PodComposition::compose(
+            vec![candidate("long", 30, SlotPositionInPod::Any)],
+            &constraints,
+        );
+
+        assert_eq!(pod.ads.len(), 1);
+        assert_eq!(pod.ads[0].sequence, Some(2));
+    }
+}
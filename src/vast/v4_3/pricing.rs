@@ -1,6 +1,7 @@
 //! Pricing information for ads
 
 use super::enums::*;
+use crate::currency::{Currency, CurrencyError, RateTable, Rounding};
 use serde::{Deserialize, Serialize};
 
 /// Pricing models
@@ -68,3 +69,21 @@ pub struct Pricing {
     #[serde(rename = "$value")]
     pub value: f64,
 }
+
+impl Pricing {
+    /// Parsed ISO-4217 currency of the price
+    pub fn iso_currency(&self) -> Result<Currency, CurrencyError> {
+        self.currency.parse()
+    }
+
+    /// Price converted into `target`, rounded down
+    pub fn value_in(
+        &self,
+        target: Currency,
+        rates: &RateTable,
+        rounding: Rounding,
+    ) -> Result<f64, CurrencyError> {
+        let value = rates.convert(self.value, self.iso_currency()?, target)?;
+        Ok(target.round_down(value, rounding))
+    }
+}
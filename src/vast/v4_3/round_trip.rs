@@ -0,0 +1,276 @@
+//! Lossless VAST round-tripping
+//!
+//! Serializing a [`Vast`] through serde drops CDATA sections, unknown elements and
+//! attributes, and reformats the document. A [`VastDocument`] keeps the source it was
+//! parsed from and, as long as the parsed model is left unmodified, re-emits that source
+//! with only URI and markup values rewritten into CDATA sections.
+
+use super::*;
+use quick_xml::escape::{escape, unescape};
+use quick_xml::events::{BytesCData, Event};
+use quick_xml::{DeError, Reader, Writer};
+
+/// Elements whose text content is a URI or markup that should be emitted in CDATA
+const CDATA_ELEMENTS: &[&str] = &[
+    "AdParameters",
+    "ClickThrough",
+    "ClickTracking",
+    "ClosedCaptionFile",
+    "CompanionClickThrough",
+    "CompanionClickTracking",
+    "CustomClick",
+    "Error",
+    "ExecutableResource",
+    "HTMLResource",
+    "IconClickThrough",
+    "IconClickTracking",
+    "IconViewTracking",
+    "IFrameResource",
+    "Impression",
+    "InteractiveCreativeFile",
+    "JavaScriptResource",
+    "MediaFile",
+    "Mezzanine",
+    "NonLinearClickThrough",
+    "NonLinearClickTracking",
+    "NotViewable",
+    "StaticResource",
+    "Tracking",
+    "VASTAdTagURI",
+    "ViewUndetermined",
+    "Viewable",
+];
+
+/// Elements whose content is opaque and copied without rewriting
+const OPAQUE_ELEMENTS: &[&str] = &["CreativeExtension", "Extension"];
+
+/// A parsed VAST document that remembers its source
+#[derive(Debug, Clone, PartialEq)]
+pub struct VastDocument {
+    /// The parsed document; changes to it are picked up by [`VastDocument::to_xml`]
+    pub vast: Vast,
+
+    source: String,
+    original: Vast,
+}
+
+impl VastDocument {
+    /// Parse a VAST document, keeping its source for lossless serialization
+    pub fn parse(xml: &str) -> Result<Self, VastError> {
+        let vast: Vast = xml.parse()?;
+        Ok(VastDocument {
+            original: vast.clone(),
+            vast,
+            source: xml.to_string(),
+        })
+    }
+
+    /// The XML the document was parsed from
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Whether `vast` differs from what was parsed
+    pub fn is_modified(&self) -> bool {
+        self.vast != self.original
+    }
+
+    /// Serialize the document
+    ///
+    /// An unmodified document is re-emitted from its source, keeping the declaration,
+    /// comments, namespaces, attribute order, unknown elements and existing CDATA
+    /// sections. A modified document is serialized from `vast`, so content the model does
+    /// not represent is lost. In both cases URI and markup values are emitted in CDATA.
+    pub fn to_xml(&self) -> Result<String, VastError> {
+        if self.is_modified() {
+            self.vast.to_xml_with_cdata()
+        } else {
+            Ok(wrap_in_cdata(&self.source)?)
+        }
+    }
+}
+
+impl std::str::FromStr for VastDocument {
+    type Err = VastError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl std::fmt::Display for VastDocument {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let xml = self.to_xml().map_err(|_| std::fmt::Error)?;
+        write!(f, "{}", xml)
+    }
+}
+
+impl Vast {
+    /// Serialize the document with URI and markup values emitted in CDATA
+    pub fn to_xml_with_cdata(&self) -> Result<String, VastError> {
+        let xml = quick_xml::se::to_string(self)?;
+        let xml = restore_extension_content(&xml).map_err(DeError::from)?;
+        Ok(wrap_in_cdata(&xml)?)
+    }
+}
+
+/// Rewrite the text content of URI and markup elements as CDATA sections
+///
+/// Everything else is copied from the input as is.
+fn wrap_in_cdata(xml: &str) -> Result<String, DeError> {
+    let mut reader = Reader::from_str(xml);
+    let mut writer = Writer::new(Vec::new());
+    let write_err = |e: std::io::Error| DeError::from(quick_xml::Error::from(e));
+
+    // Events seen inside the current CDATA element, and its content in escaped form
+    let mut pending: Option<(Vec<Event>, String, bool)> = None;
+    let mut opaque_depth = 0usize;
+
+    loop {
+        let event = reader.read_event()?;
+        if let Event::Eof = event {
+            break;
+        }
+
+        if let Some((events, content, text_only)) = pending.as_mut() {
+            match &event {
+                Event::Text(text) => content.push_str(&text.decode()?),
+                Event::GeneralRef(reference) => {
+                    content.push('&');
+                    content.push_str(&reference.decode()?);
+                    content.push(';');
+                }
+                Event::CData(cdata) => content.push_str(&escape(cdata.decode()?)),
+                Event::End(_) => {}
+                _ => *text_only = false,
+            }
+
+            if let Event::End(_) = event {
+                let (events, content, text_only) = pending.take().unwrap_or_default();
+                let value = unescape(&content)?;
+                let value = value.trim();
+                if text_only && !value.is_empty() {
+                    writer.write_event(events[0].clone()).map_err(write_err)?;
+                    for cdata in BytesCData::escaped(value) {
+                        writer.write_event(Event::CData(cdata)).map_err(write_err)?;
+                    }
+                } else {
+                    for event in events {
+                        writer.write_event(event).map_err(write_err)?;
+                    }
+                }
+                writer.write_event(event).map_err(write_err)?;
+            } else if *text_only {
+                events.push(event);
+            } else {
+                // Unexpected child elements; copy the element through unchanged
+                let events = std::mem::take(events);
+                pending = None;
+                let depth = if let Event::Start(_) = event { 2 } else { 1 };
+                for event in events {
+                    writer.write_event(event).map_err(write_err)?;
+                }
+                writer.write_event(event).map_err(write_err)?;
+                copy_until_end(&mut reader, &mut writer, depth)?;
+            }
+            continue;
+        }
+
+        match &event {
+            Event::Start(start) => {
+                let name = start.local_name();
+                let name = std::str::from_utf8(name.as_ref()).unwrap_or_default();
+                if opaque_depth > 0 || OPAQUE_ELEMENTS.contains(&name) {
+                    opaque_depth += 1;
+                } else if CDATA_ELEMENTS.contains(&name) {
+                    pending = Some((vec![event], String::new(), true));
+                    continue;
+                }
+            }
+            Event::End(_) if opaque_depth > 0 => opaque_depth -= 1,
+            _ => {}
+        }
+        writer.write_event(event).map_err(write_err)?;
+    }
+
+    String::from_utf8(writer.into_inner())
+        .map_err(|e| DeError::Custom(format!("invalid UTF-8 in output: {}", e)))
+}
+
+/// Copy events until the element `depth` levels up is closed
+fn copy_until_end(
+    reader: &mut Reader<&[u8]>,
+    writer: &mut Writer<Vec<u8>>,
+    mut depth: usize,
+) -> Result<(), DeError> {
+    while depth > 0 {
+        let event = reader.read_event()?;
+        match event {
+            Event::Start(_) => depth += 1,
+            Event::End(_) => depth -= 1,
+            Event::Eof => break,
+            _ => {}
+        }
+        writer
+            .write_event(event)
+            .map_err(|e| DeError::from(quick_xml::Error::from(e)))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VAST: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<!-- served by example -->
+<VAST version="4.3" xmlns:x="urn:example">
+  <Ad id="1" x:trace="abc">
+    <InLine>
+      <AdSystem version="2">Sys</AdSystem>
+      <AdTitle>Title</AdTitle>
+      <Impression id="imp">https://example.com/imp?a=1&amp;b=2</Impression>
+      <x:Unknown flag="yes"><x:Child/></x:Unknown>
+      <Creatives>
+        <Creative>
+          <Linear>
+            <Duration>00:00:15</Duration>
+            <MediaFiles>
+              <MediaFile delivery="progressive" type="video/mp4" width="640" height="360"><![CDATA[https://example.com/ad.mp4]]></MediaFile>
+            </MediaFiles>
+          </Linear>
+        </Creative>
+      </Creatives>
+    </InLine>
+  </Ad>
+</VAST>"#;
+
+    #[test]
+    fn test_unmodified_document_keeps_source() {
+        let document = VastDocument::parse(VAST).unwrap();
+        let xml = document.to_xml().unwrap();
+
+        let expected = VAST.replace(
+            "https://example.com/imp?a=1&amp;b=2",
+            "<![CDATA[https://example.com/imp?a=1&b=2]]>",
+        );
+        assert_eq!(xml, expected);
+
+        let reparsed = VastDocument::parse(&xml).unwrap();
+        assert_eq!(reparsed.vast, document.vast);
+    }
+
+    #[test]
+    fn test_modified_document_uses_cdata() {
+        let mut document = VastDocument::parse(VAST).unwrap();
+        document.vast.ads[0].id = "2".to_string();
+        assert!(document.is_modified());
+
+        let xml = document.to_xml().unwrap();
+        assert!(xml.contains(
+            "<Impression id=\"imp\"><![CDATA[https://example.com/imp?a=1&b=2]]></Impression>"
+        ));
+        assert!(xml.contains("<![CDATA[https://example.com/ad.mp4]]>"));
+        assert_eq!(xml.parse::<Vast>().unwrap(), document.vast);
+    }
+}
@@ -0,0 +1,228 @@
+//! HTTPS enforcement for impressions that require secure creative assets
+
+use super::*;
+use crate::core::v2_6::enums::SecureFlag;
+
+/// How insecure URIs are handled by [`Vast::enforce_https`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct HttpsPolicy {
+    /// Rewrite `http://` and protocol-relative URIs to `https://`
+    pub upgrade: bool,
+
+    /// Hosts known not to serve HTTPS; their URIs are never upgraded
+    pub insecure_hosts: Vec<String>,
+
+    /// Remove media files that remain insecure
+    pub remove_insecure_media: bool,
+}
+
+impl Default for HttpsPolicy {
+    fn default() -> Self {
+        HttpsPolicy {
+            upgrade: true,
+            insecure_hosts: Vec::new(),
+            remove_insecure_media: true,
+        }
+    }
+}
+
+impl HttpsPolicy {
+    /// The HTTPS form of an insecure URI, when the policy allows upgrading it
+    fn upgraded(&self, uri: &str) -> Option<String> {
+        if !self.upgrade {
+            return None;
+        }
+        let rest = strip_prefix_ignore_case(uri, "http://").or_else(|| uri.strip_prefix("//"))?;
+        let upgraded = format!("https://{}", rest);
+        let host = url::Url::parse(&upgraded).ok()?.host_str()?.to_string();
+        if self
+            .insecure_hosts
+            .iter()
+            .any(|h| h.eq_ignore_ascii_case(&host))
+        {
+            return None;
+        }
+        Some(upgraded)
+    }
+}
+
+fn strip_prefix_ignore_case<'a>(s: &'a str, prefix: &str) -> Option<&'a str> {
+    let head = s.get(..prefix.len())?;
+    head.eq_ignore_ascii_case(prefix)
+        .then(|| &s[prefix.len()..])
+}
+
+/// Whether a URI would be fetched over plain HTTP
+///
+/// Protocol-relative URIs are considered insecure since they follow the page scheme.
+pub fn is_insecure_uri(uri: &Uri) -> bool {
+    let uri = uri.as_str().trim();
+    strip_prefix_ignore_case(uri, "http://").is_some() || uri.starts_with("//")
+}
+
+/// An insecure URI found in a document
+#[derive(Debug, Clone, PartialEq)]
+pub struct InsecureUri {
+    /// Role of the URI in the document
+    pub role: UriRole,
+
+    /// The URI as found in the document
+    pub uri: String,
+
+    /// The HTTPS URI it was rewritten to, if it was upgraded
+    pub upgraded: Option<String>,
+}
+
+/// Result of [`Vast::enforce_https`]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HttpsReport {
+    /// Every insecure URI found, whether or not it was upgraded or removed
+    pub insecure: Vec<InsecureUri>,
+
+    /// Media files that were removed because they could not be served securely
+    pub removed_media_files: Vec<MediaFile>,
+
+    /// Creatives that were removed because removing insecure media files left their
+    /// linear creative without a media file
+    pub removed_creatives: Vec<Creative>,
+
+    /// InLine ads that were removed because all of their creatives were removed
+    pub removed_ads: Vec<Ad>,
+
+    /// Whether the document contains no insecure URIs after the transformation
+    pub secure: bool,
+}
+
+impl HttpsReport {
+    /// Whether the document satisfies an impression's `secure` requirement
+    pub fn satisfies(&self, secure: Option<SecureFlag>) -> bool {
+        secure != Some(SecureFlag::Secure) || self.secure
+    }
+}
+
+impl Vast {
+    /// Whether every URI of the document uses HTTPS
+    pub fn is_secure(&self) -> bool {
+        !self.uris().any(|(_, uri)| is_insecure_uri(uri))
+    }
+
+    /// Report insecure URIs, upgrade them to HTTPS and drop insecure media files
+    /// according to the policy
+    ///
+    /// A creative whose linear creative loses its last media file is removed rather than
+    /// left unplayable, and so is an InLine ad that loses its last creative.
+    pub fn enforce_https(&mut self, policy: &HttpsPolicy) -> HttpsReport {
+        let mut report = HttpsReport::default();
+
+        self.for_each_uri_mut(|role, uri| {
+            if !is_insecure_uri(uri) {
+                return;
+            }
+            let upgraded = policy.upgraded(uri.as_str().trim());
+            report.insecure.push(InsecureUri {
+                role,
+                uri: uri.0.clone(),
+                upgraded: upgraded.clone(),
+            });
+            if let Some(upgraded) = upgraded {
+                uri.0 = upgraded;
+            }
+        });
+
+        if policy.remove_insecure_media {
+            for inline in self.ads.iter_mut().filter_map(|ad| ad.inline.as_mut()) {
+                let creatives = std::mem::take(&mut inline.creatives.creative);
+                for mut creative in creatives {
+                    let Some(linear) = creative.linear.as_mut() else {
+                        inline.creatives.creative.push(creative);
+                        continue;
+                    };
+                    let (secure, insecure): (Vec<_>, Vec<_>) =
+                        std::mem::take(&mut linear.media_files.media_file)
+                            .into_iter()
+                            .partition(|f| !is_insecure_uri(&f.uri));
+                    let emptied = secure.is_empty() && !insecure.is_empty();
+                    linear.media_files.media_file = secure;
+                    report.removed_media_files.extend(insecure);
+                    if emptied {
+                        report.removed_creatives.push(creative);
+                    } else {
+                        inline.creatives.creative.push(creative);
+                    }
+                }
+            }
+            let (kept, removed) = std::mem::take(&mut self.ads).into_iter().partition(|ad| {
+                ad.inline
+                    .as_ref()
+                    .is_none_or(|inline| !inline.creatives.creative.is_empty())
+            });
+            self.ads = kept;
+            report.removed_ads = removed;
+        }
+
+        report.secure = self.is_secure();
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VAST: &str = r#"<VAST version="4.3"><Ad id="1"><InLine><AdSystem>Sys</AdSystem><AdTitle>Title</AdTitle><Impression>http://track.example.com/imp</Impression><Creatives><Creative><Linear><Duration>00:00:15</Duration><MediaFiles><MediaFile delivery="progressive" type="video/mp4">http://legacy.example.com/ad.mp4</MediaFile><MediaFile delivery="progressive" type="video/mp4">https://cdn.example.com/ad.mp4</MediaFile></MediaFiles><TrackingEvents><Tracking event="start">//track.example.com/start</Tracking></TrackingEvents></Linear></Creative></Creatives></InLine></Ad></VAST>"#;
+
+    #[test]
+    fn test_enforce_https() {
+        let mut vast: Vast = VAST.parse().unwrap();
+        assert!(!vast.is_secure());
+
+        let policy = HttpsPolicy {
+            insecure_hosts: vec!["legacy.example.com".to_string()],
+            ..Default::default()
+        };
+        let report = vast.enforce_https(&policy);
+
+        assert_eq!(report.insecure.len(), 3);
+        assert_eq!(report.insecure[0].role, UriRole::Impression);
+        assert_eq!(
+            report.insecure[2].upgraded.as_deref(),
+            Some("https://track.example.com/start")
+        );
+        assert_eq!(report.removed_media_files.len(), 1);
+        assert!(report.satisfies(Some(SecureFlag::Secure)));
+        assert!(vast.is_secure());
+
+        let media_files = &vast.ads[0].inline.as_ref().unwrap().creatives.creative[0]
+            .linear
+            .as_ref()
+            .unwrap()
+            .media_files;
+        assert_eq!(media_files.media_file.len(), 1);
+    }
+
+    #[test]
+    fn test_enforce_https_removes_emptied_creatives() {
+        let mut vast: Vast = VAST.parse().unwrap();
+        let mut ad = vast.ads[0].clone();
+        ad.id = "2".to_string();
+        let linear = ad.inline.as_mut().unwrap().creatives.creative[0]
+            .linear
+            .as_mut()
+            .unwrap();
+        linear.media_files.media_file.truncate(1);
+        vast.ads.push(ad);
+
+        let policy = HttpsPolicy {
+            upgrade: false,
+            ..Default::default()
+        };
+        let report = vast.enforce_https(&policy);
+
+        assert_eq!(report.removed_media_files.len(), 2);
+        assert_eq!(report.removed_creatives.len(), 1);
+        assert_eq!(report.removed_ads.len(), 1);
+        assert_eq!(report.removed_ads[0].id, "2");
+        assert_eq!(vast.ads.len(), 1);
+        assert!(!report.secure);
+    }
+}
@@ -0,0 +1,268 @@
+//! Streaming parser for large multi-ad VAST responses
+//!
+//! [`VastReader`] pulls `Ad` elements one at a time from any [`BufRead`] instead of
+//! deserializing the whole document, so callers can stop after the first ads and skip
+//! the bodies of creatives they do not need.
+
+use super::*;
+use quick_xml::Reader;
+use quick_xml::Writer;
+use quick_xml::errors::IllFormedError;
+use quick_xml::events::{BytesStart, Event};
+use std::io::BufRead;
+
+/// Pull parser yielding the `Ad` elements of a VAST document
+///
+/// The root attributes and VAST-level `Error` elements seen so far are available
+/// through [`VastReader::root`].
+pub struct VastReader<R> {
+    reader: Reader<R>,
+    buf: Vec<u8>,
+    skip_buf: Vec<u8>,
+    root: Option<Vast>,
+    skip_creatives: bool,
+    done: bool,
+}
+
+impl<R: BufRead> VastReader<R> {
+    /// Create a reader over a buffered source
+    pub fn new(source: R) -> Self {
+        VastReader {
+            reader: Reader::from_reader(source),
+            buf: Vec::new(),
+            skip_buf: Vec::new(),
+            root: None,
+            skip_creatives: false,
+            done: false,
+        }
+    }
+
+    /// Skip `Creative` elements without building them
+    ///
+    /// The yielded ads keep their `Creatives` container, left empty.
+    pub fn skip_creatives(mut self, skip: bool) -> Self {
+        self.skip_creatives = skip;
+        self
+    }
+
+    /// The root element with its attributes and the `Error` elements read so far
+    ///
+    /// `ads` is always empty. Returns `None` until the root element has been read.
+    pub fn root(&self) -> Option<&Vast> {
+        self.root.as_ref()
+    }
+
+    /// Read the next `Ad` element
+    ///
+    /// Returns `Ok(None)` once the root element is closed. After an error, no further
+    /// ads are read.
+    pub fn next_ad(&mut self) -> Result<Option<Ad>, VastError> {
+        if self.done {
+            return Ok(None);
+        }
+        let result = self.read_ad();
+        if !matches!(result, Ok(Some(_))) {
+            self.done = true;
+        }
+        result
+    }
+
+    fn read_ad(&mut self) -> Result<Option<Ad>, VastError> {
+        if self.root.is_none() && !self.read_root()? {
+            return Ok(None);
+        }
+
+        loop {
+            self.buf.clear();
+            let (start, empty) = match self
+                .reader
+                .read_event_into(&mut self.buf)
+                .map_err(xml_error)?
+            {
+                Event::Start(start) => (start.into_owned(), false),
+                Event::Empty(start) => (start.into_owned(), true),
+                Event::End(_) | Event::Eof => return Ok(None),
+                _ => continue,
+            };
+
+            let xml = self.capture(start.clone(), empty)?;
+            match start.local_name().as_ref() {
+                b"Ad" => {
+                    let xml = capture_extension_content(&xml).map_err(xml_error)?;
+                    return Ok(Some(quick_xml::de::from_str(&xml)?));
+                }
+                b"Error" => {
+                    let error = quick_xml::de::from_str(&xml)?;
+                    if let Some(root) = self.root.as_mut() {
+                        root.errors.push(error);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Read up to the root element, returning whether it has content
+    fn read_root(&mut self) -> Result<bool, VastError> {
+        loop {
+            self.buf.clear();
+            let (start, empty) = match self
+                .reader
+                .read_event_into(&mut self.buf)
+                .map_err(xml_error)?
+            {
+                Event::Start(start) => (start.into_owned(), false),
+                Event::Empty(start) => (start.into_owned(), true),
+                Event::Eof => return Err(VastError::MissingElement("VAST".to_string())),
+                _ => continue,
+            };
+            if start.local_name().as_ref() != b"VAST" {
+                return Err(VastError::MissingElement("VAST".to_string()));
+            }
+
+            let mut writer = Writer::new(Vec::new());
+            writer.write_event(Event::Empty(start)).map_err(xml_error)?;
+            self.root = Some(quick_xml::de::from_str(&into_string(writer)?)?);
+            return Ok(!empty);
+        }
+    }
+
+    /// Copy an element and its content, dropping creatives when configured to
+    fn capture(&mut self, start: BytesStart<'static>, empty: bool) -> Result<String, VastError> {
+        let mut writer = Writer::new(Vec::new());
+        if empty {
+            writer.write_event(Event::Empty(start)).map_err(xml_error)?;
+            return into_string(writer);
+        }
+
+        writer
+            .write_event(Event::Start(start.borrow()))
+            .map_err(xml_error)?;
+        let mut depth = 1;
+        while depth > 0 {
+            self.buf.clear();
+            let event = self
+                .reader
+                .read_event_into(&mut self.buf)
+                .map_err(xml_error)?;
+            match &event {
+                Event::Start(e)
+                    if self.skip_creatives && e.local_name().as_ref() == b"Creative" =>
+                {
+                    let end = e.to_end().into_owned();
+                    self.skip_buf.clear();
+                    self.reader
+                        .read_to_end_into(end.name(), &mut self.skip_buf)
+                        .map_err(xml_error)?;
+                    continue;
+                }
+                Event::Empty(e)
+                    if self.skip_creatives && e.local_name().as_ref() == b"Creative" =>
+                {
+                    continue;
+                }
+                Event::Start(_) => depth += 1,
+                Event::End(_) => depth -= 1,
+                Event::Eof => {
+                    let name = String::from_utf8_lossy(start.name().as_ref()).into_owned();
+                    return Err(xml_error(IllFormedError::MissingEndTag(name)));
+                }
+                _ => {}
+            }
+            writer.write_event(event).map_err(xml_error)?;
+        }
+        into_string(writer)
+    }
+}
+
+impl<R: BufRead> Iterator for VastReader<R> {
+    type Item = Result<Ad, VastError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_ad().transpose()
+    }
+}
+
+fn xml_error(error: impl Into<quick_xml::Error>) -> VastError {
+    VastError::XmlParse(quick_xml::DeError::from(error.into()))
+}
+
+fn into_string(writer: Writer<Vec<u8>>) -> Result<String, VastError> {
+    String::from_utf8(writer.into_inner())
+        .map_err(|e| VastError::XmlParse(quick_xml::DeError::Custom(e.to_string())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VAST: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<VAST version="4.3" id="buffet">
+  <Ad id="1" sequence="1">
+    <Wrapper>
+      <AdSystem>Sys</AdSystem>
+      <VASTAdTagURI><![CDATA[https://example.com/vast?a=1&b=2]]></VASTAdTagURI>
+      <Creatives><Creative><Linear><Duration>00:00:15</Duration><MediaFiles/></Linear></Creative></Creatives>
+    </Wrapper>
+  </Ad>
+  <Error>https://example.com/error</Error>
+  <Ad id="2">
+    <InLine>
+      <AdSystem>Sys</AdSystem>
+      <AdTitle>Tom &amp; Jerry</AdTitle>
+      <Impression>https://example.com/imp</Impression>
+      <Creatives><Creative><Linear><Duration>00:00:15</Duration><MediaFiles><MediaFile delivery="progressive" type="video/mp4">https://example.com/ad.mp4</MediaFile></MediaFiles></Linear></Creative></Creatives>
+    </InLine>
+  </Ad>
+  <Ad id="3"><InLine><AdSystem>Sys</AdSystem><AdTitle>Unread</AdTitle><Creatives/></InLine></Ad>
+</VAST>"#;
+
+    #[test]
+    fn test_stream_ads() {
+        let mut reader = VastReader::new(VAST.as_bytes());
+        let ads: Vec<Ad> = reader.by_ref().take(2).collect::<Result<_, _>>().unwrap();
+        assert_eq!(ads[0].id, "1");
+        assert_eq!(
+            ads[0]
+                .wrapper
+                .as_ref()
+                .unwrap()
+                .vast_ad_tag_uri
+                .uri
+                .as_str(),
+            "https://example.com/vast?a=1&b=2"
+        );
+        let inline = ads[1].inline.as_ref().unwrap();
+        assert_eq!(inline.ad_title.as_ref().unwrap().value, "Tom & Jerry");
+        assert_eq!(inline.creatives.creative.len(), 1);
+
+        let root = reader.root().unwrap();
+        assert_eq!(root.id.as_deref(), Some("buffet"));
+        assert_eq!(root.errors.len(), 1);
+
+        let skipped: Vec<Ad> = VastReader::new(VAST.as_bytes())
+            .skip_creatives(true)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(skipped.len(), 3);
+        assert!(
+            skipped[1]
+                .inline
+                .as_ref()
+                .unwrap()
+                .creatives
+                .creative
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_stream_missing_root() {
+        let mut reader = VastReader::new("<VMAP/>".as_bytes());
+        assert!(matches!(
+            reader.next_ad(),
+            Err(VastError::MissingElement(_))
+        ));
+        assert!(reader.next().is_none());
+    }
+}
@@ -0,0 +1,195 @@
+//! Time offsets used by tracking, skip and icon attributes
+
+use super::*;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::time::Duration as StdDuration;
+
+/// A point in time relative to the start of a linear creative.
+///
+/// VAST expresses offsets either as a clock value (`HH:MM:SS` or `HH:MM:SS.mmm`),
+/// a percentage of the creative duration (e.g. `25%`), or the `start`/`end` keywords.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TimeOffset {
+    /// The start of the creative
+    Start,
+    /// The end of the creative
+    End,
+    /// An absolute offset from the start of the creative
+    Time(StdDuration),
+    /// A percentage (0-100) of the creative duration
+    Percentage(f32),
+}
+
+impl TimeOffset {
+    /// Returns the offset as a duration when it does not depend on the creative length
+    pub fn to_duration(&self) -> Option<StdDuration> {
+        match self {
+            TimeOffset::Start => Some(StdDuration::ZERO),
+            TimeOffset::Time(d) => Some(*d),
+            TimeOffset::End | TimeOffset::Percentage(_) => None,
+        }
+    }
+
+    /// Compute the absolute trigger time for a creative of the given length
+    ///
+    /// Offsets past the end of the creative are clamped to its duration.
+    pub fn resolve(&self, creative_duration: StdDuration) -> StdDuration {
+        let offset = match self {
+            TimeOffset::Start => StdDuration::ZERO,
+            TimeOffset::End => creative_duration,
+            TimeOffset::Time(d) => *d,
+            TimeOffset::Percentage(p) => {
+                creative_duration.mul_f64(f64::from(p.clamp(0.0, 100.0)) / 100.0)
+            }
+        };
+        offset.min(creative_duration)
+    }
+}
+
+/// Parse a VAST clock value (`HH:MM:SS` or `HH:MM:SS.mmm`)
+pub(crate) fn parse_clock(s: &str) -> Option<StdDuration> {
+    let mut parts = s.trim().split(':');
+    let hours = parts.next()?.parse::<u64>().ok()?;
+    let minutes = parts.next()?.parse::<u64>().ok()?;
+    let seconds = parts.next()?;
+    if parts.next().is_some() || minutes >= 60 {
+        return None;
+    }
+
+    let (secs, millis) = match seconds.split_once('.') {
+        Some((secs, frac)) => {
+            if frac.is_empty() || frac.len() > 3 || !frac.bytes().all(|b| b.is_ascii_digit()) {
+                return None;
+            }
+            let scale = 10u64.pow(3 - frac.len() as u32);
+            (secs, frac.parse::<u64>().ok()? * scale)
+        }
+        None => (seconds, 0),
+    };
+    let secs = secs.parse::<u64>().ok()?;
+    if secs >= 60 {
+        return None;
+    }
+
+    Some(StdDuration::from_millis(
+        ((hours * 60 + minutes) * 60 + secs) * 1000 + millis,
+    ))
+}
+
+/// Format a duration as a VAST clock value, including milliseconds only when present
+pub(crate) fn format_clock(d: StdDuration) -> String {
+    let total = d.as_secs();
+    let millis = d.subsec_millis();
+    let (hours, minutes, seconds) = (total / 3600, (total / 60) % 60, total % 60);
+    if millis == 0 {
+        format!("{:02}:{:02}:{:02}", hours, minutes, seconds)
+    } else {
+        format!("{:02}:{:02}:{:02}.{:03}", hours, minutes, seconds, millis)
+    }
+}
+
+impl fmt::Display for TimeOffset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TimeOffset::Start => write!(f, "start"),
+            TimeOffset::End => write!(f, "end"),
+            TimeOffset::Time(d) => write!(f, "{}", format_clock(*d)),
+            TimeOffset::Percentage(p) => write!(f, "{}%", p),
+        }
+    }
+}
+
+impl std::str::FromStr for TimeOffset {
+    type Err = VastError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        Ok(match trimmed {
+            "start" => TimeOffset::Start,
+            "end" => TimeOffset::End,
+            _ => match trimmed.strip_suffix('%') {
+                Some(p) => p
+                    .trim()
+                    .parse::<f32>()
+                    .ok()
+                    .filter(|p| (0.0..=100.0).contains(p))
+                    .map(TimeOffset::Percentage)
+                    .ok_or_else(|| VastError::InvalidTimeOffset(s.to_string()))?,
+                None => parse_clock(trimmed)
+                    .map(TimeOffset::Time)
+                    .ok_or_else(|| VastError::InvalidTimeOffset(s.to_string()))?,
+            },
+        })
+    }
+}
+
+impl From<StdDuration> for TimeOffset {
+    fn from(d: StdDuration) -> Self {
+        TimeOffset::Time(d)
+    }
+}
+
+impl Serialize for TimeOffset {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for TimeOffset {
+    fn deserialize<D>(deserializer: D) -> Result<TimeOffset, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        value.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_clock_with_millis() {
+        let offset: TimeOffset = "00:01:02.250".parse().unwrap();
+        assert_eq!(offset, TimeOffset::Time(StdDuration::from_millis(62_250)));
+        assert_eq!(offset.to_string(), "00:01:02.250");
+
+        let offset: TimeOffset = "01:00:05".parse().unwrap();
+        assert_eq!(offset.to_duration(), Some(StdDuration::from_secs(3605)));
+        assert_eq!(offset.to_string(), "01:00:05");
+    }
+
+    #[test]
+    fn test_parse_keywords_and_percentages() {
+        assert_eq!("start".parse::<TimeOffset>().unwrap(), TimeOffset::Start);
+        assert_eq!("end".parse::<TimeOffset>().unwrap(), TimeOffset::End);
+        assert_eq!(
+            "25%".parse::<TimeOffset>().unwrap(),
+            TimeOffset::Percentage(25.0)
+        );
+        assert!("150%".parse::<TimeOffset>().is_err());
+        assert!("00:61:00".parse::<TimeOffset>().is_err());
+        assert!("00:00:01.5000".parse::<TimeOffset>().is_err());
+        assert!("soon".parse::<TimeOffset>().is_err());
+    }
+
+    #[test]
+    fn test_resolve_against_creative_duration() {
+        let length = StdDuration::from_secs(30);
+        assert_eq!(TimeOffset::Start.resolve(length), StdDuration::ZERO);
+        assert_eq!(TimeOffset::End.resolve(length), length);
+        assert_eq!(
+            TimeOffset::Percentage(50.0).resolve(length),
+            StdDuration::from_secs(15)
+        );
+        assert_eq!(
+            TimeOffset::Time(StdDuration::from_secs(45)).resolve(length),
+            length
+        );
+    }
+}
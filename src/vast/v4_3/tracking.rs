@@ -22,7 +22,7 @@ pub struct Tracking {
 
     /// Offset for progress events (percentage or time)
     #[serde(rename = "@offset", skip_serializing_if = "Option::is_none")]
-    pub offset: Option<String>,
+    pub offset: Option<TimeOffset>,
 
     /// The tracking URI
     #[serde(rename = "$value")]
@@ -47,13 +47,30 @@ impl TrackingEvents {
     }
 
     /// Add a tracking event with offset
-    pub fn add_tracking_with_offset(&mut self, event: TrackingEvent, uri: Uri, offset: String) {
+    pub fn add_tracking_with_offset(&mut self, event: TrackingEvent, uri: Uri, offset: TimeOffset) {
         self.tracking.push(Tracking {
             event,
             offset: Some(offset),
             uri,
         });
     }
+
+    /// Absolute trigger times of all `progress` events for a creative of the given length
+    ///
+    /// Events are returned in playback order. Progress events without an offset are skipped.
+    pub fn progress_schedule(
+        &self,
+        creative_duration: std::time::Duration,
+    ) -> Vec<(std::time::Duration, &Tracking)> {
+        let mut schedule: Vec<_> = self
+            .tracking
+            .iter()
+            .filter(|t| t.event == TrackingEvent::Progress)
+            .filter_map(|t| Some((t.offset?.resolve(creative_duration), t)))
+            .collect();
+        schedule.sort_by_key(|(at, _)| *at);
+        schedule
+    }
 }
 
 impl Default for TrackingEvents {
@@ -61,4 +78,3 @@ impl Default for TrackingEvents {
         Self::new()
     }
 }
-
@@ -67,10 +67,9 @@ impl Default for Vast {
 
 impl std::fmt::Display for Vast {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match quick_xml::se::to_string(self) {
-            Ok(s) => write!(f, "{}", s),
-            Err(_) => Err(std::fmt::Error),
-        }
+        let xml = quick_xml::se::to_string(self).map_err(|_| std::fmt::Error)?;
+        let xml = restore_extension_content(&xml).map_err(|_| std::fmt::Error)?;
+        write!(f, "{}", xml)
     }
 }
 
@@ -78,7 +77,7 @@ impl std::str::FromStr for Vast {
     type Err = quick_xml::DeError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        quick_xml::de::from_str(s)
+        quick_xml::de::from_str(&capture_extension_content(s)?)
     }
 }
 
@@ -99,12 +99,12 @@ pub struct VerificationTrackingEvents {
 }
 
 /// A verification tracking event
-#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename = "Tracking")]
 pub struct VerificationTracking {
-    /// The event type (e.g., "verificationNotExecuted")
+    /// The event type
     #[serde(rename = "@event")]
-    pub event: String,
+    pub event: VerificationEvent,
 
     /// The tracking URI
     #[serde(rename = "$value")]
@@ -119,3 +119,217 @@ pub struct VerificationParameters {
     #[serde(rename = "$value")]
     pub parameters: String,
 }
+
+impl AdVerifications {
+    /// Append the verifications of another container, skipping exact duplicates
+    ///
+    /// Used to aggregate the verifications of every ad in a wrapper chain.
+    pub fn merge(&mut self, other: &AdVerifications) {
+        for verification in &other.verification {
+            if !self.verification.contains(verification) {
+                self.verification.push(verification.clone());
+            }
+        }
+    }
+
+    /// Aggregate the verifications of a wrapper chain, outermost wrapper first
+    pub fn from_chain<'a>(ads: impl IntoIterator<Item = &'a Ad>) -> Self {
+        let mut merged = AdVerifications::default();
+        for ad in ads {
+            let wrapper = ad
+                .wrapper
+                .as_ref()
+                .and_then(|w| w.ad_verifications.as_ref());
+            let inline = ad.inline.as_ref().and_then(|i| i.ad_verifications.as_ref());
+            for verifications in wrapper.into_iter().chain(inline) {
+                merged.merge(verifications);
+            }
+        }
+        merged
+    }
+
+    /// Split the verifications into those the player can execute and those it cannot
+    pub fn select(&self, capabilities: &VerificationCapabilities) -> VerificationSelection<'_> {
+        let mut selection = VerificationSelection {
+            executable: Vec::new(),
+            not_executed: Vec::new(),
+        };
+        for verification in &self.verification {
+            match verification.check(capabilities) {
+                Ok(()) => selection.executable.push(verification),
+                Err(reason) => selection.not_executed.push((verification, reason)),
+            }
+        }
+        selection
+    }
+}
+
+impl Verification {
+    /// Check whether the player can execute at least one resource of the verification
+    pub fn check(
+        &self,
+        capabilities: &VerificationCapabilities,
+    ) -> Result<(), VerificationNotExecutedReason> {
+        if capabilities
+            .blocked_vendors
+            .iter()
+            .any(|v| v.eq_ignore_ascii_case(&self.vendor))
+        {
+            return Err(VerificationNotExecutedReason::ResourceRejected);
+        }
+
+        let javascript = self.javascript_resources.iter().any(|r| {
+            capabilities.accepts_api(&r.api_framework.0)
+                && (capabilities.browser || r.browser_optional == Some(true))
+        });
+        let executable = self.executable_resources.iter().any(|r| {
+            capabilities.accepts_api(&r.api_framework.0)
+                && r.mime_type.as_deref().is_some_and(|t| {
+                    capabilities
+                        .executable_types
+                        .iter()
+                        .any(|e| e.eq_ignore_ascii_case(t))
+                })
+        });
+
+        if javascript || executable {
+            Ok(())
+        } else {
+            Err(VerificationNotExecutedReason::NotSupported)
+        }
+    }
+
+    /// The `verificationNotExecuted` trackers with `[REASON]` replaced by the reason code
+    pub fn not_executed_uris(&self, reason: VerificationNotExecutedReason) -> Vec<Uri> {
+        let code = reason.code().to_string();
+        self.tracking_events
+            .iter()
+            .flat_map(|events| events.tracking.iter())
+            .filter(|t| t.event == VerificationEvent::VerificationNotExecuted)
+            .map(|t| Uri(t.uri.0.replace("[REASON]", &code)))
+            .collect()
+    }
+}
+
+/// Verification capabilities of the player
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct VerificationCapabilities {
+    /// Supported `apiFramework` values of verification resources (e.g., "omid")
+    pub api_frameworks: Vec<String>,
+
+    /// Whether resources run in a browser context; when false only JavaScript
+    /// resources marked `browserOptional` can run
+    pub browser: bool,
+
+    /// MIME types of `ExecutableResource` files the player can run
+    pub executable_types: Vec<String>,
+
+    /// Vendors the publisher does not allow to run
+    pub blocked_vendors: Vec<String>,
+}
+
+impl VerificationCapabilities {
+    /// Derive capabilities from the `api` list of an OpenRTB 2.6 impression
+    ///
+    /// OMID support is taken from the presence of `Omid1`; a browser context is assumed.
+    pub fn from_api(api: &[crate::core::v2_6::enums::ApiFramework]) -> Self {
+        let omid = api.contains(&crate::core::v2_6::enums::ApiFramework::Omid1);
+        VerificationCapabilities {
+            api_frameworks: if omid {
+                vec!["omid".to_string()]
+            } else {
+                Vec::new()
+            },
+            browser: true,
+            ..Default::default()
+        }
+    }
+
+    fn accepts_api(&self, api: &str) -> bool {
+        self.api_frameworks
+            .iter()
+            .any(|a| a.eq_ignore_ascii_case(api.trim()))
+    }
+}
+
+/// Verifications split by whether the player can execute them
+#[derive(Debug, Clone, PartialEq)]
+pub struct VerificationSelection<'a> {
+    /// Verifications with at least one resource the player can execute
+    pub executable: Vec<&'a Verification>,
+
+    /// Verifications that will not run, with the reason reported to the vendor
+    pub not_executed: Vec<(&'a Verification, VerificationNotExecutedReason)>,
+}
+
+impl VerificationSelection<'_> {
+    /// The `verificationNotExecuted` pings to fire for the skipped verifications
+    pub fn not_executed_uris(&self) -> Vec<Uri> {
+        self.not_executed
+            .iter()
+            .flat_map(|(verification, reason)| verification.not_executed_uris(*reason))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn verification(vendor: &str, api: &str, browser_optional: Option<bool>) -> Verification {
+        Verification {
+            vendor: vendor.to_string(),
+            javascript_resources: vec![JavaScriptResource {
+                api_framework: ApiFramework(api.to_string()),
+                browser_optional,
+                uri: Uri(format!("https://{}/omid.js", vendor)),
+            }],
+            tracking_events: Some(VerificationTrackingEvents {
+                tracking: vec![VerificationTracking {
+                    event: VerificationEvent::VerificationNotExecuted,
+                    uri: Uri(format!("https://{}/ne?r=[REASON]", vendor)),
+                }],
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_merge_chain_and_select() {
+        let shared = verification("shared.com", "omid", None);
+        let wrapper = Wrapper {
+            ad_verifications: Some(AdVerifications {
+                verification: vec![shared.clone(), verification("vpaid.com", "vpaid", None)],
+            }),
+            ..Default::default()
+        };
+        let inline = InLine {
+            ad_verifications: Some(AdVerifications {
+                verification: vec![shared, verification("blocked.com", "omid", Some(true))],
+            }),
+            ..Default::default()
+        };
+        let chain = [
+            Ad::new_wrapper("w".to_string(), wrapper),
+            Ad::new_inline("i".to_string(), inline),
+        ];
+
+        let merged = AdVerifications::from_chain(&chain);
+        assert_eq!(merged.verification.len(), 3);
+
+        let capabilities = VerificationCapabilities {
+            blocked_vendors: vec!["blocked.com".to_string()],
+            ..VerificationCapabilities::from_api(&[crate::core::v2_6::enums::ApiFramework::Omid1])
+        };
+        let selection = merged.select(&capabilities);
+        assert_eq!(selection.executable.len(), 1);
+        assert_eq!(selection.executable[0].vendor, "shared.com");
+        assert_eq!(
+            selection.not_executed_uris(),
+            vec![
+                Uri("https://vpaid.com/ne?r=2".to_string()),
+                Uri("https://blocked.com/ne?r=1".to_string()),
+            ]
+        );
+    }
+}
@@ -0,0 +1,737 @@
+//! Traversal of the VAST element tree
+//!
+//! [`Visitor`] walks a document by shared reference and [`VisitorMut`] by mutable
+//! reference. Every element type has a `visit_*` hook whose default implementation
+//! descends into the element's children through the matching `walk_*` function, so an
+//! implementation only overrides the hooks it cares about and calls the `walk_*`
+//! function when it still wants the children to be visited. Every [`Uri`] is reported
+//! to `visit_uri` together with its [`UriRole`].
+
+use super::*;
+
+/// The meaning of a URI within a VAST document
+#[derive(Debug, Clone, PartialEq)]
+pub enum UriRole {
+    /// Error tracker of the document or of an ad
+    Error,
+    /// Impression tracker
+    Impression,
+    /// Location of the next VAST document of a wrapper
+    VastAdTagUri,
+    /// Viewable impression tracker
+    Viewable,
+    /// Not viewable impression tracker
+    NotViewable,
+    /// Undetermined viewability tracker
+    ViewUndetermined,
+    /// Verification script
+    VerificationScript,
+    /// Verification executable
+    VerificationExecutable,
+    /// Verification tracking event
+    VerificationTracking(VerificationEvent),
+    /// Linear media file
+    MediaFile,
+    /// Mezzanine file for server-side ad insertion
+    Mezzanine,
+    /// Interactive creative file
+    InteractiveCreativeFile,
+    /// Closed caption file
+    ClosedCaptionFile,
+    /// Linear click-through landing page
+    ClickThrough,
+    /// Linear click tracker
+    ClickTracking,
+    /// Custom click tracker
+    CustomClick,
+    /// Creative tracking event
+    Tracking(TrackingEvent),
+    /// Static resource of an icon, non-linear or companion
+    StaticResource,
+    /// IFrame resource of an icon, non-linear or companion
+    IFrameResource,
+    /// Icon click-through landing page
+    IconClickThrough,
+    /// Icon click tracker
+    IconClickTracking,
+    /// Icon view tracker
+    IconViewTracking,
+    /// Non-linear click-through landing page
+    NonLinearClickThrough,
+    /// Non-linear click tracker
+    NonLinearClickTracking,
+    /// Companion click-through landing page
+    CompanionClickThrough,
+    /// Companion click tracker
+    CompanionClickTracking,
+}
+
+impl UriRole {
+    /// Name of the element holding a URI with this role
+    pub fn element(&self) -> &'static str {
+        match self {
+            UriRole::Error => "Error",
+            UriRole::Impression => "Impression",
+            UriRole::VastAdTagUri => "VASTAdTagURI",
+            UriRole::Viewable => "Viewable",
+            UriRole::NotViewable => "NotViewable",
+            UriRole::ViewUndetermined => "ViewUndetermined",
+            UriRole::VerificationScript => "JavaScriptResource",
+            UriRole::VerificationExecutable => "ExecutableResource",
+            UriRole::VerificationTracking(_) => "Tracking",
+            UriRole::MediaFile => "MediaFile",
+            UriRole::Mezzanine => "Mezzanine",
+            UriRole::InteractiveCreativeFile => "InteractiveCreativeFile",
+            UriRole::ClosedCaptionFile => "ClosedCaptionFile",
+            UriRole::ClickThrough => "ClickThrough",
+            UriRole::ClickTracking => "ClickTracking",
+            UriRole::CustomClick => "CustomClick",
+            UriRole::Tracking(_) => "Tracking",
+            UriRole::StaticResource => "StaticResource",
+            UriRole::IFrameResource => "IFrameResource",
+            UriRole::IconClickThrough => "IconClickThrough",
+            UriRole::IconClickTracking => "IconClickTracking",
+            UriRole::IconViewTracking => "IconViewTracking",
+            UriRole::NonLinearClickThrough => "NonLinearClickThrough",
+            UriRole::NonLinearClickTracking => "NonLinearClickTracking",
+            UriRole::CompanionClickThrough => "CompanionClickThrough",
+            UriRole::CompanionClickTracking => "CompanionClickTracking",
+        }
+    }
+}
+
+/// Generate a visitor trait and its `walk_*` functions for one kind of reference
+macro_rules! visitor {
+    ($visitor:ident, $($lt:lifetime)?; $($m:tt)?) => {
+        use super::*;
+
+        /// Hooks called for every element of a VAST document
+        #[allow(unused_variables)]
+        pub trait $visitor $(<$lt>)? {
+            fn visit_vast(&mut self, node: &$($lt)? $($m)? Vast) {
+                walk_vast(self, node)
+            }
+            fn visit_vast_error(&mut self, node: &$($lt)? $($m)? Error) {
+                self.visit_uri(&$($m)? node.uri, UriRole::Error)
+            }
+            fn visit_ad(&mut self, node: &$($lt)? $($m)? Ad) {
+                walk_ad(self, node)
+            }
+            fn visit_inline(&mut self, node: &$($lt)? $($m)? InLine) {
+                walk_inline(self, node)
+            }
+            fn visit_wrapper(&mut self, node: &$($lt)? $($m)? Wrapper) {
+                walk_wrapper(self, node)
+            }
+            fn visit_ad_system(&mut self, node: &$($lt)? $($m)? AdSystem) {}
+            fn visit_wrapper_ad_system(&mut self, node: &$($lt)? $($m)? WrapperAdSystem) {}
+            fn visit_advertiser(&mut self, node: &$($lt)? $($m)? Advertiser) {}
+            fn visit_category(&mut self, node: &$($lt)? $($m)? Category) {}
+            fn visit_blocked_ad_categories(&mut self, node: &$($lt)? $($m)? BlockedAdCategories) {}
+            fn visit_pricing(&mut self, node: &$($lt)? $($m)? Pricing) {}
+            fn visit_impression(&mut self, node: &$($lt)? $($m)? Impression) {
+                self.visit_uri(&$($m)? node.uri, UriRole::Impression)
+            }
+            fn visit_wrapper_impression(&mut self, node: &$($lt)? $($m)? WrapperImpression) {
+                self.visit_uri(&$($m)? node.uri, UriRole::Impression)
+            }
+            fn visit_error_element(&mut self, node: &$($lt)? $($m)? ErrorElement) {
+                self.visit_uri(&$($m)? node.uri, UriRole::Error)
+            }
+            fn visit_wrapper_error(&mut self, node: &$($lt)? $($m)? WrapperError) {
+                self.visit_uri(&$($m)? node.uri, UriRole::Error)
+            }
+            fn visit_vast_ad_tag_uri(&mut self, node: &$($lt)? $($m)? VASTAdTagURI) {
+                self.visit_uri(&$($m)? node.uri, UriRole::VastAdTagUri)
+            }
+            fn visit_viewable_impression(&mut self, node: &$($lt)? $($m)? ViewableImpression) {
+                walk_viewable_impression(self, node)
+            }
+            fn visit_viewable(&mut self, node: &$($lt)? $($m)? Viewable) {
+                self.visit_uri(&$($m)? node.uri, UriRole::Viewable)
+            }
+            fn visit_not_viewable(&mut self, node: &$($lt)? $($m)? NotViewable) {
+                self.visit_uri(&$($m)? node.uri, UriRole::NotViewable)
+            }
+            fn visit_view_undetermined(&mut self, node: &$($lt)? $($m)? ViewUndetermined) {
+                self.visit_uri(&$($m)? node.uri, UriRole::ViewUndetermined)
+            }
+            fn visit_ad_verifications(&mut self, node: &$($lt)? $($m)? AdVerifications) {
+                walk_ad_verifications(self, node)
+            }
+            fn visit_verification(&mut self, node: &$($lt)? $($m)? Verification) {
+                walk_verification(self, node)
+            }
+            fn visit_javascript_resource(&mut self, node: &$($lt)? $($m)? JavaScriptResource) {
+                self.visit_uri(&$($m)? node.uri, UriRole::VerificationScript)
+            }
+            fn visit_executable_resource(&mut self, node: &$($lt)? $($m)? ExecutableResource) {
+                self.visit_uri(&$($m)? node.uri, UriRole::VerificationExecutable)
+            }
+            fn visit_verification_tracking(&mut self, node: &$($lt)? $($m)? VerificationTracking) {
+                self.visit_uri(
+                    &$($m)? node.uri,
+                    UriRole::VerificationTracking(node.event),
+                )
+            }
+            fn visit_verification_parameters(
+                &mut self,
+                node: &$($lt)? $($m)? VerificationParameters,
+            ) {
+            }
+            fn visit_extensions(&mut self, node: &$($lt)? $($m)? Extensions) {
+                for extension in &$($m)? node.extension {
+                    self.visit_extension(extension);
+                }
+            }
+            fn visit_extension(&mut self, node: &$($lt)? $($m)? Extension) {}
+            fn visit_creatives(&mut self, node: &$($lt)? $($m)? Creatives) {
+                for creative in &$($m)? node.creative {
+                    self.visit_creative(creative);
+                }
+            }
+            fn visit_creative(&mut self, node: &$($lt)? $($m)? Creative) {
+                walk_creative(self, node)
+            }
+            fn visit_universal_ad_id(&mut self, node: &$($lt)? $($m)? UniversalAdId) {}
+            fn visit_creative_extensions(&mut self, node: &$($lt)? $($m)? CreativeExtensions) {
+                for extension in &$($m)? node.creative_extension {
+                    self.visit_creative_extension(extension);
+                }
+            }
+            fn visit_creative_extension(&mut self, node: &$($lt)? $($m)? CreativeExtension) {}
+            fn visit_linear(&mut self, node: &$($lt)? $($m)? Linear) {
+                walk_linear(self, node)
+            }
+            fn visit_media_files(&mut self, node: &$($lt)? $($m)? MediaFiles) {
+                walk_media_files(self, node)
+            }
+            fn visit_media_file(&mut self, node: &$($lt)? $($m)? MediaFile) {
+                self.visit_uri(&$($m)? node.uri, UriRole::MediaFile)
+            }
+            fn visit_mezzanine(&mut self, node: &$($lt)? $($m)? Mezzanine) {
+                self.visit_uri(&$($m)? node.uri, UriRole::Mezzanine)
+            }
+            fn visit_interactive_creative_file(
+                &mut self,
+                node: &$($lt)? $($m)? InteractiveCreativeFile,
+            ) {
+                self.visit_uri(&$($m)? node.uri, UriRole::InteractiveCreativeFile)
+            }
+            fn visit_closed_caption_file(&mut self, node: &$($lt)? $($m)? ClosedCaptionFile) {
+                self.visit_uri(&$($m)? node.uri, UriRole::ClosedCaptionFile)
+            }
+            fn visit_ad_parameters(&mut self, node: &$($lt)? $($m)? AdParameters) {}
+            fn visit_video_clicks(&mut self, node: &$($lt)? $($m)? VideoClicks) {
+                walk_video_clicks(self, node)
+            }
+            fn visit_click_through(&mut self, node: &$($lt)? $($m)? ClickThrough) {
+                self.visit_uri(&$($m)? node.uri, UriRole::ClickThrough)
+            }
+            fn visit_click_tracking(&mut self, node: &$($lt)? $($m)? ClickTracking) {
+                self.visit_uri(&$($m)? node.uri, UriRole::ClickTracking)
+            }
+            fn visit_custom_click(&mut self, node: &$($lt)? $($m)? CustomClick) {
+                self.visit_uri(&$($m)? node.uri, UriRole::CustomClick)
+            }
+            fn visit_tracking_events(&mut self, node: &$($lt)? $($m)? TrackingEvents) {
+                for tracking in &$($m)? node.tracking {
+                    self.visit_tracking(tracking);
+                }
+            }
+            fn visit_tracking(&mut self, node: &$($lt)? $($m)? Tracking) {
+                self.visit_uri(&$($m)? node.uri, UriRole::Tracking(node.event.clone()))
+            }
+            fn visit_icons(&mut self, node: &$($lt)? $($m)? Icons) {
+                for icon in &$($m)? node.icon {
+                    self.visit_icon(icon);
+                }
+            }
+            fn visit_icon(&mut self, node: &$($lt)? $($m)? Icon) {
+                walk_icon(self, node)
+            }
+            fn visit_icon_static_resource(&mut self, node: &$($lt)? $($m)? IconStaticResource) {
+                self.visit_uri(&$($m)? node.uri, UriRole::StaticResource)
+            }
+            fn visit_icon_iframe_resource(&mut self, node: &$($lt)? $($m)? IconIFrameResource) {
+                self.visit_uri(&$($m)? node.uri, UriRole::IFrameResource)
+            }
+            fn visit_icon_html_resource(&mut self, node: &$($lt)? $($m)? IconHTMLResource) {}
+            fn visit_icon_clicks(&mut self, node: &$($lt)? $($m)? IconClicks) {
+                walk_icon_clicks(self, node)
+            }
+            fn visit_icon_click_through(&mut self, node: &$($lt)? $($m)? IconClickThrough) {
+                self.visit_uri(&$($m)? node.uri, UriRole::IconClickThrough)
+            }
+            fn visit_icon_click_tracking(&mut self, node: &$($lt)? $($m)? IconClickTracking) {
+                self.visit_uri(&$($m)? node.uri, UriRole::IconClickTracking)
+            }
+            fn visit_icon_click_fallback_image(
+                &mut self,
+                node: &$($lt)? $($m)? IconClickFallbackImage,
+            ) {
+                if let Some(resource) = &$($m)? node.static_resource {
+                    self.visit_icon_static_resource(resource);
+                }
+            }
+            fn visit_icon_view_tracking(&mut self, node: &$($lt)? $($m)? IconViewTracking) {
+                self.visit_uri(&$($m)? node.uri, UriRole::IconViewTracking)
+            }
+            fn visit_non_linear_ads(&mut self, node: &$($lt)? $($m)? NonLinearAds) {
+                walk_non_linear_ads(self, node)
+            }
+            fn visit_non_linear(&mut self, node: &$($lt)? $($m)? NonLinear) {
+                walk_non_linear(self, node)
+            }
+            fn visit_static_resource(&mut self, node: &$($lt)? $($m)? StaticResource) {
+                self.visit_uri(&$($m)? node.uri, UriRole::StaticResource)
+            }
+            fn visit_iframe_resource(&mut self, node: &$($lt)? $($m)? IFrameResource) {
+                self.visit_uri(&$($m)? node.uri, UriRole::IFrameResource)
+            }
+            fn visit_html_resource(&mut self, node: &$($lt)? $($m)? HTMLResource) {}
+            fn visit_non_linear_click_through(
+                &mut self,
+                node: &$($lt)? $($m)? NonLinearClickThrough,
+            ) {
+                self.visit_uri(&$($m)? node.uri, UriRole::NonLinearClickThrough)
+            }
+            fn visit_non_linear_click_tracking(
+                &mut self,
+                node: &$($lt)? $($m)? NonLinearClickTracking,
+            ) {
+                self.visit_uri(&$($m)? node.uri, UriRole::NonLinearClickTracking)
+            }
+            fn visit_companion_ads(&mut self, node: &$($lt)? $($m)? CompanionAds) {
+                for companion in &$($m)? node.companion {
+                    self.visit_companion(companion);
+                }
+            }
+            fn visit_companion(&mut self, node: &$($lt)? $($m)? CompanionAd) {
+                walk_companion(self, node)
+            }
+            fn visit_companion_static_resource(
+                &mut self,
+                node: &$($lt)? $($m)? CompanionStaticResource,
+            ) {
+                self.visit_uri(&$($m)? node.uri, UriRole::StaticResource)
+            }
+            fn visit_companion_iframe_resource(
+                &mut self,
+                node: &$($lt)? $($m)? CompanionIFrameResource,
+            ) {
+                self.visit_uri(&$($m)? node.uri, UriRole::IFrameResource)
+            }
+            fn visit_companion_html_resource(
+                &mut self,
+                node: &$($lt)? $($m)? CompanionHTMLResource,
+            ) {}
+            fn visit_companion_click_through(
+                &mut self,
+                node: &$($lt)? $($m)? CompanionClickThrough,
+            ) {
+                self.visit_uri(&$($m)? node.uri, UriRole::CompanionClickThrough)
+            }
+            fn visit_companion_click_tracking(
+                &mut self,
+                node: &$($lt)? $($m)? CompanionClickTracking,
+            ) {
+                self.visit_uri(&$($m)? node.uri, UriRole::CompanionClickTracking)
+            }
+            fn visit_uri(&mut self, uri: &$($lt)? $($m)? Uri, role: UriRole) {}
+        }
+
+        pub fn walk_vast<$($lt,)? V: $visitor $(<$lt>)? + ?Sized>(
+            v: &mut V,
+            node: &$($lt)? $($m)? Vast,
+        ) {
+            for ad in &$($m)? node.ads {
+                v.visit_ad(ad);
+            }
+            for error in &$($m)? node.errors {
+                v.visit_vast_error(error);
+            }
+        }
+
+        pub fn walk_ad<$($lt,)? V: $visitor $(<$lt>)? + ?Sized>(
+            v: &mut V,
+            node: &$($lt)? $($m)? Ad,
+        ) {
+            if let Some(inline) = &$($m)? node.inline {
+                v.visit_inline(inline);
+            }
+            if let Some(wrapper) = &$($m)? node.wrapper {
+                v.visit_wrapper(wrapper);
+            }
+        }
+
+        pub fn walk_inline<$($lt,)? V: $visitor $(<$lt>)? + ?Sized>(
+            v: &mut V,
+            node: &$($lt)? $($m)? InLine,
+        ) {
+            v.visit_ad_system(&$($m)? node.ad_system);
+            if let Some(advertiser) = &$($m)? node.advertiser {
+                v.visit_advertiser(advertiser);
+            }
+            for impression in &$($m)? node.impressions {
+                v.visit_impression(impression);
+            }
+            for category in &$($m)? node.categories {
+                v.visit_category(category);
+            }
+            for error in &$($m)? node.errors {
+                v.visit_error_element(error);
+            }
+            if let Some(viewable) = &$($m)? node.viewable_impression {
+                v.visit_viewable_impression(viewable);
+            }
+            if let Some(verifications) = &$($m)? node.ad_verifications {
+                v.visit_ad_verifications(verifications);
+            }
+            if let Some(extensions) = &$($m)? node.extensions {
+                v.visit_extensions(extensions);
+            }
+            if let Some(pricing) = &$($m)? node.pricing {
+                v.visit_pricing(pricing);
+            }
+            v.visit_creatives(&$($m)? node.creatives);
+        }
+
+        pub fn walk_wrapper<$($lt,)? V: $visitor $(<$lt>)? + ?Sized>(
+            v: &mut V,
+            node: &$($lt)? $($m)? Wrapper,
+        ) {
+            v.visit_wrapper_ad_system(&$($m)? node.ad_system);
+            v.visit_vast_ad_tag_uri(&$($m)? node.vast_ad_tag_uri);
+            for impression in &$($m)? node.impressions {
+                v.visit_wrapper_impression(impression);
+            }
+            for error in &$($m)? node.errors {
+                v.visit_wrapper_error(error);
+            }
+            if let Some(viewable) = &$($m)? node.viewable_impression {
+                v.visit_viewable_impression(viewable);
+            }
+            if let Some(verifications) = &$($m)? node.ad_verifications {
+                v.visit_ad_verifications(verifications);
+            }
+            if let Some(extensions) = &$($m)? node.extensions {
+                v.visit_extensions(extensions);
+            }
+            if let Some(pricing) = &$($m)? node.pricing {
+                v.visit_pricing(pricing);
+            }
+            if let Some(creatives) = &$($m)? node.creatives {
+                v.visit_creatives(creatives);
+            }
+            for blocked in &$($m)? node.blocked_ad_categories {
+                v.visit_blocked_ad_categories(blocked);
+            }
+        }
+
+        pub fn walk_viewable_impression<$($lt,)? V: $visitor $(<$lt>)? + ?Sized>(
+            v: &mut V,
+            node: &$($lt)? $($m)? ViewableImpression,
+        ) {
+            for viewable in &$($m)? node.viewable {
+                v.visit_viewable(viewable);
+            }
+            for not_viewable in &$($m)? node.not_viewable {
+                v.visit_not_viewable(not_viewable);
+            }
+            for undetermined in &$($m)? node.view_undetermined {
+                v.visit_view_undetermined(undetermined);
+            }
+        }
+
+        pub fn walk_ad_verifications<$($lt,)? V: $visitor $(<$lt>)? + ?Sized>(
+            v: &mut V,
+            node: &$($lt)? $($m)? AdVerifications,
+        ) {
+            for verification in &$($m)? node.verification {
+                v.visit_verification(verification);
+            }
+        }
+
+        pub fn walk_verification<$($lt,)? V: $visitor $(<$lt>)? + ?Sized>(
+            v: &mut V,
+            node: &$($lt)? $($m)? Verification,
+        ) {
+            for resource in &$($m)? node.javascript_resources {
+                v.visit_javascript_resource(resource);
+            }
+            for resource in &$($m)? node.executable_resources {
+                v.visit_executable_resource(resource);
+            }
+            if let Some(events) = &$($m)? node.tracking_events {
+                for tracking in &$($m)? events.tracking {
+                    v.visit_verification_tracking(tracking);
+                }
+            }
+            if let Some(parameters) = &$($m)? node.verification_parameters {
+                v.visit_verification_parameters(parameters);
+            }
+        }
+
+        pub fn walk_creative<$($lt,)? V: $visitor $(<$lt>)? + ?Sized>(
+            v: &mut V,
+            node: &$($lt)? $($m)? Creative,
+        ) {
+            if let Some(universal_ad_id) = &$($m)? node.universal_ad_id {
+                v.visit_universal_ad_id(universal_ad_id);
+            }
+            if let Some(extensions) = &$($m)? node.creative_extensions {
+                v.visit_creative_extensions(extensions);
+            }
+            if let Some(linear) = &$($m)? node.linear {
+                v.visit_linear(linear);
+            }
+            if let Some(non_linear_ads) = &$($m)? node.non_linear_ads {
+                v.visit_non_linear_ads(non_linear_ads);
+            }
+            if let Some(companion_ads) = &$($m)? node.companion_ads {
+                v.visit_companion_ads(companion_ads);
+            }
+        }
+
+        pub fn walk_linear<$($lt,)? V: $visitor $(<$lt>)? + ?Sized>(
+            v: &mut V,
+            node: &$($lt)? $($m)? Linear,
+        ) {
+            v.visit_media_files(&$($m)? node.media_files);
+            if let Some(parameters) = &$($m)? node.ad_parameters {
+                v.visit_ad_parameters(parameters);
+            }
+            if let Some(clicks) = &$($m)? node.video_clicks {
+                v.visit_video_clicks(clicks);
+            }
+            if let Some(events) = &$($m)? node.tracking_events {
+                v.visit_tracking_events(events);
+            }
+            if let Some(icons) = &$($m)? node.icons {
+                v.visit_icons(icons);
+            }
+        }
+
+        pub fn walk_media_files<$($lt,)? V: $visitor $(<$lt>)? + ?Sized>(
+            v: &mut V,
+            node: &$($lt)? $($m)? MediaFiles,
+        ) {
+            for media_file in &$($m)? node.media_file {
+                v.visit_media_file(media_file);
+            }
+            if let Some(mezzanine) = &$($m)? node.mezzanine {
+                v.visit_mezzanine(mezzanine);
+            }
+            for file in &$($m)? node.interactive_creative_files {
+                v.visit_interactive_creative_file(file);
+            }
+            if let Some(files) = &$($m)? node.closed_caption_files {
+                for file in &$($m)? files.closed_caption_file {
+                    v.visit_closed_caption_file(file);
+                }
+            }
+        }
+
+        pub fn walk_video_clicks<$($lt,)? V: $visitor $(<$lt>)? + ?Sized>(
+            v: &mut V,
+            node: &$($lt)? $($m)? VideoClicks,
+        ) {
+            if let Some(click) = &$($m)? node.click_through {
+                v.visit_click_through(click);
+            }
+            for click in &$($m)? node.click_tracking {
+                v.visit_click_tracking(click);
+            }
+            for click in &$($m)? node.custom_clicks {
+                v.visit_custom_click(click);
+            }
+        }
+
+        pub fn walk_icon<$($lt,)? V: $visitor $(<$lt>)? + ?Sized>(
+            v: &mut V,
+            node: &$($lt)? $($m)? Icon,
+        ) {
+            if let Some(resource) = &$($m)? node.static_resource {
+                v.visit_icon_static_resource(resource);
+            }
+            if let Some(resource) = &$($m)? node.iframe_resource {
+                v.visit_icon_iframe_resource(resource);
+            }
+            if let Some(resource) = &$($m)? node.html_resource {
+                v.visit_icon_html_resource(resource);
+            }
+            if let Some(clicks) = &$($m)? node.icon_clicks {
+                v.visit_icon_clicks(clicks);
+            }
+            for tracking in &$($m)? node.icon_view_tracking {
+                v.visit_icon_view_tracking(tracking);
+            }
+        }
+
+        pub fn walk_icon_clicks<$($lt,)? V: $visitor $(<$lt>)? + ?Sized>(
+            v: &mut V,
+            node: &$($lt)? $($m)? IconClicks,
+        ) {
+            if let Some(click) = &$($m)? node.click_through {
+                v.visit_icon_click_through(click);
+            }
+            for click in &$($m)? node.click_tracking {
+                v.visit_icon_click_tracking(click);
+            }
+            if let Some(images) = &$($m)? node.fallback_images {
+                for image in &$($m)? images.fallback_image {
+                    v.visit_icon_click_fallback_image(image);
+                }
+            }
+        }
+
+        pub fn walk_non_linear_ads<$($lt,)? V: $visitor $(<$lt>)? + ?Sized>(
+            v: &mut V,
+            node: &$($lt)? $($m)? NonLinearAds,
+        ) {
+            if let Some(events) = &$($m)? node.tracking_events {
+                v.visit_tracking_events(events);
+            }
+            for non_linear in &$($m)? node.non_linear {
+                v.visit_non_linear(non_linear);
+            }
+        }
+
+        pub fn walk_non_linear<$($lt,)? V: $visitor $(<$lt>)? + ?Sized>(
+            v: &mut V,
+            node: &$($lt)? $($m)? NonLinear,
+        ) {
+            if let Some(resource) = &$($m)? node.static_resource {
+                v.visit_static_resource(resource);
+            }
+            if let Some(resource) = &$($m)? node.iframe_resource {
+                v.visit_iframe_resource(resource);
+            }
+            if let Some(resource) = &$($m)? node.html_resource {
+                v.visit_html_resource(resource);
+            }
+            if let Some(click) = &$($m)? node.click_through {
+                v.visit_non_linear_click_through(click);
+            }
+            for click in &$($m)? node.click_tracking {
+                v.visit_non_linear_click_tracking(click);
+            }
+            if let Some(parameters) = &$($m)? node.ad_parameters {
+                v.visit_ad_parameters(parameters);
+            }
+        }
+
+        pub fn walk_companion<$($lt,)? V: $visitor $(<$lt>)? + ?Sized>(
+            v: &mut V,
+            node: &$($lt)? $($m)? CompanionAd,
+        ) {
+            if let Some(resource) = &$($m)? node.static_resource {
+                v.visit_companion_static_resource(resource);
+            }
+            if let Some(resource) = &$($m)? node.iframe_resource {
+                v.visit_companion_iframe_resource(resource);
+            }
+            if let Some(resource) = &$($m)? node.html_resource {
+                v.visit_companion_html_resource(resource);
+            }
+            if let Some(click) = &$($m)? node.click_through {
+                v.visit_companion_click_through(click);
+            }
+            for click in &$($m)? node.click_tracking {
+                v.visit_companion_click_tracking(click);
+            }
+            if let Some(events) = &$($m)? node.tracking_events {
+                v.visit_tracking_events(events);
+            }
+            if let Some(parameters) = &$($m)? node.ad_parameters {
+                v.visit_ad_parameters(parameters);
+            }
+        }
+    };
+}
+
+/// Traversal by shared reference
+pub mod visit {
+    visitor!(Visitor, 'ast;);
+}
+
+/// Traversal by mutable reference
+pub mod visit_mut {
+    visitor!(VisitorMut, ; mut);
+}
+
+pub use visit::Visitor;
+pub use visit_mut::VisitorMut;
+
+struct UriCollector<'a> {
+    uris: Vec<(UriRole, &'a Uri)>,
+}
+
+impl<'a> Visitor<'a> for UriCollector<'a> {
+    fn visit_uri(&mut self, uri: &'a Uri, role: UriRole) {
+        self.uris.push((role, uri));
+    }
+}
+
+struct UriMapper<F> {
+    f: F,
+}
+
+impl<F: FnMut(UriRole, &mut Uri)> VisitorMut for UriMapper<F> {
+    fn visit_uri(&mut self, uri: &mut Uri, role: UriRole) {
+        (self.f)(role, uri)
+    }
+}
+
+impl Vast {
+    /// Every URI of the document with its role
+    ///
+    /// URIs come in the traversal order of [`Visitor`], which follows the fields of the
+    /// document model rather than the order of the elements in the XML source.
+    pub fn uris(&self) -> impl Iterator<Item = (UriRole, &Uri)> {
+        let mut collector = UriCollector { uris: Vec::new() };
+        collector.visit_vast(self);
+        collector.uris.into_iter()
+    }
+
+    /// Call `f` with every URI of the document and its role, in the order of
+    /// [`Vast::uris`]
+    pub fn for_each_uri_mut(&mut self, f: impl FnMut(UriRole, &mut Uri)) {
+        UriMapper { f }.visit_vast(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VAST: &str = r#"<VAST version="4.3"><Ad id="1"><Wrapper><AdSystem>Sys</AdSystem><VASTAdTagURI>https://example.com/vast</VASTAdTagURI><Impression>https://example.com/imp</Impression><Creatives><Creative><Linear><Duration>00:00:10</Duration><MediaFiles></MediaFiles><TrackingEvents><Tracking event="start">https://example.com/start</Tracking></TrackingEvents><VideoClicks><ClickTracking>https://example.com/click</ClickTracking></VideoClicks></Linear></Creative></Creatives></Wrapper></Ad><Error>https://example.com/error</Error></VAST>"#;
+
+    #[test]
+    fn test_uris_with_roles() {
+        let mut vast: Vast = VAST.parse().unwrap();
+        let roles: Vec<_> = vast.uris().map(|(role, _)| role).collect();
+        assert_eq!(
+            roles,
+            vec![
+                UriRole::VastAdTagUri,
+                UriRole::Impression,
+                UriRole::ClickTracking,
+                UriRole::Tracking(TrackingEvent::Start),
+                UriRole::Error,
+            ]
+        );
+
+        vast.for_each_uri_mut(|role, uri| {
+            if role == UriRole::Impression {
+                uri.0.push_str("?seen=1");
+            }
+        });
+        let impression = vast.uris().find(|(role, _)| *role == UriRole::Impression);
+        assert_eq!(
+            impression.unwrap().1.as_str(),
+            "https://example.com/imp?seen=1"
+        );
+    }
+}
@@ -0,0 +1,6 @@
+//! IAB VMAP (Video Multiple Ad Playlist) specifications
+//!
+//! This module contains implementations of the VMAP specifications, which describe
+//! the ad breaks of a piece of video content.
+
+pub mod v1_0;
@@ -0,0 +1,204 @@
+//! Ad break elements and their attributes
+
+use super::*;
+use crate::vast::v4_3::TimeOffset;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+
+/// Time offset of an ad break within the content
+///
+/// In addition to the VAST offset forms, VMAP allows positional offsets (`#1`, `#2`, ...)
+/// that refer to the n-th ad opportunity of content without a timeline.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BreakTimeOffset {
+    /// A time, percentage, `start` or `end` offset
+    Offset(TimeOffset),
+    /// The n-th ad opportunity, starting at 1
+    Position(u32),
+}
+
+impl fmt::Display for BreakTimeOffset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BreakTimeOffset::Offset(offset) => write!(f, "{}", offset),
+            BreakTimeOffset::Position(n) => write!(f, "#{}", n),
+        }
+    }
+}
+
+impl std::str::FromStr for BreakTimeOffset {
+    type Err = VmapError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().strip_prefix('#') {
+            Some(n) => n
+                .parse::<u32>()
+                .ok()
+                .filter(|n| *n > 0)
+                .map(BreakTimeOffset::Position)
+                .ok_or_else(|| VmapError::InvalidTimeOffset(s.to_string())),
+            None => s
+                .parse::<TimeOffset>()
+                .map(BreakTimeOffset::Offset)
+                .map_err(|_| VmapError::InvalidTimeOffset(s.to_string())),
+        }
+    }
+}
+
+impl Serialize for BreakTimeOffset {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for BreakTimeOffset {
+    fn deserialize<D>(deserializer: D) -> Result<BreakTimeOffset, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        value.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// Kind of ads allowed in an ad break
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BreakType {
+    /// Linear video ads
+    Linear,
+    /// Non-linear overlay ads
+    NonLinear,
+    /// Display (companion) ads
+    Display,
+}
+
+impl fmt::Display for BreakType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BreakType::Linear => write!(f, "linear"),
+            BreakType::NonLinear => write!(f, "nonlinear"),
+            BreakType::Display => write!(f, "display"),
+        }
+    }
+}
+
+impl std::str::FromStr for BreakType {
+    type Err = VmapError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.trim() {
+            "linear" => BreakType::Linear,
+            "nonlinear" => BreakType::NonLinear,
+            "display" => BreakType::Display,
+            _ => return Err(VmapError::InvalidBreakType(s.to_string())),
+        })
+    }
+}
+
+/// Comma separated list of break types, as used by the `breakType` attribute
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BreakTypes(pub Vec<BreakType>);
+
+impl BreakTypes {
+    /// Whether the list contains the given break type
+    pub fn contains(&self, break_type: BreakType) -> bool {
+        self.0.contains(&break_type)
+    }
+}
+
+impl fmt::Display for BreakTypes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let types: Vec<String> = self.0.iter().map(|t| t.to_string()).collect();
+        write!(f, "{}", types.join(","))
+    }
+}
+
+impl std::str::FromStr for BreakTypes {
+    type Err = VmapError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split(',')
+            .filter(|t| !t.trim().is_empty())
+            .map(str::parse)
+            .collect::<Result<_, _>>()
+            .map(BreakTypes)
+    }
+}
+
+impl Serialize for BreakTypes {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for BreakTypes {
+    fn deserialize<D>(deserializer: D) -> Result<BreakTypes, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        value.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// A single ad break within the content
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AdBreak {
+    /// When the ad break should be played
+    #[serde(rename = "@timeOffset")]
+    pub time_offset: BreakTimeOffset,
+
+    /// Kinds of ads allowed in the break
+    #[serde(rename = "@breakType")]
+    pub break_type: BreakTypes,
+
+    /// Optional identifier for the break
+    #[serde(rename = "@breakId", skip_serializing_if = "Option::is_none")]
+    pub break_id: Option<String>,
+
+    /// Repeat the break at this interval after its first occurrence (`HH:MM:SS[.mmm]`)
+    #[serde(rename = "@repeatAfter", skip_serializing_if = "Option::is_none")]
+    pub repeat_after: Option<TimeOffset>,
+
+    /// The ad response to use for the break
+    #[serde(
+        rename(serialize = "vmap:AdSource", deserialize = "AdSource"),
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub ad_source: Option<AdSource>,
+
+    /// Ad break tracking events
+    #[serde(
+        rename(serialize = "vmap:TrackingEvents", deserialize = "TrackingEvents"),
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub tracking_events: Option<TrackingEvents>,
+
+    /// Ad break extensions
+    #[serde(
+        rename(serialize = "vmap:Extensions", deserialize = "Extensions"),
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub extensions: Option<Extensions>,
+}
+
+impl AdBreak {
+    /// Create a linear ad break at the given offset
+    pub fn new_linear(time_offset: BreakTimeOffset, ad_source: AdSource) -> Self {
+        AdBreak {
+            time_offset,
+            break_type: BreakTypes(vec![BreakType::Linear]),
+            break_id: None,
+            repeat_after: None,
+            ad_source: Some(ad_source),
+            tracking_events: None,
+            extensions: None,
+        }
+    }
+}
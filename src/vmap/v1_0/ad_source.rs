@@ -0,0 +1,98 @@
+//! Ad source of a VMAP ad break
+
+use crate::vast::v4_3::{Uri, Vast};
+use serde::{Deserialize, Serialize};
+
+/// The ad response to be used for an ad break
+///
+/// An ad source holds exactly one of inline VAST data, an ad tag URI, or custom ad data.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AdSource {
+    /// Identifier for the ad source
+    #[serde(rename = "@id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
+    /// Whether the player may play multiple ads from a VAST response containing a pod
+    #[serde(rename = "@allowMultipleAds", skip_serializing_if = "Option::is_none")]
+    pub allow_multiple_ads: Option<bool>,
+
+    /// Whether the player may follow wrapper redirects
+    #[serde(rename = "@followRedirects", skip_serializing_if = "Option::is_none")]
+    pub follow_redirects: Option<bool>,
+
+    /// A VAST document embedded in the VMAP response
+    #[serde(
+        rename(serialize = "vmap:VASTAdData", deserialize = "VASTAdData"),
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub vast_ad_data: Option<VastAdData>,
+
+    /// URI of an ad response to request for the break
+    #[serde(
+        rename(serialize = "vmap:AdTagURI", deserialize = "AdTagURI"),
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub ad_tag_uri: Option<AdTagURI>,
+
+    /// Ad data in a format other than VAST
+    #[serde(
+        rename(serialize = "vmap:CustomAdData", deserialize = "CustomAdData"),
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub custom_ad_data: Option<CustomAdData>,
+}
+
+/// Inline VAST ad data
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct VastAdData {
+    /// The embedded VAST document
+    #[serde(rename = "VAST")]
+    pub vast: Vast,
+}
+
+/// URI of an ad response
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AdTagURI {
+    /// Format of the ad response (e.g., "vast3", "vast4")
+    #[serde(rename = "@templateType")]
+    pub template_type: String,
+
+    /// The ad tag URI
+    #[serde(rename = "$value")]
+    pub uri: Uri,
+}
+
+/// Ad data in a non-VAST format
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CustomAdData {
+    /// Format of the ad data
+    #[serde(rename = "@templateType")]
+    pub template_type: String,
+
+    /// The custom ad data
+    #[serde(rename = "$value", default)]
+    pub data: String,
+}
+
+impl AdSource {
+    /// Create an ad source holding an inline VAST document
+    pub fn from_vast(vast: Vast) -> Self {
+        AdSource {
+            vast_ad_data: Some(VastAdData { vast }),
+            ..Default::default()
+        }
+    }
+
+    /// Create an ad source pointing at an ad tag
+    pub fn from_ad_tag(template_type: String, uri: Uri) -> Self {
+        AdSource {
+            ad_tag_uri: Some(AdTagURI { template_type, uri }),
+            ..Default::default()
+        }
+    }
+
+    /// The inline VAST document, if any
+    pub fn vast(&self) -> Option<&Vast> {
+        self.vast_ad_data.as_ref().map(|d| &d.vast)
+    }
+}
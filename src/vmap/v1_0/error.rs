@@ -0,0 +1,23 @@
+//! Error types for VMAP parsing and validation
+
+use thiserror::Error;
+
+/// Errors that can occur when working with VMAP documents
+#[derive(Debug, Error)]
+pub enum VmapError {
+    /// XML parsing error
+    #[error("XML parsing error: {0}")]
+    XmlParse(#[from] quick_xml::DeError),
+
+    /// Invalid time offset
+    #[error("Invalid time offset: {0}")]
+    InvalidTimeOffset(String),
+
+    /// Invalid break type
+    #[error("Invalid break type: {0}")]
+    InvalidBreakType(String),
+
+    /// Invalid tracking event
+    #[error("Invalid tracking event: {0}")]
+    InvalidTrackingEvent(String),
+}
@@ -0,0 +1,27 @@
+//! Extension elements for custom VMAP extensions
+
+use serde::{Deserialize, Serialize};
+
+/// Container for VMAP extensions
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Extensions {
+    /// List of extensions
+    #[serde(
+        rename(serialize = "vmap:Extension", deserialize = "Extension"),
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub extension: Vec<Extension>,
+}
+
+/// A single extension element
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Extension {
+    /// Type identifier for the extension
+    #[serde(rename = "@type", skip_serializing_if = "Option::is_none")]
+    pub extension_type: Option<String>,
+
+    /// The raw inner XML of the extension
+    #[serde(rename = "$value", default)]
+    pub content: String,
+}
@@ -0,0 +1,19 @@
+//! VMAP 1.0 (Video Multiple Ad Playlist) implementation
+//!
+//! This module provides the IAB's VMAP 1.0 specification, used by content owners to describe
+//! the structure of ad breaks within a video. Inline ad data is represented with the
+//! VAST 4.3 model from [`crate::vast::v4_3`].
+
+pub mod ad_break;
+pub mod ad_source;
+pub mod error;
+pub mod extensions;
+pub mod tracking;
+pub mod vmap;
+
+pub use ad_break::*;
+pub use ad_source::*;
+pub use error::*;
+pub use extensions::*;
+pub use tracking::*;
+pub use vmap::*;
@@ -0,0 +1,76 @@
+//! Tracking events for VMAP ad breaks
+
+use super::*;
+use crate::vast::v4_3::Uri;
+use serde::{Deserialize, Serialize};
+
+/// Types of ad break tracking events as defined in VMAP 1.0
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BreakTrackingEvent {
+    /// The ad break has started
+    #[serde(rename = "breakStart")]
+    BreakStart,
+    /// The ad break has ended
+    #[serde(rename = "breakEnd")]
+    BreakEnd,
+    /// An error occurred while processing the ad break
+    #[serde(rename = "error")]
+    Error,
+}
+
+impl std::fmt::Display for BreakTrackingEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BreakTrackingEvent::BreakStart => write!(f, "breakStart"),
+            BreakTrackingEvent::BreakEnd => write!(f, "breakEnd"),
+            BreakTrackingEvent::Error => write!(f, "error"),
+        }
+    }
+}
+
+impl std::str::FromStr for BreakTrackingEvent {
+    type Err = VmapError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "breakStart" => BreakTrackingEvent::BreakStart,
+            "breakEnd" => BreakTrackingEvent::BreakEnd,
+            "error" => BreakTrackingEvent::Error,
+            _ => return Err(VmapError::InvalidTrackingEvent(s.to_string())),
+        })
+    }
+}
+
+/// Container for ad break tracking events
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TrackingEvents {
+    /// List of tracking events
+    #[serde(
+        rename(serialize = "vmap:Tracking", deserialize = "Tracking"),
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub tracking: Vec<Tracking>,
+}
+
+/// A single ad break tracking event
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Tracking {
+    /// The type of event to track
+    #[serde(rename = "@event")]
+    pub event: BreakTrackingEvent,
+
+    /// The tracking URI
+    #[serde(rename = "$value")]
+    pub uri: Uri,
+}
+
+impl TrackingEvents {
+    /// Tracking URIs registered for the given event
+    pub fn uris(&self, event: BreakTrackingEvent) -> impl Iterator<Item = &Uri> {
+        self.tracking
+            .iter()
+            .filter(move |t| t.event == event)
+            .map(|t| &t.uri)
+    }
+}
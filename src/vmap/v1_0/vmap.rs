@@ -0,0 +1,170 @@
+//! The root VMAP element
+
+use super::*;
+use crate::vast::v4_3::TimeOffset;
+use crate::vast::v4_3::extensions::{capture_extension_content, restore_extension_content};
+use serde::{Deserialize, Serialize};
+use std::time::Duration as StdDuration;
+
+/// The VMAP namespace URI
+pub const VMAP_NAMESPACE: &str = "http://www.iab.net/videosuite/vmap";
+
+/// The root element of a VMAP document.
+///
+/// A VMAP document describes the ad breaks of a piece of content, when they occur
+/// and where the ads for each break come from.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename(serialize = "vmap:VMAP", deserialize = "VMAP"))]
+pub struct Vmap {
+    /// The VMAP namespace declaration
+    #[serde(rename = "@xmlns:vmap", skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+
+    /// The VMAP version - should be "1.0" for this implementation
+    #[serde(rename = "@version")]
+    pub version: String,
+
+    /// Ad breaks in document order
+    #[serde(
+        rename(serialize = "vmap:AdBreak", deserialize = "AdBreak"),
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub ad_breaks: Vec<AdBreak>,
+
+    /// Document level extensions
+    #[serde(
+        rename(serialize = "vmap:Extensions", deserialize = "Extensions"),
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub extensions: Option<Extensions>,
+}
+
+impl Vmap {
+    /// Create a new VMAP 1.0 document
+    pub fn new() -> Self {
+        Vmap {
+            namespace: Some(VMAP_NAMESPACE.to_string()),
+            version: "1.0".to_string(),
+            ad_breaks: Vec::new(),
+            extensions: None,
+        }
+    }
+
+    /// Add an ad break to the document
+    pub fn add_ad_break(&mut self, ad_break: AdBreak) {
+        self.ad_breaks.push(ad_break);
+    }
+
+    /// Ad breaks in playback order for content of the given length
+    ///
+    /// `start` breaks come first, followed by positional breaks (`#n`) in position order,
+    /// then time and percentage breaks by their resolved time, and `end` breaks last.
+    /// Breaks that resolve to the same point keep their document order.
+    pub fn breaks_in_order(&self, content_duration: StdDuration) -> Vec<&AdBreak> {
+        let mut breaks: Vec<&AdBreak> = self.ad_breaks.iter().collect();
+        breaks.sort_by_key(|b| match b.time_offset {
+            BreakTimeOffset::Offset(TimeOffset::Start) => (0, 0, StdDuration::ZERO),
+            BreakTimeOffset::Position(n) => (1, n, StdDuration::ZERO),
+            BreakTimeOffset::Offset(TimeOffset::End) => (3, 0, content_duration),
+            BreakTimeOffset::Offset(offset) => (2, 0, offset.resolve(content_duration)),
+        });
+        breaks
+    }
+}
+
+impl Default for Vmap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Display for Vmap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let xml = quick_xml::se::to_string(self).map_err(|_| std::fmt::Error)?;
+        let xml = restore_extension_content(&xml).map_err(|_| std::fmt::Error)?;
+        write!(f, "{}", xml)
+    }
+}
+
+impl std::str::FromStr for Vmap {
+    type Err = VmapError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let xml = capture_extension_content(s).map_err(quick_xml::DeError::from)?;
+        Ok(quick_xml::de::from_str(&xml)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VMAP: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<vmap:VMAP xmlns:vmap="http://www.iab.net/videosuite/vmap" version="1.0">
+  <vmap:AdBreak timeOffset="end" breakType="linear" breakId="postroll">
+    <vmap:AdSource id="post" allowMultipleAds="false" followRedirects="true">
+      <vmap:AdTagURI templateType="vast4"><![CDATA[https://ads.example.com/post]]></vmap:AdTagURI>
+    </vmap:AdSource>
+  </vmap:AdBreak>
+  <vmap:AdBreak timeOffset="00:10:00.000" breakType="linear,nonlinear" breakId="midroll">
+    <vmap:AdSource id="mid">
+      <vmap:VASTAdData>
+        <VAST version="4.3">
+          <Ad id="1">
+            <Wrapper>
+              <AdSystem>Example</AdSystem>
+              <VASTAdTagURI><![CDATA[https://ads.example.com/mid]]></VASTAdTagURI>
+            </Wrapper>
+          </Ad>
+        </VAST>
+      </vmap:VASTAdData>
+    </vmap:AdSource>
+    <vmap:TrackingEvents>
+      <vmap:Tracking event="breakStart"><![CDATA[https://track.example.com/start]]></vmap:Tracking>
+    </vmap:TrackingEvents>
+  </vmap:AdBreak>
+  <vmap:AdBreak timeOffset="start" breakType="linear" breakId="preroll">
+    <vmap:AdSource id="pre">
+      <vmap:AdTagURI templateType="vast4"><![CDATA[https://ads.example.com/pre]]></vmap:AdTagURI>
+    </vmap:AdSource>
+  </vmap:AdBreak>
+</vmap:VMAP>"#;
+
+    #[test]
+    fn test_parse_and_order_breaks() {
+        let vmap: Vmap = VMAP.parse().unwrap();
+        assert_eq!(vmap.ad_breaks.len(), 3);
+
+        let mid = &vmap.ad_breaks[1];
+        assert!(mid.break_type.contains(BreakType::NonLinear));
+        let vast = mid.ad_source.as_ref().unwrap().vast().unwrap();
+        assert_eq!(vast.ads[0].id, "1");
+        let tracking = mid.tracking_events.as_ref().unwrap();
+        assert_eq!(
+            tracking
+                .uris(BreakTrackingEvent::BreakStart)
+                .next()
+                .unwrap()
+                .as_str(),
+            "https://track.example.com/start"
+        );
+
+        let ids: Vec<_> = vmap
+            .breaks_in_order(StdDuration::from_secs(1800))
+            .iter()
+            .map(|b| b.break_id.as_deref().unwrap())
+            .collect();
+        assert_eq!(ids, vec!["preroll", "midroll", "postroll"]);
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let vmap: Vmap = VMAP.parse().unwrap();
+        let xml = vmap.to_string();
+        assert!(xml.starts_with("<vmap:VMAP"));
+        assert!(xml.contains("<vmap:AdTagURI templateType=\"vast4\">"));
+        let reparsed: Vmap = xml.parse().unwrap();
+        assert_eq!(vmap, reparsed);
+    }
+}